@@ -0,0 +1,45 @@
+extern crate aoc_common;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+fn walk_floors(input: &str) -> (isize, Option<usize>) {
+    let mut cnt = 0;
+
+    let mut entered_basement_at_pos = None;
+
+    for (pos_in_input, ch) in input.chars().enumerate() {
+
+        if ch == '(' {
+            cnt += 1;
+        } else if ch == ')' {
+            cnt -= 1;
+        }
+
+        if cnt == -1 && entered_basement_at_pos.is_none() {
+            entered_basement_at_pos = Some(pos_in_input + 1);
+        }
+    }
+
+    (cnt, entered_basement_at_pos)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(walk_floors(input).0.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        walk_floors(input)
+            .1
+            .map(|pos| pos.to_string())
+            .ok_or_else(|| AocError::unsolvable("never entered the basement"))
+    }
+}
@@ -0,0 +1,51 @@
+extern crate aoc_common;
+extern crate itertools;
+
+use itertools::Itertools;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+fn look_and_say(start: &[u8], num_iterations: u32) -> Vec<u8> {
+    let mut current = start.to_vec();
+
+    for _ in 0..num_iterations {
+        current = current.into_iter()
+                         .group_by(|&ch| ch)
+                         .flat_map(|(key, matched)| vec![matched.len() as u8, key])
+                         .collect();
+    }
+
+    current
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.trim()
+             .chars()
+             .map(|ch| ch.to_digit(10).map(|d| d as u8).ok_or_else(|| AocError::parse(format!("Invalid digit: {:?}", ch))))
+             .collect()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(look_and_say(input, 40).len().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(look_and_say(input, 50).len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::look_and_say;
+
+    #[test]
+    fn look_and_say_test() {
+        assert_eq!(vec![1, 1], look_and_say(&[1], 1));
+        assert_eq!(vec![3, 1, 2, 2, 1, 1], look_and_say(&[1], 5));
+    }
+}
@@ -1,7 +1,6 @@
+extern crate aoc_common;
 
-use std::env::args;
-
-use std::collections::HashMap;
+use aoc_common::{AocError, AocResult, Solver};
 
 trait Validator {
 
@@ -48,9 +47,9 @@ impl Validator for Vec<u8> {
     }
 
     fn no_forbidden_chars(&self) -> bool {
-        let i = ('i' as u8) - ('a' as u8);
-        let o = ('o' as u8) - ('a' as u8);
-        let l = ('l' as u8) - ('a' as u8);
+        let i = b'i' - b'a';
+        let o = b'o' - b'a';
+        let l = b'l' - b'a';
         !(self.contains(&i) || self.contains(&o) || self.contains(&l))
     }
 
@@ -78,30 +77,18 @@ impl Validator for Vec<u8> {
 }
 
 
-fn main() {
-
-    let mut a = args();
-
-    a.next(); // The first argument is the binary name/path
-
-    let start = a.next().unwrap(); // The puzzle input
-
-    let mut char_to_num = HashMap::new();
-    let mut num_to_char = HashMap::new();
-
-
-    for i in 0..26 {
-        let ch = (('a' as u8) + i) as char;
-
-        char_to_num.insert(ch, i);
-        num_to_char.insert(i, ch);
-    }
-
-    let mut passwd_vec = start.chars().map(|ch| char_to_num[&ch]).collect::<Vec<u8>>();
+fn next_password(start: &str) -> AocResult<String> {
+    let mut passwd_vec = start.chars()
+                              .map(|ch| if ch.is_ascii_lowercase() {
+                                  Ok((ch as u8) - b'a')
+                              } else {
+                                  Err(AocError::parse(format!("Invalid password character: {:?}", ch)))
+                              })
+                              .collect::<AocResult<Vec<u8>>>()?;
 
     loop {
         if passwd_vec.increment() {
-            panic!("All password combinations exhausted and no password found.");
+            return Err(AocError::runtime("All password combinations exhausted and no password found."));
         }
 
         if !passwd_vec.has_sequence() {
@@ -119,9 +106,34 @@ fn main() {
         break;
     }
 
-    let readable_passwd = passwd_vec.iter().map(|ch_num| num_to_char[ch_num]).collect::<String>();
+    Ok(passwd_vec.iter().map(|ch_num| (b'a' + ch_num) as char).collect())
+}
 
-    println!("The next password is: {:?}", passwd_vec);
-    println!("Readable password: {:?}", readable_passwd);
+pub struct Solution;
 
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        next_password(input)
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        next_password(&next_password(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_password;
+
+    #[test]
+    fn next_password_test() {
+        assert_eq!(Ok("abcdffaa".to_string()), next_password("abcdefgh"));
+        assert_eq!(Ok("ghjaabcc".to_string()), next_password("ghijklmn"));
+    }
 }
@@ -1,9 +1,9 @@
+extern crate aoc_common;
 extern crate rustc_serialize;
 
 use rustc_serialize::json::Json;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 use std::collections::btree_map::Values;
 
@@ -12,8 +12,8 @@ fn parse_json(acc: i64, json_obj: &Json) -> i64 {
         &Json::U64(num) => acc + num as i64,
         &Json::I64(num) => acc + num,
         &Json::F64(num) => acc + num as i64,
-        &Json::Array(ref arr) => acc + arr.iter().fold(0_i64, parse_json),
-        &Json::Object(ref obj) => acc + obj.values().fold(0_i64, parse_json),
+        Json::Array(arr) => acc + arr.iter().fold(0_i64, parse_json),
+        Json::Object(obj) => acc + obj.values().fold(0_i64, parse_json),
         _ => acc,
     }
 }
@@ -22,7 +22,7 @@ fn contains_red(vals: Values<String, Json>) -> bool {
     let red = "red".to_string();
 
     for v in vals {
-        if let &Json::String(ref s) = v {
+        if let Json::String(s) = v {
             if s == &red {
                 return true;
             }
@@ -37,29 +37,36 @@ fn parse_json_no_red(acc: i64, json_obj: &Json) -> i64 {
         &Json::U64(num) => acc + num as i64,
         &Json::I64(num) => acc + num,
         &Json::F64(num) => acc + num as i64,
-        &Json::Array(ref arr) => acc + arr.iter().fold(0_i64, parse_json_no_red),
-        &Json::Object(ref obj) if !contains_red(obj.values()) => {
+        Json::Array(arr) => acc + arr.iter().fold(0_i64, parse_json_no_red),
+        Json::Object(obj) if !contains_red(obj.values()) => {
             acc + obj.values().fold(0_i64, parse_json_no_red)
         }
         _ => acc,
     }
 }
 
-fn main() {
-    let mut input = String::new();
+pub struct Solution;
 
-    let _ = io::stdin().read_to_string(&mut input);
+impl Solver for Solution {
+    type Input = Json;
 
-    let json = Json::from_str(&input).unwrap();
-
-    let json_obj = json.as_object().unwrap();
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Json::from_str(input).map_err(|e| AocError::parse(e.to_string()))
+    }
 
-    let total = json_obj.values().fold(0_i64, parse_json);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(parse_json(0, input).to_string())
+    }
 
-    let total_no_red = json_obj.values().fold(0, parse_json_no_red);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        // The outermost object is always counted, even if it contains "red".
+        let total_no_red = match *input {
+            Json::Object(ref obj) => obj.values().fold(0, parse_json_no_red),
+            ref other => parse_json_no_red(0, other),
+        };
 
-    println!("Sum: {}", total);
-    println!("Sum w/o red: {}", total_no_red);
+        Ok(total_no_red.to_string())
+    }
 }
 
 
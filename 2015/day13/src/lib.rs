@@ -1,3 +1,4 @@
+extern crate aoc_common;
 extern crate regex;
 extern crate permutohedron;
 
@@ -7,11 +8,10 @@ use std::collections::HashMap;
 
 use permutohedron::Heap;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
-
-struct Seating {
+#[derive(Clone)]
+pub struct Seating {
     people: Vec<String>,
     relations: HashMap<String, HashMap<String, i32>>
 }
@@ -26,7 +26,7 @@ impl Seating {
 
         for line in input.lines() {
 
-            let caps = re.captures(&line).unwrap();
+            let caps = re.captures(line).unwrap();
             let person = caps.name("person").unwrap().to_string();
 
             let mut amount = caps.name("amount").unwrap().parse::<i32>().unwrap();
@@ -40,7 +40,7 @@ impl Seating {
                 p.push(person.clone());
             }
 
-            let person_rel = rel.entry(person).or_insert(HashMap::new());
+            let person_rel = rel.entry(person).or_default();
 
             person_rel.insert(target, amount);
 
@@ -54,7 +54,7 @@ impl Seating {
         let perm = Heap::new(&mut self.people);
 
         let mut seating = Vec::new();
-        let mut max_happiness = std::i32::MIN;
+        let mut max_happiness = i32::MIN;
 
 
         for p in perm {
@@ -98,26 +98,27 @@ impl Seating {
     }
 }
 
-fn main() {
-
-    let mut input_str = String::new();
+pub struct Solution;
 
-    let _ = io::stdin().read_to_string(&mut input_str);
+impl Solver for Solution {
+    type Input = Seating;
 
-    let mut seating = Seating::from_str(&input_str);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(Seating::from_str(input))
+    }
 
-    let (arrangement, happiness) = seating.best_seating();
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut seating = input.clone();
 
-    println!("The best seating arrangement is: {:?}", arrangement);
-    println!("Total happiness achieved: {}", happiness);
+        Ok(seating.best_seating().1.to_string())
+    }
 
-    seating.add_self();
-    let (arrangement_w_me, happiness_w_me) = seating.best_seating();
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut seating = input.clone();
+        seating.add_self();
 
-    println!("---------------------------------------------------");
-    println!("Added myself.");
-    println!("The best seating arrangement is: {:?}", arrangement_w_me);
-    println!("Total happiness achieved: {}", happiness_w_me);
+        Ok(seating.best_seating().1.to_string())
+    }
 }
 
 
@@ -126,7 +127,7 @@ mod tests {
     use super::Seating;
     use std::collections::HashMap;
 
-    const TEST_INPUT: &'static str =
+    const TEST_INPUT: &str =
                 "Alice would gain 54 happiness units by sitting next to Bob.\n\
                  Alice would lose 79 happiness units by sitting next to Carol.\n\
                  Alice would lose 2 happiness units by sitting next to David.\n\
@@ -153,7 +154,7 @@ mod tests {
         let mut relations = HashMap::new();
         {
             relations.insert("Alice".to_string(), HashMap::new());
-            let mut alice_rel = relations.get_mut("Alice").unwrap();
+            let alice_rel = relations.get_mut("Alice").unwrap();
             alice_rel.insert("Bob".to_string(), 54);
             alice_rel.insert("Carol".to_string(), -79);
             alice_rel.insert("David".to_string(), -2);
@@ -161,7 +162,7 @@ mod tests {
 
         {
             relations.insert("Bob".to_string(), HashMap::new());
-            let mut bob_rel = relations.get_mut("Bob").unwrap();
+            let bob_rel = relations.get_mut("Bob").unwrap();
             bob_rel.insert("Alice".to_string(), 83);
             bob_rel.insert("Carol".to_string(), -7);
             bob_rel.insert("David".to_string(), -63);
@@ -169,7 +170,7 @@ mod tests {
 
         {
             relations.insert("Carol".to_string(), HashMap::new());
-            let mut carol_rel = relations.get_mut("Carol").unwrap();
+            let carol_rel = relations.get_mut("Carol").unwrap();
             carol_rel.insert("Alice".to_string(), -62);
             carol_rel.insert("Bob".to_string(), 60);
             carol_rel.insert("David".to_string(), 55);
@@ -177,7 +178,7 @@ mod tests {
 
         {
             relations.insert("David".to_string(), HashMap::new());
-            let mut david_rel = relations.get_mut("David").unwrap();
+            let david_rel = relations.get_mut("David").unwrap();
             david_rel.insert("Alice".to_string(), 46);
             david_rel.insert("Bob".to_string(), -7);
             david_rel.insert("Carol".to_string(), 41);
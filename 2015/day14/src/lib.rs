@@ -1,15 +1,12 @@
+extern crate aoc_common;
 extern crate regex;
 
-
-use std::env::args;
-
 use regex::Regex;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
-#[derive(PartialEq, Eq, Debug)]
-struct Reindeer {
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Reindeer {
     name: String,
     speed_in_km_s: u32,
     move_time_in_s: u32,
@@ -29,7 +26,7 @@ impl Reindeer {
         let move_time = caps.name("move_dur").unwrap().parse::<u32>().unwrap();
         let rest_time = caps.name("rest_dur").unwrap().parse::<u32>().unwrap();
 
-        Reindeer { name: name, speed_in_km_s: speed, move_time_in_s: move_time, rest_time_in_s: rest_time, curr_pos: 0, points: 0 }
+        Reindeer { name, speed_in_km_s: speed, move_time_in_s: move_time, rest_time_in_s: rest_time, curr_pos: 0, points: 0 }
     }
 
     fn get_pos_after(&self, num_s: u32) -> u32 {
@@ -50,8 +47,6 @@ impl Reindeer {
         if (curr_time % cycle_time) < self.move_time_in_s {
             self.curr_pos += self.speed_in_km_s
         }
-
-        self.curr_pos;
     }
 
     fn add_point_if_leading(&mut self, lead_pos: u32) {
@@ -62,46 +57,12 @@ impl Reindeer {
 }
 
 
-fn main() {
-
-    let mut a = args();
-
-    a.next(); // The first argument is the binary name/path
-    let race_duration = a.next().unwrap().parse::<u32>().unwrap();
-
-
-    let stdin = io::stdin();
-
-    let mut reindeer = Vec::new();
-
-    for wline in stdin.lock().lines() {
-        let line = wline.unwrap();
-
-        reindeer.push(Reindeer::from_str(&line));
-    }
-
-
-    {
-        let mut max_dist = 0;
-        let mut max_reindeer: Option<&Reindeer> = None;
-
-        for r in &reindeer {
-            let dist = r.get_pos_after(race_duration);
-
-            if dist > max_dist {
-                max_dist = dist;
-                max_reindeer = Some(r);
-            }
-        }
-
-        if let Some(rd) = max_reindeer {
-            println!("{} has traveled the furthest distance - {} km.", rd.name, max_dist);
-        } else {
-            panic!();
-        }
-    }
+fn get_max_distance(reindeer: &[Reindeer], race_duration: u32) -> u32 {
+    reindeer.iter().map(|r| r.get_pos_after(race_duration)).max().unwrap_or(0)
+}
 
-    println!("---------------------------------");
+fn get_max_points(reindeer: &[Reindeer], race_duration: u32) -> u32 {
+    let mut reindeer = reindeer.to_vec();
 
     for curr_time in 0..race_duration {
 
@@ -121,9 +82,24 @@ fn main() {
         }
     }
 
-    println!("Die Punktestand:");
-    for r in &reindeer {
-        println!("{} - {}", r.name, r.points);
+    reindeer.iter().map(|r| r.points).max().unwrap_or(0)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.lines().map(Reindeer::from_str).collect())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(get_max_distance(input, 2503).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(get_max_points(input, 2503).to_string())
     }
 }
 
@@ -132,7 +108,7 @@ mod tests {
     use super::Reindeer;
     use std::cmp;
 
-    const TEST_INPUT: &'static str =
+    const TEST_INPUT: &str =
             "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
              Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.\n";
 
@@ -1,6 +1,4 @@
-
-#![feature(iter_arith)]
-
+extern crate aoc_common;
 extern crate regex;
 
 use std::str::FromStr;
@@ -8,11 +6,10 @@ use std::str::FromStr;
 use regex::Regex;
 use std::error::Error;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug, PartialEq, Eq)]
-struct Ingredient {
+pub struct Ingredient {
     name: String,
     capacity: i32,
     durability: i32,
@@ -22,7 +19,7 @@ struct Ingredient {
 }
 
 #[derive(Debug)]
-struct ParseIngredientErr;
+pub struct ParseIngredientErr;
 
 impl<T: Error> From<T> for ParseIngredientErr {
     fn from(_t: T) -> ParseIngredientErr {
@@ -34,43 +31,43 @@ impl FromStr for Ingredient {
     type Err = ParseIngredientErr;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = try!(Regex::new("^(?P<name>[:alpha:]+): \
+        let re = Regex::new("^(?P<name>[:alpha:]+): \
                                   capacity (?P<cap_neg>-)?(?P<cap>[:digit:]+), \
                                   durability (?P<dur_neg>-)?(?P<dur>[:digit:]+), \
                                   flavor (?P<flav_neg>-)?(?P<flav>[:digit:]+), \
                                   texture (?P<tex_neg>-)?(?P<tex>[:digit:]+), \
-                                  calories (?P<cal>[:digit:]+)$"));
+                                  calories (?P<cal>[:digit:]+)$")?;
 
-        let caps = try!(re.captures(s).ok_or(ParseIngredientErr));
+        let caps = re.captures(s).ok_or(ParseIngredientErr)?;
 
-        let name = try!(caps.name("name").ok_or(ParseIngredientErr));
+        let name = caps.name("name").ok_or(ParseIngredientErr)?;
 
-        let cap_str = try!(caps.name("cap").ok_or(ParseIngredientErr));
-        let mut cap = try!(cap_str.parse::<i32>());
+        let cap_str = caps.name("cap").ok_or(ParseIngredientErr)?;
+        let mut cap = cap_str.parse::<i32>()?;
         if caps.name("cap_neg").is_some() {
             cap = -cap;
         }
 
-        let dur_str = try!(caps.name("dur").ok_or(ParseIngredientErr));
-        let mut dur = try!(dur_str.parse::<i32>());
+        let dur_str = caps.name("dur").ok_or(ParseIngredientErr)?;
+        let mut dur = dur_str.parse::<i32>()?;
         if caps.name("dur_neg").is_some() {
             dur = -dur;
         }
 
-        let flav_str = try!(caps.name("flav").ok_or(ParseIngredientErr));
-        let mut flav = try!(flav_str.parse::<i32>());
+        let flav_str = caps.name("flav").ok_or(ParseIngredientErr)?;
+        let mut flav = flav_str.parse::<i32>()?;
         if caps.name("flav_neg").is_some() {
             flav = -flav;
         }
 
-        let tex_str = try!(caps.name("tex").ok_or(ParseIngredientErr));
-        let mut tex = try!(tex_str.parse::<i32>());
+        let tex_str = caps.name("tex").ok_or(ParseIngredientErr)?;
+        let mut tex = tex_str.parse::<i32>()?;
         if caps.name("tex_neg").is_some() {
             tex = -tex;
         }
 
-        let cal_str = try!(caps.name("cal").ok_or(ParseIngredientErr));
-        let cal = try!(cal_str.parse::<u32>());
+        let cal_str = caps.name("cal").ok_or(ParseIngredientErr)?;
+        let cal = cal_str.parse::<u32>()?;
 
         Ok(Ingredient {
             name: name.to_string(),
@@ -92,19 +89,14 @@ struct Combination<'a> {
 }
 
 impl<'a> Combination<'a> {
-    fn new(ingredients: &Vec<Ingredient>, ingredient_sum: u32, calorie_tgt: u32) -> Combination {
+    fn new(ingredients: &Vec<Ingredient>, ingredient_sum: u32, calorie_tgt: u32) -> Combination<'_> {
         let len = ingredients.len();
 
-        let mut comb: Vec<u32> = Vec::with_capacity(len);
-        for _ in 0..len {
-            comb.push(0);
-        }
-
         Combination {
-            ingredients: ingredients,
-            combination: comb,
-            ingredient_sum: ingredient_sum,
-            calorie_tgt: calorie_tgt,
+            ingredients,
+            combination: vec![0; len],
+            ingredient_sum,
+            calorie_tgt,
         }
     }
 
@@ -149,7 +141,7 @@ impl<'a> Combination<'a> {
         calories
     }
 
-    fn increment_comb(comb: &mut Vec<u32>, base: u32) -> bool {
+    fn increment_comb(comb: &mut [u32], base: u32) -> bool {
         *(comb.last_mut().unwrap()) += 1;
 
         let mut carry: u32 = 0;
@@ -231,37 +223,29 @@ fn find_max_goodness_cals(comb: &mut Combination) -> (u32, Vec<u32>) {
 }
 
 
-fn main() {
-    let stdin = io::stdin();
-
-    let ingredients: Vec<Ingredient> = stdin.lock()
-                                            .lines()
-                                            .map(|line| line.unwrap().parse().unwrap())
-                                            .collect();
+pub struct Solution;
 
-    let mut c = Combination::new(&ingredients, 100, 500);
+impl Solver for Solution {
+    type Input = Vec<Ingredient>;
 
-    let (max, max_comb) = find_max_goodness(&mut c);
-
-    println!("-----------------------------------------------");
-    println!("Without calorie constraint.");
-    println!("Maximum goodness: {}", max);
-
-    for i in 0..ingredients.len() {
-        println!("{} - {}", max_comb[i], ingredients[i].name);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.lines()
+             .enumerate()
+             .map(|(idx, line)| line.parse().map_err(|_| AocError::parse_at(idx + 1, 1, format!("Invalid ingredient: {:?}", line))))
+             .collect()
     }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut c = Combination::new(input, 100, 500);
 
-    let (max_cals, max_comb_cals) = find_max_goodness_cals(&mut c);
+        Ok(find_max_goodness(&mut c).0.to_string())
+    }
 
-    println!("-----------------------------------------------");
-    println!("With calorie constraint.");
-    println!("Maximum goodness: {}", max_cals);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut c = Combination::new(input, 100, 500);
 
-    for i in 0..ingredients.len() {
-        println!("{} - {}", max_comb_cals[i], ingredients[i].name);
+        Ok(find_max_goodness_cals(&mut c).0.to_string())
     }
-
 }
 
 
@@ -272,11 +256,11 @@ mod tests {
     use super::find_max_goodness;
     use super::find_max_goodness_cals;
 
-    const TEST_INPUT: &'static str =
+    const TEST_INPUT: &str =
             "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8\n\
              Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
 
-    const BAD_TEST_INPUT: &'static str = "Butterscotch: capacity -1";
+    const BAD_TEST_INPUT: &str = "Butterscotch: capacity -1";
 
     #[test]
     fn parser_test() {
@@ -1,15 +1,14 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 use regex::Regex;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 use std::collections::BTreeMap;
 
 #[derive(Debug)]
-struct Sue {
+pub struct Sue {
     children: Option<u32>,
     cats: Option<u32>,
     samoyeds: Option<u32>,
@@ -68,16 +67,16 @@ impl Sue {
 
         (id,
          Sue {
-            children: children,
-            cats: cats,
-            samoyeds: samoyeds,
-            pomeranians: pomeranians,
-            akitas: akitas,
-            vizslas: vizslas,
-            goldfish: goldfish,
-            trees: trees,
-            cars: cars,
-            perfumes: perfumes,
+            children,
+            cats,
+            samoyeds,
+            pomeranians,
+            akitas,
+            vizslas,
+            goldfish,
+            trees,
+            cars,
+            perfumes,
         })
     }
 
@@ -251,48 +250,42 @@ impl Sue {
 }
 
 
-fn main() {
-    let mut sues = BTreeMap::new();
-
-    let stdin = io::stdin();
+const REF_SUE: Sue = Sue {
+    children: Some(3),
+    cats: Some(7),
+    samoyeds: Some(2),
+    pomeranians: Some(3),
+    akitas: Some(0),
+    vizslas: Some(0),
+    goldfish: Some(5),
+    trees: Some(3),
+    cars: Some(2),
+    perfumes: Some(1),
+};
+
+fn find_matching_sue<F>(sues: &BTreeMap<u32, Sue>, matches: F) -> AocResult<String>
+    where F: Fn(&Sue) -> bool
+{
+    sues.iter()
+        .find(|&(_, sue)| matches(sue))
+        .map(|(id, _)| id.to_string())
+        .ok_or_else(|| AocError::unsolvable("No matching Sue found"))
+}
 
-    for wline in stdin.lock().lines() {
-        let line = wline.unwrap();
+pub struct Solution;
 
-        let (id, sue) = Sue::from_str(&line);
+impl Solver for Solution {
+    type Input = BTreeMap<u32, Sue>;
 
-        sues.insert(id, sue);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.lines().map(Sue::from_str).collect())
     }
 
-    let ref_sue = Sue {
-        children: Some(3),
-        cats: Some(7),
-        samoyeds: Some(2),
-        pomeranians: Some(3),
-        akitas: Some(0),
-        vizslas: Some(0),
-        goldfish: Some(5),
-        trees: Some(3),
-        cars: Some(2),
-        perfumes: Some(1),
-    };
-
-    println!("Looking for the following Sue: {:?}", ref_sue);
-
-    for (id, sue) in &sues {
-        if sue.matches_reference(&ref_sue) {
-            println!("Possible match with Sue #{}: {:?}", id, sue);
-        }
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        find_matching_sue(input, |sue| sue.matches_reference(&REF_SUE))
     }
 
-    println!("-------------------------------------------------");
-    println!("Recalibrating.");
-
-    println!("Looking for the following Sue: {:?}", ref_sue);
-
-    for (id, sue) in &sues {
-        if sue.matches_reference_recalibrated(&ref_sue) {
-            println!("Possible match with Sue #{}: {:?}", id, sue);
-        }
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        find_matching_sue(input, |sue| sue.matches_reference_recalibrated(&REF_SUE))
     }
 }
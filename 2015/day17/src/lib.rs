@@ -1,6 +1,6 @@
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
 
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug)]
 struct Combination<'a> {
@@ -10,11 +10,11 @@ struct Combination<'a> {
 }
 
 impl<'a> Combination<'a> {
-    fn new(containers: &'a Vec<u32>, expected_sum: u32) -> Combination {
+    fn new(containers: &'a Vec<u32>, expected_sum: u32) -> Combination<'a> {
         Combination {
-            containers: containers,
+            containers,
             current: vec![false; containers.len()],
-            expected_sum: expected_sum,
+            expected_sum,
         }
     }
 
@@ -62,7 +62,7 @@ fn get_num_valid_combinations(combination: &mut Combination) -> u32 {
         }
     }
 
-    return total;
+    total
 }
 
 fn get_min_num_containers(combination: &mut Combination) -> (usize, u32) {
@@ -92,28 +92,28 @@ fn get_min_num_containers(combination: &mut Combination) -> (usize, u32) {
 }
 
 
-fn main() {
+pub struct Solution;
 
-    let mut containers = Vec::new();
+impl Solver for Solution {
+    type Input = Vec<u32>;
 
-    let stdin = io::stdin();
-    for wline in stdin.lock().lines() {
-        let line = wline.unwrap();
-
-        containers.push(line.parse::<u32>().unwrap());
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.lines()
+             .map(|line| line.parse::<u32>().map_err(AocError::from))
+             .collect()
     }
 
-    let total = 150;
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut c = Combination::new(input, 150);
 
-    let mut c = Combination::new(&containers, total);
+        Ok(get_num_valid_combinations(&mut c).to_string())
+    }
 
-    let num_valid = get_num_valid_combinations(&mut c);
-    println!("The required total is {} liters", total);
-    println!("Total number of valid combinations: {}", num_valid);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut c = Combination::new(input, 150);
 
-    let (min_containers, num_min) = get_min_num_containers(&mut c);
-    println!("The minimum number of containers is: {}", min_containers);
-    println!("The number of these combinations is: {}", num_min);
+        Ok(get_min_num_containers(&mut c).1.to_string())
+    }
 }
 
 
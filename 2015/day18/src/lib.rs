@@ -1,160 +1,60 @@
-
-use std::error::Error;
+extern crate aoc_common;
 
 use std::str::FromStr;
 
 use std::fmt;
 
-use std::io;
-use std::io::Read;
-
 use std::mem;
 
-use std::env;
-
+use aoc_common::{AocError, AocResult, Grid, Point, Solver};
 
-#[derive(Debug, PartialEq)]
-struct LightParseError;
 
-impl fmt::Display for LightParseError {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.write_str(self.description())
-    }
-}
-
-impl Error for LightParseError {
-    fn description(&self) -> &str {
-        "error during input parsing"
-    }
-}
-
-
-//                 y
-//   +-------------->
-//   |
-//   |
-//   |
-// x |
-//   v
-
-#[derive(Debug)]
-struct Lights {
-    current: Vec<Vec<bool>>,
-    next: Vec<Vec<bool>>,
-    dimensions: (usize, usize),
+#[derive(Debug, Clone)]
+pub struct Lights {
+    current: Grid<bool>,
+    next: Grid<bool>,
 }
 
 impl FromStr for Lights {
-    type Err = LightParseError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let initial_state_res = s.lines()
-                                 .map(|line| {
-                                     line.chars()
-                                         .map(|ch| {
-                                             match ch {
-                                                 '.' => Ok(false),
-                                                 '#' => Ok(true),
-                                                 _ => Err(LightParseError),
-                                             }
-                                         })
-                                         .collect::<Result<Vec<bool>, LightParseError>>()
-                                 })
-                                 .collect::<Result<Vec<Vec<bool>>, LightParseError>>();
-
-        match initial_state_res {
-            Ok(initial_state) => {
-                let dims = (initial_state.len(), initial_state[0].len());
-
-                if initial_state.iter().all(|line| line.len() == dims.1) {
-                    Ok(Lights {
-                        current: initial_state,
-                        next: vec![vec![false; dims.1]; dims.0],
-                        dimensions: dims,
-                    })
-                } else {
-                    Err(LightParseError)
-                }
+        let grid = Grid::parse(s, |ch| {
+            match ch {
+                '.' => Ok(false),
+                '#' => Ok(true),
+                _ => Err(AocError::parse(format!("Invalid light: {:?}", ch))),
             }
-            Err(err) => Err(err),
-        }
+        })?;
+
+        Ok(Lights {
+            next: grid.clone(),
+            current: grid,
+        })
     }
 }
 
 impl fmt::Display for Lights {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let mut out = String::with_capacity((self.dimensions.0 + 1) * self.dimensions.1);
-
-        for light_row in self.current.iter() {
-            for light_state in light_row {
-                out.push(if *light_state {
-                    '#'
-                } else {
-                    '.'
-                });
-            }
-
-            out.push('\n');
-        }
-
-        fmt.write_str(&out)
+        fmt.write_str(&self.current.render(|light_state| if *light_state { '#' } else { '.' }))
     }
 }
 
 impl Lights {
     fn step(&mut self) {
-
-        for x in 0..self.dimensions.0 {
-            for y in 0..self.dimensions.1 {
-                let mut neighbours_on = 0;
-
-                if x > 0 {
-                    neighbours_on += self.current[x - 1][y] as u8;
-
-                    if y > 0 {
-                        neighbours_on += self.current[x - 1][y - 1] as u8;
-                    }
-
-                    if y < self.dimensions.1 - 1 {
-                        neighbours_on += self.current[x - 1][y + 1] as u8;
-                    }
-                }
-
-                if y > 0 {
-                    neighbours_on += self.current[x][y - 1] as u8;
-                }
-
-                if y < self.dimensions.1 - 1 {
-                    neighbours_on += self.current[x][y + 1] as u8;
-                }
-
-                if x < self.dimensions.0 - 1 {
-                    neighbours_on += self.current[x + 1][y] as u8;
-
-                    if y > 0 {
-                        neighbours_on += self.current[x + 1][y - 1] as u8;
-                    }
-
-                    if y < self.dimensions.1 - 1 {
-                        neighbours_on += self.current[x + 1][y + 1] as u8;
-                    }
-                }
-
-
-                self.next[x][y] = if self.current[x][y] {
-                    if neighbours_on == 2 || neighbours_on == 3 {
-                        true
-                    } else {
-                        false
-                    }
-                } else {
-                    if neighbours_on == 3 {
-                        true
-                    } else {
-                        false
-                    }
-                };
-            }
+        let current = &self.current;
+
+        for (pos, light) in self.next.iter_mut() {
+            let neighbours_on = current
+                .neighbours_diagonal(pos)
+                .filter(|neighbour| current[*neighbour])
+                .count();
+
+            *light = if current[pos] {
+                neighbours_on == 2 || neighbours_on == 3
+            } else {
+                neighbours_on == 3
+            };
         }
 
         mem::swap(&mut self.current, &mut self.next);
@@ -162,57 +62,55 @@ impl Lights {
 
     fn step_b(&mut self) {
         self.step();
-        self.current[0][0] = true;
-        self.current[0][self.dimensions.1 - 1] = true;
-        self.current[self.dimensions.0 - 1][0] = true;
-        self.current[self.dimensions.0 - 1][self.dimensions.1 - 1] = true;
+        self.set_corners_on();
+    }
+
+    fn set_corners_on(&mut self) {
+        let (max_x, max_y) = (self.current.width() as isize - 1, self.current.height() as isize - 1);
+
+        for corner in [Point::new(0, 0), Point::new(max_x, 0), Point::new(0, max_y), Point::new(max_x, max_y)] {
+            self.current[corner] = true;
+        }
     }
 
     fn get_num_lights_on(&self) -> u32 {
-        self.current.iter().fold(0, |acc, light_row| {
-            acc +
-            light_row.iter().fold(0, |acc, light_state| {
-                if *light_state {
-                    acc + 1
-                } else {
-                    acc
-                }
-            })
-        })
+        self.current.values().filter(|light_state| **light_state).count() as u32
     }
 }
 
-fn main() {
-
-    let mut a = env::args();
+fn count_after_steps(initial: &Lights, num_iterations: u32, corners_on: bool) -> u32 {
+    let mut lights = initial.clone();
 
-    a.next(); // The first argument is the binary name/path
+    if corners_on {
+        lights.set_corners_on();
+    }
 
-    let num_iterations = a.next().unwrap().parse::<u32>().unwrap();
-    let corners_on = match a.next().unwrap().as_str() {
-        "on" => true,
-        "off" => false,
-        _ => panic!()
-    };
+    for _ in 0..num_iterations {
+        if !corners_on {
+            lights.step();
+        } else {
+            lights.step_b();
+        }
+    }
 
+    lights.get_num_lights_on()
+}
 
-    let mut input_str = String::new();
-    let mut stdin = io::stdin();
+pub struct Solution;
 
-    stdin.read_to_string(&mut input_str).unwrap();
+impl Solver for Solution {
+    type Input = Lights;
 
-    let mut lights = Lights::from_str(&input_str).unwrap();
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Lights::from_str(input)
+    }
 
-    for i in 0..(num_iterations + 1) {
-        println!("State after {} iterations.", i);
-        println!("Number of lights on: {}", lights.get_num_lights_on());
-        println!("{}", lights);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(count_after_steps(input, 100, false).to_string())
+    }
 
-        if !corners_on {
-            lights.step();
-        } else {
-            lights.step_b();
-        }
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(count_after_steps(input, 100, true).to_string())
     }
 }
 
@@ -220,17 +118,17 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::Lights;
-    use super::LightParseError;
+    use aoc_common::AocError;
     use std::str::FromStr;
 
-    const TEST_INPUT_NOT_RECTANGULAR: &'static str = ".#.#.#\n\
+    const TEST_INPUT_NOT_RECTANGULAR: &str = ".#.#.#\n\
                                                       ...##.\n\
                                                       #....\n\
                                                       ..#...\n\
                                                       #.#..#\n\
                                                       ####..";
 
-    const TEST_INPUT_INVALID_CHAR: &'static str = ".#.#.#\n\
+    const TEST_INPUT_INVALID_CHAR: &str = ".#.#.#\n\
                                                    .X.##.\n\
                                                    #....#\n\
                                                    ..#...\n\
@@ -238,7 +136,7 @@ mod tests {
                                                    ####..";
 
 
-    const TEST_INPUT: [&'static str; 5] = [".#.#.#\n\
+    const TEST_INPUT: [&str; 5] = [".#.#.#\n\
                                             ...##.\n\
                                             #....#\n\
                                             ..#...\n\
@@ -275,7 +173,7 @@ mod tests {
 
     const TEST_INPUT_LIGHT_COUNT: [u32; 5] = [15, 11, 8, 4, 4];
 
-    const TEST_INPUT_B: [&'static str; 6] = ["##.#.#\n\
+    const TEST_INPUT_B: [&str; 6] = ["##.#.#\n\
                                               ...##.\n\
                                               #....#\n\
                                               ..#...\n\
@@ -329,16 +227,18 @@ mod tests {
                             vec![true, false, true, false, false, true],
                             vec![true, true, true, true, false, false]];
 
-        assert_eq!(lights.current, expected);
+        assert_eq!(lights.current.rows().map(<[bool]>::to_vec).collect::<Vec<Vec<bool>>>(), expected);
 
         let lights_not_rectangular = Lights::from_str(TEST_INPUT_NOT_RECTANGULAR);
 
-        assert_eq!(lights_not_rectangular.unwrap_err(), LightParseError);
+        assert_eq!(lights_not_rectangular.unwrap_err(),
+                   AocError::parse_at(3, 6, "expected 6 cells, found 5"));
 
 
         let lights_invalid_char = Lights::from_str(TEST_INPUT_INVALID_CHAR);
 
-        assert_eq!(lights_invalid_char.unwrap_err(), LightParseError);
+        assert_eq!(lights_invalid_char.unwrap_err(),
+                   AocError::parse_at(2, 2, "Invalid light: 'X'"));
     }
 
     #[test]
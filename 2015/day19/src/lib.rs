@@ -1,14 +1,14 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 use regex::Regex;
 
-use std::io;
 use std::io::prelude::*;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use aoc_common::{AocResult, Solver};
 
 fn parse_input<T: BufRead>(input: T) -> (HashMap<String, HashSet<Vec<String>>>, Vec<String>) {
 
@@ -31,17 +31,17 @@ fn parse_input<T: BufRead>(input: T) -> (HashMap<String, HashSet<Vec<String>>>,
             let (_, endpos) = transform.pos(1).unwrap();
             let (_, rest) = line.split_at(endpos);
 
-            for atom in atoms_re.captures_iter(&rest) {
+            for atom in atoms_re.captures_iter(rest) {
                 output_molecule.push(atom.at(0).unwrap().to_string());
             }
 
-            let mut output_molecules = transforms.entry(source_atom.to_string())
+            let output_molecules = transforms.entry(source_atom.to_string())
                                                  .or_insert(HashSet::new());
             output_molecules.insert(output_molecule);
         }
 
         if molecule_re.is_match(&line) {
-            if molecule_vec.len() != 0 {
+            if !molecule_vec.is_empty() {
                 panic!("Multiple occureces of calibration molecule.");
             }
 
@@ -56,7 +56,7 @@ fn parse_input<T: BufRead>(input: T) -> (HashMap<String, HashSet<Vec<String>>>,
 }
 
 fn apply_transforms(transform_map: &HashMap<String, HashSet<Vec<String>>>,
-                    molecule: &Vec<String>)
+                    molecule: &[String])
                     -> HashSet<Vec<String>> {
 
     let mut possible_molecules = HashSet::new();
@@ -79,12 +79,12 @@ fn apply_transforms(transform_map: &HashMap<String, HashSet<Vec<String>>>,
 }
 
 // Unfortunately this is really slow. But it works for short lengths of "cure".
+#[cfg(test)]
 fn steps_to_cure(transform_map: &HashMap<String, HashSet<Vec<String>>>,
                  cure: &Vec<String>,
                  current: &HashSet<Vec<String>>,
                  acc: u32)
                  -> u32 {
-    println!("At level {}.", acc);
     let mut next = HashSet::with_capacity(current.len());
 
     for molecule in current {
@@ -92,32 +92,41 @@ fn steps_to_cure(transform_map: &HashMap<String, HashSet<Vec<String>>>,
         next = next.union(&possible).cloned().collect();
     }
 
-    println!("{:?}", current.len());
     if next.contains(cure) {
-        return acc;
+        acc
     } else {
-        return steps_to_cure(transform_map, cure, &next, acc + 1);
+        steps_to_cure(transform_map, cure, &next, acc + 1)
     }
 }
 
 
-fn main() {
-    let stdin = io::stdin();
+type Transforms = HashMap<String, HashSet<Vec<String>>>;
+
+pub struct Solution;
 
-    let (transforms, calib_molecule) = parse_input(stdin.lock());
+impl Solver for Solution {
+    type Input = (Transforms, Vec<String>);
 
-    let possible = apply_transforms(&transforms, &calib_molecule);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input.as_bytes()))
+    }
 
-    println!("There are {} possible distinct molecules after applying the transforms.", possible.len());
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let (ref transforms, ref calib_molecule) = *input;
 
+        Ok(apply_transforms(transforms, calib_molecule).len().to_string())
+    }
 
-    // Simply copied askalski's solution from Reddit. :-(
-    let num_elements = calib_molecule.len();
-    let num_parenths = calib_molecule.iter().filter(|atom| *atom == "Rn" || *atom == "Ar").count();
-    let num_commas = calib_molecule.iter().filter(|atom| *atom == "Y").count();
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let calib_molecule = &input.1;
 
-    println!("The cure can be synthesized in {} steps.", num_elements - num_parenths - 2 * num_commas - 1);
+        // Simply copied askalski's solution from Reddit. :-(
+        let num_elements = calib_molecule.len();
+        let num_parenths = calib_molecule.iter().filter(|atom| *atom == "Rn" || *atom == "Ar").count();
+        let num_commas = calib_molecule.iter().filter(|atom| *atom == "Y").count();
 
+        Ok((num_elements - num_parenths - 2 * num_commas - 1).to_string())
+    }
 }
 
 
@@ -130,25 +139,25 @@ mod tests {
 
     use std::collections::{HashMap, HashSet};
 
-    const TEST_INPUT_1: &'static str = "H => HO\n\
+    const TEST_INPUT_1: &str = "H => HO\n\
                                         H => OH\n\
                                         O => HH\n\
                                         Ca => PbHC\n\
                                         HHCaOPb";
 
-    const TEST_INPUT_2: &'static str = "H => HO\n\
+    const TEST_INPUT_2: &str = "H => HO\n\
                                         H => OH\n\
                                         O => HH\n\
                                         HOH";
 
-    const TEST_INPUT_3: &'static str = "e => H\n\
+    const TEST_INPUT_3: &str = "e => H\n\
                                         e => O\n\
                                         H => HO\n\
                                         H => OH\n\
                                         O => HH\n\
                                         HOH";
 
-    const TEST_INPUT_4: &'static str = "e => H\n\
+    const TEST_INPUT_4: &str = "e => H\n\
                                         e => O\n\
                                         H => HO\n\
                                         H => OH\n\
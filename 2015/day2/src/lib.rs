@@ -0,0 +1,60 @@
+extern crate aoc_common;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+fn parse_dimensions(input: &str) -> AocResult<Vec<[u32; 3]>> {
+    input
+        .lines()
+        .map(|line| {
+            let dimensions = line.split('x')
+                                 .map(|i| i.parse::<u32>().map_err(AocError::from))
+                                 .collect::<AocResult<Vec<u32>>>()?;
+
+            if dimensions.len() != 3 {
+                return Err(AocError::parse(format!("invalid present dimensions: {:?}", line)));
+            }
+
+            Ok([dimensions[0], dimensions[1], dimensions[2]])
+        })
+        .collect()
+}
+
+fn wrap_presents(presents: &[[u32; 3]]) -> (u32, u32) {
+    let mut total_area = 0;
+    let mut total_ribbon = 0;
+
+    for dimensions in presents {
+        let faces = [dimensions[0] * dimensions[1],
+                     dimensions[0] * dimensions[2],
+                     dimensions[1] * dimensions[2]];
+
+        let perimeters = [2 * (dimensions[0] + dimensions[1]),
+                          2 * (dimensions[0] + dimensions[2]),
+                          2 * (dimensions[1] + dimensions[2])];
+
+        let volume = dimensions[0] * dimensions[1] * dimensions[2];
+
+        total_area += 2 * faces.iter().sum::<u32>() + faces.iter().min().unwrap();
+        total_ribbon += perimeters.iter().min().unwrap() + volume;
+    }
+
+    (total_area, total_ribbon)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<[u32; 3]>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_dimensions(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(wrap_presents(input).0.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(wrap_presents(input).1.to_string())
+    }
+}
@@ -1,9 +1,8 @@
-#![feature(iter_arith)]
+extern crate aoc_common;
 
 use std::iter::Iterator;
 
-use std::env;
-
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug)]
 struct Divisors {
@@ -16,7 +15,7 @@ struct Divisors {
 impl Divisors {
     fn new(num_divided: u64) -> Divisors {
         Divisors {
-            num_divided: num_divided,
+            num_divided,
             divisor_complement: None,
             pos: 0,
             max: (num_divided as f64).sqrt() as u64,
@@ -43,7 +42,7 @@ impl Iterator for Divisors {
         while self.pos < self.max {
             self.pos += 1;
 
-            if self.num_divided % self.pos == 0 {
+            if self.num_divided.is_multiple_of(self.pos) {
                 self.divisor_complement = Some(self.pos);
 
                 return Some(self.pos);
@@ -95,38 +94,24 @@ fn part2(num_presents_min: u64, guess_start: bool) -> (u64, u64) {
 }
 
 
-fn main() {
-    let mut args = env::args();
-
+pub struct Solution;
 
-    args.next();
-
-    let input = args.next().unwrap().parse::<u64>().unwrap();
-    let should_guess = if let Some(guess_str) = args.next() {
-        match guess_str.as_ref() {
-            "guess" => true,
-            _ => false,
-        }
-    } else {
-        false
-    };
+impl Solver for Solution {
+    type Input = u64;
 
-    if should_guess {
-        println!("Trying to reduce the search time by guessing at which house to start searching.");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.trim().parse::<u64>().map_err(AocError::from)
     }
 
-    let (part_1_house, part_1_num_presents) = part1(input, should_guess);
-    println!("Part 1. House #{} is the first to receive at least {} presents. ({})",
-             part_1_house,
-             input,
-             part_1_num_presents);
+    // Guessing the starting house considerably reduces the search time.
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(part1(*input, true).0.to_string())
+    }
 
-    let (part_2_house, part_2_num_presents) = part2(input, should_guess);
-    println!("Part 2. House #{} is the first to receive at least {} presents. ({})",
-             part_2_house,
-             input,
-             part_2_num_presents);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(part2(*input, true).0.to_string())
+    }
 }
 
 
@@ -158,10 +143,10 @@ mod tests {
             let mut ex_house_num = 0;
             let mut ex_num_presents = 0;
 
-            for house_num in 0..HOUSE_PRESENTS.len() {
-                if HOUSE_PRESENTS[house_num] >= input_presents {
+            for (house_num, &house_presents) in HOUSE_PRESENTS.iter().enumerate() {
+                if house_presents >= input_presents {
                     ex_house_num = house_num as u64 + 1;
-                    ex_num_presents = HOUSE_PRESENTS[house_num];
+                    ex_num_presents = house_presents;
 
                     break;
                 }
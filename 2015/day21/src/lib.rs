@@ -0,0 +1,204 @@
+extern crate aoc_common;
+
+pub mod shop;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+use shop::{Loadout, Shop};
+
+/// The hit points the player starts with.
+pub const PLAYER_HP: u32 = 100;
+
+#[derive(Debug, Clone)]
+pub struct Character {
+    hp: u32,
+    damage: u32,
+    armor: u32,
+}
+
+impl Character {
+    fn equipped(hp: u32, loadout: &Loadout) -> Character {
+        Character {
+            hp,
+            damage: loadout.damage,
+            armor: loadout.armor,
+        }
+    }
+
+    /// The rounds of attacks it takes to bring the enemy down, every attack doing at least 1 damage.
+    fn rounds_to_beat(&self, enemy: &Character) -> u32 {
+        let damage = self.damage.saturating_sub(enemy.armor).max(1);
+
+        enemy.hp.div_ceil(damage)
+    }
+
+    /// The rounds the character has to spare when it fights the enemy, striking first. It wins when
+    /// the margin is not negative.
+    fn margin(&self, enemy: &Character) -> i64 {
+        i64::from(enemy.rounds_to_beat(self)) - i64::from(self.rounds_to_beat(enemy))
+    }
+}
+
+/// A loadout, and how the fight against the boss goes with it.
+#[derive(Debug, Clone)]
+pub struct Outcome<'s> {
+    pub loadout: Loadout<'s>,
+    pub margin: i64,
+}
+
+impl<'s> Outcome<'s> {
+    pub fn wins(&self) -> bool {
+        self.margin >= 0
+    }
+}
+
+/// Fights the boss with every loadout the shop allows.
+pub fn outcomes<'s>(shop: &'s Shop, boss: &Character) -> Vec<Outcome<'s>> {
+    shop.loadouts()
+        .into_iter()
+        .map(|loadout| {
+            let margin = Character::equipped(PLAYER_HP, &loadout).margin(boss);
+
+            Outcome { loadout, margin }
+        })
+        .collect()
+}
+
+/// The winning outcomes no other one beats both on cost and on margin, from the cheapest.
+pub fn pareto_front<'s>(outcomes: &[Outcome<'s>]) -> Vec<Outcome<'s>> {
+    let mut wins = outcomes.iter().filter(|outcome| outcome.wins()).collect::<Vec<&Outcome>>();
+    wins.sort_by_key(|outcome| (outcome.loadout.cost, -outcome.margin));
+
+    let mut front: Vec<Outcome> = Vec::new();
+
+    for outcome in wins {
+        if front.last().is_none_or(|best| outcome.margin > best.margin) {
+            front.push(outcome.clone());
+        }
+    }
+
+    front
+}
+
+/// The Pareto front as a table, one loadout per line.
+pub fn pareto_report(front: &[Outcome]) -> String {
+    let mut report = "cost  margin  items\n".to_string();
+
+    for outcome in front {
+        report += &format!(
+            "{:>4}  {:>6}  {}\n",
+            outcome.loadout.cost,
+            outcome.margin,
+            outcome.loadout.names().join(", ")
+        );
+    }
+
+    report
+}
+
+pub fn parse_boss(input: &str) -> AocResult<Character> {
+    let mut stats = input.lines().map(|line| {
+        let mut parts = line.split(": ");
+
+        match (parts.next(), parts.next()) {
+            (Some(_), Some(val)) => val.trim().parse::<u32>().map_err(AocError::from),
+            _ => Err(AocError::parse(format!("Invalid boss stat: {:?}", line))),
+        }
+    });
+
+    let mut next_stat = || stats.next().unwrap_or_else(|| Err(AocError::parse("Missing boss stat")));
+
+    Ok(Character {
+        hp: next_stat()?,
+        damage: next_stat()?,
+        armor: next_stat()?,
+    })
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Character;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_boss(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let shop = Shop::standard();
+
+        outcomes(&shop, input)
+            .iter()
+            .filter(|outcome| outcome.wins())
+            .map(|outcome| outcome.loadout.cost)
+            .min()
+            .map(|cost| cost.to_string())
+            .ok_or_else(|| AocError::unsolvable("No loadout beats the boss."))
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let shop = Shop::standard();
+
+        outcomes(&shop, input)
+            .iter()
+            .filter(|outcome| !outcome.wins())
+            .map(|outcome| outcome.loadout.cost)
+            .max()
+            .map(|cost| cost.to_string())
+            .ok_or_else(|| AocError::unsolvable("Every loadout beats the boss."))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{outcomes, pareto_front, Character};
+    use shop::Shop;
+
+    #[test]
+    fn fight_test() {
+        let player = Character {
+            hp: 8,
+            damage: 5,
+            armor: 5,
+        };
+
+        let boss = Character {
+            hp: 12,
+            damage: 7,
+            armor: 2,
+        };
+
+        // the player hits for 3 and the boss for 2, so both need 4 rounds, and the player strikes first
+        assert_eq!(4, player.rounds_to_beat(&boss));
+        assert_eq!(4, boss.rounds_to_beat(&player));
+        assert_eq!(0, player.margin(&boss));
+
+        let weaker = Character { hp: 6, ..player };
+        assert_eq!(-1, weaker.margin(&boss));
+
+        // armor never stops the damage completely
+        let tank = Character { armor: 10, ..boss };
+        assert_eq!(12, player.rounds_to_beat(&tank));
+    }
+
+    #[test]
+    fn pareto_front_test() {
+        let shop = Shop::standard();
+        let boss = Character {
+            hp: 100,
+            damage: 8,
+            armor: 2,
+        };
+
+        let all = outcomes(&shop, &boss);
+        let front = pareto_front(&all);
+
+        assert_eq!(91, front[0].loadout.cost);
+        assert!(front.windows(2).all(|pair| pair[0].loadout.cost < pair[1].loadout.cost && pair[0].margin < pair[1].margin));
+        assert!(all
+            .iter()
+            .filter(|outcome| outcome.wins())
+            .all(|outcome| front.iter().any(|best| best.loadout.cost <= outcome.loadout.cost && best.margin >= outcome.margin)));
+    }
+}
@@ -0,0 +1,307 @@
+//! The item shop, written as the puzzle lists it:
+//!
+//! ```text
+//! Weapons:    Cost  Damage  Armor
+//! Dagger        8     4       0
+//! Shortsword   10     5       0
+//!
+//! Rings:      Cost  Damage  Armor
+//! Damage +1    25     1       0
+//!
+//! Slots:      Min  Max
+//! Weapons       1    1
+//! Rings         0    2
+//! ```
+//!
+//! The optional `Slots` table says how many items of each category have to be bought, at least and
+//! at most. A category it does not list takes zero or one item. No item is bought twice.
+
+use std::str::FromStr;
+
+use aoc_common::AocError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub name: String,
+    pub cost: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Category {
+    pub name: String,
+    pub items: Vec<Item>,
+    /// The number of items that have to be bought from the category, at least and at most.
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shop {
+    categories: Vec<Category>,
+}
+
+/// A set of items bought together, with their total stats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loadout<'s> {
+    pub items: Vec<&'s Item>,
+    pub cost: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl<'s> Loadout<'s> {
+    fn with(&self, item: &'s Item) -> Loadout<'s> {
+        let mut items = self.items.clone();
+        items.push(item);
+
+        Loadout {
+            items,
+            cost: self.cost + item.cost,
+            damage: self.damage + item.damage,
+            armor: self.armor + item.armor,
+        }
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.items.iter().map(|item| item.name.as_str()).collect()
+    }
+}
+
+/// The ways to pick between `min` and `max` of the first `count` indices, each in increasing order.
+fn choices(count: usize, min: usize, max: usize) -> Vec<Vec<usize>> {
+    let mut chosen = vec![Vec::new()];
+    let mut all = Vec::new();
+
+    // extends every choice of the last size by one index greater than its last one
+    for size in 0..=max.min(count) {
+        if size >= min {
+            all.extend(chosen.iter().cloned());
+        }
+
+        chosen = chosen
+            .iter()
+            .flat_map(|choice| {
+                let start = choice.last().map_or(0, |&last| last + 1);
+
+                (start..count).map(move |idx| {
+                    let mut longer = choice.clone();
+                    longer.push(idx);
+                    longer
+                })
+            })
+            .collect();
+    }
+
+    all
+}
+
+impl Shop {
+    /// The shop of the puzzle.
+    pub fn standard() -> Shop {
+        include_str!("../shop.txt").parse().expect("the built-in shop is valid")
+    }
+
+    pub fn categories(&self) -> &[Category] {
+        &self.categories
+    }
+
+    /// Every loadout the slot rules allow.
+    pub fn loadouts(&self) -> Vec<Loadout<'_>> {
+        let mut loadouts = vec![Loadout {
+            items: Vec::new(),
+            cost: 0,
+            damage: 0,
+            armor: 0,
+        }];
+
+        for category in &self.categories {
+            let picks = choices(category.items.len(), category.min, category.max);
+
+            loadouts = loadouts
+                .iter()
+                .flat_map(|loadout| {
+                    picks.iter().map(move |pick| {
+                        pick.iter().fold(loadout.clone(), |bought, &idx| bought.with(&category.items[idx]))
+                    })
+                })
+                .collect();
+        }
+
+        loadouts
+    }
+}
+
+/// The last `count` words of the line parsed as numbers, and the words before them.
+fn split_row(line: &str, count: usize) -> Option<(String, Vec<&str>)> {
+    let words = line.split_whitespace().collect::<Vec<&str>>();
+
+    if words.len() <= count {
+        return None;
+    }
+
+    let (name, values) = words.split_at(words.len() - count);
+
+    Some((name.join(" "), values.to_vec()))
+}
+
+enum Table {
+    Items(usize),
+    Slots,
+}
+
+impl FromStr for Shop {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut categories: Vec<Category> = Vec::new();
+        let mut slots = Vec::new();
+        let mut table = None;
+
+        for (idx, line) in s.lines().enumerate() {
+            let error = |message: String| AocError::parse_at(idx + 1, 1, message);
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some((name, columns)) = line.split_once(':') {
+                let name = name.trim();
+                let columns = columns.split_whitespace().collect::<Vec<&str>>();
+
+                table = Some(match columns.as_slice() {
+                    ["Min", "Max"] if name == "Slots" => Table::Slots,
+                    ["Cost", "Damage", "Armor"] => {
+                        if categories.iter().any(|category| category.name == name) {
+                            return Err(error(format!("The category {} is listed twice", name)));
+                        }
+
+                        categories.push(Category {
+                            name: name.to_string(),
+                            items: Vec::new(),
+                            min: 0,
+                            max: 1,
+                        });
+
+                        Table::Items(categories.len() - 1)
+                    }
+                    _ => return Err(error(format!("Invalid table header: {:?}", line.trim()))),
+                });
+
+                continue;
+            }
+
+            let number = |value: &str| {
+                value
+                    .parse::<u32>()
+                    .map_err(|e| error(format!("Invalid number {:?}: {}", value, e)))
+            };
+
+            match table {
+                Some(Table::Items(category)) => {
+                    let (name, values) =
+                        split_row(line, 3).ok_or_else(|| error(format!("Invalid item: {:?}", line.trim())))?;
+
+                    if categories[category].items.iter().any(|item| item.name == name) {
+                        return Err(error(format!("The item {} is listed twice", name)));
+                    }
+
+                    categories[category].items.push(Item {
+                        name,
+                        cost: number(values[0])?,
+                        damage: number(values[1])?,
+                        armor: number(values[2])?,
+                    });
+                }
+                Some(Table::Slots) => {
+                    let (name, values) =
+                        split_row(line, 2).ok_or_else(|| error(format!("Invalid slot rule: {:?}", line.trim())))?;
+                    let (min, max) = (number(values[0])? as usize, number(values[1])? as usize);
+
+                    if min > max {
+                        return Err(error(format!("{} has to have at least {} and at most {} items", name, min, max)));
+                    }
+
+                    slots.push((name, min, max, idx + 1));
+                }
+                None => return Err(error("An item outside of any table".to_string())),
+            }
+        }
+
+        for (name, min, max, line) in slots {
+            let category = categories
+                .iter_mut()
+                .find(|category| category.name == name)
+                .ok_or_else(|| AocError::parse_at(line, 1, format!("There is no category {}", name)))?;
+
+            if min > category.items.len() {
+                return Err(AocError::parse_at(
+                    line,
+                    1,
+                    format!("{} has only {} items, {} have to be bought", name, category.items.len(), min),
+                ));
+            }
+
+            category.min = min;
+            category.max = max;
+        }
+
+        Ok(Shop { categories })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{choices, Shop};
+    use aoc_common::AocError;
+
+    #[test]
+    fn choices_test() {
+        assert_eq!(vec![vec![0], vec![1], vec![2]], choices(3, 1, 1));
+        assert_eq!(vec![vec![], vec![0], vec![1], vec![0, 1]], choices(2, 0, 2));
+        assert_eq!(22, choices(6, 0, 2).len());
+        assert!(choices(1, 2, 2).is_empty());
+    }
+
+    #[test]
+    fn parse_test() {
+        let shop = Shop::standard();
+
+        assert_eq!(3, shop.categories().len());
+        assert_eq!("Damage +1", shop.categories()[2].items[0].name);
+        assert_eq!((1, 1), (shop.categories()[0].min, shop.categories()[0].max));
+        assert_eq!((0, 2), (shop.categories()[2].min, shop.categories()[2].max));
+
+        // a weapon, maybe an armor, and up to two different rings
+        assert_eq!(5 * 6 * 22, shop.loadouts().len());
+
+        let loadout = &shop.loadouts()[1];
+        assert_eq!(vec!["Dagger", "Damage +1"], loadout.names());
+        assert_eq!((33, 5, 0), (loadout.cost, loadout.damage, loadout.armor));
+    }
+
+    #[test]
+    fn error_test() {
+        let error = |input: &str| input.parse::<Shop>().unwrap_err();
+        let shop = "Weapons:    Cost  Damage  Armor\nDagger        8     4       0\n";
+
+        assert_eq!(
+            AocError::parse_at(2, 1, "Invalid number \"x\": invalid digit found in string"),
+            error(&shop.replace("4 ", "x "))
+        );
+        assert_eq!(AocError::parse_at(1, 1, "An item outside of any table"), error("Dagger 8 4 0"));
+        assert_eq!(
+            AocError::parse_at(4, 1, "There is no category Rings"),
+            error(&format!("{}Slots: Min Max\nRings 0 2", shop))
+        );
+        assert_eq!(
+            AocError::parse_at(4, 1, "Weapons has only 1 items, 2 have to be bought"),
+            error(&format!("{}Slots: Min Max\nWeapons 2 2", shop))
+        );
+        assert_eq!(
+            AocError::parse_at(3, 1, "Invalid table header: \"Weapons: Cost Armor\""),
+            error(&format!("{}Weapons: Cost Armor", shop))
+        );
+    }
+}
@@ -0,0 +1,557 @@
+extern crate aoc_common;
+extern crate regex;
+extern crate serde;
+extern crate toml;
+
+pub mod replay;
+pub mod rulebook;
+
+use regex::Regex;
+
+use std::hash::{Hash, Hasher};
+
+use aoc_common::search::{self, SearchState};
+use aoc_common::{AocError, AocResult, Solver};
+
+use rulebook::{Rule, Rulebook, Spell};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Player {
+    hp: u32,
+    mana: u32,
+    mana_spent: u32,
+}
+
+impl Player {
+    pub fn new(hp: u32, mana: u32) -> Player {
+        Player {
+            hp,
+            mana,
+            mana_spent: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Boss {
+    hp: u32,
+    damage: u32,
+}
+
+impl Boss {
+    pub fn new(hp: u32, damage: u32) -> Boss {
+        Boss { hp, damage }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum ActionResult {
+    PlayerDead,
+    BossDead,
+    BothAlive,
+}
+
+/// The fight at the start of a player's turn, or after it ended, under the rules of a rulebook
+/// and a difficulty.
+#[derive(Clone, Debug)]
+struct State<'r> {
+    book: &'r Rulebook,
+    rules: &'r [Rule],
+    player: Player,
+    boss: Boss,
+    /// The turns left of the effect of each spell of the book, 0 when it is not active.
+    timers: Vec<u8>,
+}
+
+// Only states under the same rules are ever compared, so the rules are left out.
+impl<'r> PartialEq for State<'r> {
+    fn eq(&self, other: &State<'r>) -> bool {
+        self.player == other.player && self.boss == other.boss && self.timers == other.timers
+    }
+}
+
+impl<'r> Eq for State<'r> {}
+
+impl<'r> Hash for State<'r> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.player.hash(state);
+        self.boss.hash(state);
+        self.timers.hash(state);
+    }
+}
+
+impl<'r> State<'r> {
+    fn new(book: &'r Rulebook, rules: &'r [Rule], player: &Player, boss: &Boss) -> State<'r> {
+        State {
+            book,
+            rules,
+            player: player.clone(),
+            boss: boss.clone(),
+            timers: vec![0; book.spells().count()],
+        }
+    }
+
+    fn active_effects(&self) -> impl Iterator<Item = Spell> + '_ {
+        self.book.spells().filter(move |spell| self.timers[spell.index()] > 0)
+    }
+
+    /// The armor of the active effects.
+    fn armor(&self) -> u32 {
+        self.active_effects()
+            .filter_map(|spell| self.book.definition(spell).effect.as_ref())
+            .map(|effect| effect.armor)
+            .sum()
+    }
+
+    /// The damage of the boss' attacks, with the rules that make it enrage.
+    fn boss_damage(&self) -> u32 {
+        let rage = self
+            .rules
+            .iter()
+            .map(|rule| match *rule {
+                Rule::Enrage { below, damage } if self.boss.hp <= below => damage,
+                _ => 0,
+            })
+            .sum::<u32>();
+
+        self.boss.damage + rage
+    }
+
+    /// The hit points an attack takes, after the armor.
+    fn damage_taken(&self, damage: u32) -> u32 {
+        damage.saturating_sub(self.armor()).max(1)
+    }
+
+    fn hurt_boss(&mut self, damage: u32) -> ActionResult {
+        self.boss.hp = self.boss.hp.saturating_sub(damage);
+
+        if self.boss.hp == 0 {
+            ActionResult::BossDead
+        } else {
+            ActionResult::BothAlive
+        }
+    }
+
+    fn hurt_player(&mut self, damage: u32) -> ActionResult {
+        self.player.hp = self.player.hp.saturating_sub(damage);
+
+        if self.player.hp == 0 {
+            ActionResult::PlayerDead
+        } else {
+            ActionResult::BothAlive
+        }
+    }
+
+    /// The hit points the rules take from the player at the start of their turn.
+    fn bleeding(&self) -> u32 {
+        self.rules
+            .iter()
+            .map(|rule| match *rule {
+                Rule::Bleed { hp } => hp,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn apply_effects(&mut self) -> ActionResult {
+        for spell in self.book.spells() {
+            if self.timers[spell.index()] == 0 {
+                continue;
+            }
+
+            if let Some(ref effect) = self.book.definition(spell).effect {
+                self.player.hp += effect.heal;
+                self.player.mana += effect.mana;
+                self.boss.hp = self.boss.hp.saturating_sub(effect.damage);
+            }
+
+            self.timers[spell.index()] -= 1;
+        }
+
+        self.hurt_boss(0)
+    }
+
+    fn castable_spells(&self) -> Vec<Spell> {
+        self.book
+            .spells()
+            .filter(|&spell| self.player.mana >= self.book.definition(spell).cost && self.timers[spell.index()] == 0)
+            .collect()
+    }
+
+    fn cast(&mut self, spell: Spell) -> ActionResult {
+        if self.timers[spell.index()] > 0 {
+            panic!("Spell already in effect: {}", self.book.name(spell));
+        }
+
+        let def = self.book.definition(spell);
+
+        self.player.mana -= def.cost;
+        self.player.mana_spent += def.cost;
+        self.player.hp += def.heal;
+
+        if let Some(ref effect) = def.effect {
+            self.timers[spell.index()] = effect.turns;
+        }
+
+        self.hurt_boss(def.damage)
+    }
+
+    fn boss_action(&mut self) -> ActionResult {
+        let damage = self.damage_taken(self.boss_damage());
+
+        self.hurt_player(damage)
+    }
+
+    /// Plays a round for each spell the player can cast, up to the start of the player's next
+    /// turn. The states where the boss died end the fight early.
+    fn play_round(&self) -> Vec<(Option<Spell>, State<'r>)> {
+        let mut state = self.clone();
+
+        if state.hurt_player(state.bleeding()) == ActionResult::PlayerDead {
+            return Vec::new();
+        }
+
+        // Apply effects before player's turn.
+        if state.apply_effects() == ActionResult::BossDead {
+            return vec![(None, state)];
+        }
+
+        // Let player cast all available spells, then execute boss' turn for each of them.
+        state
+            .castable_spells()
+            .into_iter()
+            .filter_map(|spell| {
+                let mut next = state.clone();
+
+                if next.cast(spell) == ActionResult::BossDead || next.apply_effects() == ActionResult::BossDead {
+                    return Some((Some(spell), next));
+                }
+
+                if next.boss_action() == ActionResult::PlayerDead {
+                    None
+                } else {
+                    Some((Some(spell), next))
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'r> SearchState for State<'r> {
+    type Cost = u32;
+
+    fn neighbors(&self) -> Vec<(State<'r>, u32)> {
+        if self.boss.hp == 0 {
+            return Vec::new();
+        }
+
+        self.play_round()
+            .into_iter()
+            .map(|(_, next)| {
+                let mana = next.player.mana_spent - self.player.mana_spent;
+                (next, mana)
+            })
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.boss.hp == 0
+    }
+}
+
+/// The spells to cast, in order, to win a battle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Strategy {
+    pub spells: Vec<Spell>,
+    pub mana: u32,
+}
+
+/// Finds the strategy spending the least mana to beat the boss under the rules of the book and
+/// the difficulty, if the player can win at all.
+pub fn find_best_strategy(book: &Rulebook, rules: &[Rule], player: &Player, boss: &Boss) -> Option<Strategy> {
+    let result = search::dijkstra(State::new(book, rules, player, boss))?;
+
+    // Replays each round of the path to find the spell leading to the next one. The last round
+    // casts nothing when the effects kill the boss before the player's turn.
+    let spells = result
+        .path
+        .windows(2)
+        .filter_map(|rounds| {
+            rounds[0]
+                .play_round()
+                .into_iter()
+                .find(|(_, next)| *next == rounds[1])
+                .and_then(|(spell, _)| spell)
+        })
+        .collect();
+
+    Some(Strategy {
+        spells,
+        mana: result.cost,
+    })
+}
+
+/// The player the puzzle fights with.
+pub const PLAYER_HP: u32 = 50;
+pub const PLAYER_MANA: u32 = 500;
+
+pub fn parse_boss(input: &str) -> AocResult<Boss> {
+    let mut lines = input.lines();
+
+    let hp_line = lines.next().ok_or_else(|| AocError::parse("Missing boss hit points"))?;
+    let damage_line = lines.next().ok_or_else(|| AocError::parse("Missing boss damage"))?;
+
+    let hp_regex = Regex::new(r"^Hit Points: (?P<hp>[:digit:]+)$").unwrap();
+    let damage_regex = Regex::new(r"^Damage: (?P<dmg>[:digit:]+)$").unwrap();
+
+
+    let boss_hp = hp_regex.captures(hp_line.trim())
+                          .and_then(|caps| caps.name("hp"))
+                          .ok_or_else(|| AocError::parse(format!("Invalid hit points: {:?}", hp_line)))?
+                          .parse::<u32>().map_err(AocError::from)?;
+
+    let boss_damage = damage_regex.captures(damage_line.trim())
+                                  .and_then(|caps| caps.name("dmg"))
+                                  .ok_or_else(|| AocError::parse(format!("Invalid damage: {:?}", damage_line)))?
+                                  .parse::<u32>().map_err(AocError::from)?;
+
+
+    Ok(Boss::new(boss_hp, boss_damage))
+}
+
+fn solve(boss: &Boss, difficulty: &str) -> AocResult<String> {
+    let book = Rulebook::standard();
+    let player = Player::new(PLAYER_HP, PLAYER_MANA);
+
+    find_best_strategy(&book, book.difficulty(difficulty)?, &player, boss)
+        .map(|strategy| strategy.mana.to_string())
+        .ok_or_else(|| AocError::unsolvable("The boss cannot be beaten."))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Boss;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_boss(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        solve(input, "normal")
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        solve(input, "hard")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Player;
+    use super::Boss;
+    use super::State;
+    use super::ActionResult;
+    use super::find_best_strategy;
+    use rulebook::{Rule, Rulebook, Spell};
+
+    fn check_hp(state: &State, player_hp: u32, boss_hp: u32) {
+        assert_eq!(state.player.hp, player_hp);
+        assert_eq!(state.boss.hp, boss_hp);
+    }
+
+    fn spell(book: &Rulebook, name: &str) -> Spell {
+        book.spell(name).unwrap()
+    }
+
+    #[test]
+    fn battle_mechanics_test() {
+        let book = Rulebook::standard();
+
+        let p = Player::new(10, 250);
+
+        let b = Boss::new(13, 8);
+
+        let mut state = State::new(&book, &[], &p, &b);
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 10, 13);
+
+        assert_eq!(state.cast(spell(&book, "Poison")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 10, 13);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 10, 10);
+
+        assert_eq!(state.boss_action(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 10);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 7);
+
+        assert_eq!(state.cast(spell(&book, "Magic Missile")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 3);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BossDead);
+        check_hp(&state, 2, 0);
+
+        assert_eq!(state.timers[spell(&book, "Poison").index()], 3);
+    }
+
+    #[test]
+    fn battle_mechanics_2_test() {
+        let book = Rulebook::standard();
+
+        let p = Player::new(10, 250);
+
+        let b = Boss::new(14, 8);
+
+        let mut state = State::new(&book, &[], &p, &b);
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 10, 14);
+
+        assert_eq!(state.cast(spell(&book, "Recharge")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 10, 14);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 10, 14);
+
+        assert_eq!(state.boss_action(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 14);
+
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 14);
+
+        assert_eq!(state.cast(spell(&book, "Shield")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 14);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 14);
+
+        assert_eq!(state.boss_action(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 1, 14);
+
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 1, 14);
+
+        assert_eq!(state.cast(spell(&book, "Drain")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 3, 12);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 3, 12);
+
+        assert_eq!(state.boss_action(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 12);
+
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 12);
+
+        assert_eq!(state.cast(spell(&book, "Poison")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 12);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 2, 9);
+
+        assert_eq!(state.boss_action(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 1, 9);
+
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BothAlive);
+        check_hp(&state, 1, 6);
+
+        assert_eq!(state.cast(spell(&book, "Magic Missile")),
+                   ActionResult::BothAlive);
+        check_hp(&state, 1, 2);
+
+
+        assert_eq!(state.apply_effects(),
+                   ActionResult::BossDead);
+        check_hp(&state, 1, 0);
+
+    }
+
+    #[test]
+    fn lowest_mana_test() {
+        let book = Rulebook::standard();
+        let player = Player::new(10, 250);
+        let hard = book.difficulty("hard").unwrap();
+
+        // Poison and Magic Missile, as in the first example battle
+        let strategy = find_best_strategy(&book, &[], &player, &Boss::new(13, 8)).unwrap();
+        assert_eq!(226, strategy.mana);
+        assert_eq!(vec![spell(&book, "Poison"), spell(&book, "Magic Missile")], strategy.spells);
+
+        let strategy = find_best_strategy(&book, &[], &player, &Boss::new(14, 8)).unwrap();
+        assert_eq!(strategy.mana, strategy.spells.iter().map(|&spell| book.definition(spell).cost).sum::<u32>());
+
+        // losing a hit point every turn, the player dies before the poison kills the boss
+        assert_eq!(None, find_best_strategy(&book, hard, &Player::new(2, 250), &Boss::new(13, 8)));
+    }
+
+    #[test]
+    fn custom_rules_test() {
+        let book = Rulebook::standard();
+        let player = Player::new(10, 250);
+        let boss = Boss::new(13, 8);
+
+        // the boss hits for 8 until the poison brings it down to 10, then for 10
+        let enraged = [Rule::Enrage { below: 10, damage: 2 }];
+        let mut state = State::new(&book, &enraged, &player, &boss);
+        assert_eq!(8, state.boss_damage());
+        state.boss.hp = 10;
+        assert_eq!(10, state.boss_damage());
+
+        // an angry boss kills the player before the poison does its work, so the cheap win is gone
+        assert!(find_best_strategy(&book, &enraged, &player, &boss).is_none());
+
+        // rules add up
+        let bleeding = [Rule::Bleed { hp: 1 }, Rule::Bleed { hp: 2 }];
+        assert_eq!(3, State::new(&book, &bleeding, &player, &boss).bleeding());
+
+        // a rulebook with a single, stronger missile
+        let book = "[[spell]]\nname = \"Fireball\"\ncost = 100\ndamage = 13".parse::<Rulebook>().unwrap();
+        let strategy = find_best_strategy(&book, &[], &player, &boss).unwrap();
+        assert_eq!(100, strategy.mana);
+        assert_eq!(vec![spell(&book, "Fireball")], strategy.spells);
+    }
+}
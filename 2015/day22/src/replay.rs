@@ -0,0 +1,458 @@
+//! Replays a strategy turn by turn, and writes the battle log the way the puzzle describes the
+//! example battles:
+//!
+//! ```text
+//! -- Player turn --
+//! - Player has 10 hit points, 0 armor, 250 mana
+//! - Boss has 13 hit points
+//! Player casts Poison.
+//!
+//! -- Boss turn --
+//! - Player has 10 hit points, 0 armor, 77 mana
+//! - Boss has 13 hit points
+//! Poison deals 3 damage; its timer is now 5.
+//! Boss attacks for 8 damage.
+//! ```
+
+use std::fmt;
+
+use aoc_common::{AocError, AocResult};
+
+use rulebook::{Rule, Rulebook, Spell};
+use {ActionResult, Boss, Player, State};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Player,
+    Boss,
+}
+
+impl Side {
+    fn name(self) -> &'static str {
+        match self {
+            Side::Player => "player",
+            Side::Boss => "boss",
+        }
+    }
+}
+
+/// The string as a JSON string literal, quotes included. Spell names come from the rulebook, so
+/// they can hold anything.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The player loses hit points at the start of their turn, by the rules of the difficulty.
+    Bleed { hp: u32 },
+    /// An effect of the spell takes place, the timer is what remains of it afterwards.
+    Effect { spell: Spell, timer: u8 },
+    Cast(Spell),
+    /// The boss attacks with its damage, and the player takes `dealt` hit points of it.
+    Attack { damage: u32, armor: u32, dealt: u32 },
+}
+
+fn hit_points(hp: u32) -> String {
+    if hp == 1 {
+        "1 hit point".to_string()
+    } else {
+        format!("{} hit points", hp)
+    }
+}
+
+/// Joins the clauses like "dealing 2 damage, and healing 2 hit points".
+fn clauses(parts: &[String], separator: &str) -> String {
+    match parts.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{}{}{}", rest.join(", "), separator, last),
+        None => String::new(),
+    }
+}
+
+impl Event {
+    /// The lines the event is written as. When the event ends the battle, an effect does not
+    /// mention its timer any more.
+    fn describe(&self, book: &Rulebook, fatal: bool) -> String {
+        match *self {
+            Event::Bleed { hp } => format!("Player loses {}.", hit_points(hp)),
+            Event::Effect { spell, timer } => {
+                let name = book.name(spell);
+                let effect = book.definition(spell).effect.as_ref().expect("only effects have timers");
+
+                let mut parts = Vec::new();
+
+                if effect.damage > 0 {
+                    parts.push(format!("deals {} damage", effect.damage));
+                }
+
+                if effect.heal > 0 {
+                    parts.push(format!("heals {}", hit_points(effect.heal)));
+                }
+
+                if effect.mana > 0 {
+                    parts.push(format!("provides {} mana", effect.mana));
+                }
+
+                let line = match (parts.is_empty(), fatal) {
+                    (true, _) => format!("{}'s timer is now {}.", name, timer),
+                    (false, true) => format!("{} {}.", name, clauses(&parts, " and ")),
+                    (false, false) => format!("{} {}; its timer is now {}.", name, clauses(&parts, " and "), timer),
+                };
+
+                match timer {
+                    0 if fatal => line,
+                    0 if effect.armor > 0 => format!("{}\n{} wears off, decreasing armor by {}.", line, name, effect.armor),
+                    0 => format!("{}\n{} wears off.", line, name),
+                    _ => line,
+                }
+            }
+            Event::Cast(spell) => {
+                let def = book.definition(spell);
+                let mut parts = Vec::new();
+
+                if def.damage > 0 {
+                    parts.push(format!("dealing {} damage", def.damage));
+                }
+
+                if def.heal > 0 {
+                    parts.push(format!("healing {}", hit_points(def.heal)));
+                }
+
+                match def.effect {
+                    Some(ref effect) if effect.armor > 0 => parts.push(format!("increasing armor by {}", effect.armor)),
+                    _ => {}
+                }
+
+                if parts.is_empty() {
+                    format!("Player casts {}.", def.name)
+                } else {
+                    format!("Player casts {}, {}.", def.name, clauses(&parts, ", and "))
+                }
+            }
+            Event::Attack { damage, armor, dealt } if armor > 0 => {
+                format!("Boss attacks for {} - {} = {} damage!", damage, armor, dealt)
+            }
+            Event::Attack { damage, .. } => format!("Boss attacks for {} damage.", damage),
+        }
+    }
+
+    fn to_json(self, book: &Rulebook) -> String {
+        match self {
+            Event::Bleed { hp } => format!("{{\"event\":\"bleed\",\"hp\":{}}}", hp),
+            Event::Effect { spell, timer } => format!(
+                "{{\"event\":\"effect\",\"spell\":{},\"timer\":{}}}",
+                json_string(book.name(spell)),
+                timer
+            ),
+            Event::Cast(spell) => format!(
+                "{{\"event\":\"cast\",\"spell\":{},\"mana\":{}}}",
+                json_string(book.name(spell)),
+                book.definition(spell).cost
+            ),
+            Event::Attack { damage, armor, dealt } => format!(
+                "{{\"event\":\"attack\",\"damage\":{},\"armor\":{},\"dealt\":{}}}",
+                damage, armor, dealt
+            ),
+        }
+    }
+}
+
+/// A turn of the battle: who plays it, how everyone stands at its start, and what happens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turn {
+    pub side: Side,
+    pub player_hp: u32,
+    pub armor: u32,
+    pub mana: u32,
+    pub boss_hp: u32,
+    pub events: Vec<Event>,
+}
+
+impl Turn {
+    fn start(side: Side, state: &State) -> Turn {
+        Turn {
+            side,
+            player_hp: state.player.hp,
+            armor: state.armor(),
+            mana: state.player.mana,
+            boss_hp: state.boss.hp,
+            events: Vec::new(),
+        }
+    }
+
+    /// Applies the effects at the start of the turn, noting the ones that take place.
+    fn apply_effects(&mut self, state: &mut State) -> ActionResult {
+        let active = state.active_effects().collect::<Vec<Spell>>();
+
+        let result = state.apply_effects();
+
+        for spell in active {
+            self.events.push(Event::Effect {
+                spell,
+                timer: state.timers[spell.index()],
+            });
+        }
+
+        result
+    }
+}
+
+/// The turns of a battle, up to the one ending it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BattleLog<'r> {
+    book: &'r Rulebook,
+    pub turns: Vec<Turn>,
+    pub winner: Side,
+}
+
+impl<'r> BattleLog<'r> {
+    /// The whole battle as a JSON object, with a list of turns holding their events.
+    pub fn to_json(&self) -> String {
+        let turns = self
+            .turns
+            .iter()
+            .map(|turn| {
+                format!(
+                    "{{\"side\":{},\"player_hp\":{},\"armor\":{},\"mana\":{},\"boss_hp\":{},\"events\":[{}]}}",
+                    json_string(turn.side.name()),
+                    turn.player_hp,
+                    turn.armor,
+                    turn.mana,
+                    turn.boss_hp,
+                    turn.events.iter().map(|event| event.to_json(self.book)).collect::<Vec<String>>().join(",")
+                )
+            })
+            .collect::<Vec<String>>();
+
+        format!("{{\"winner\":{},\"turns\":[{}]}}", json_string(self.winner.name()), turns.join(","))
+    }
+}
+
+impl<'r> fmt::Display for BattleLog<'r> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, turn) in self.turns.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+
+            let side = match turn.side {
+                Side::Player => "Player",
+                Side::Boss => "Boss",
+            };
+
+            writeln!(f, "-- {} turn --", side)?;
+            writeln!(f, "- Player has {}, {} armor, {} mana", hit_points(turn.player_hp), turn.armor, turn.mana)?;
+            writeln!(f, "- Boss has {}", hit_points(turn.boss_hp))?;
+
+            for (event_idx, event) in turn.events.iter().enumerate() {
+                // the last event of the battle is the one that ends it
+                if idx + 1 == self.turns.len() && event_idx + 1 == turn.events.len() {
+                    let outcome = match self.winner {
+                        Side::Player => "This kills the boss, and the player wins.",
+                        Side::Boss => "This kills the player, and the boss wins.",
+                    };
+                    let line = event.describe(self.book, true);
+
+                    writeln!(f, "{}. {}", line.trim_end_matches(&['.', '!'][..]), outcome)?;
+                } else {
+                    writeln!(f, "{}", event.describe(self.book, false))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Fights the battle under the rules of the book and the difficulty, casting the spells in order.
+/// Fails when a spell cannot be cast, or when the battle does not end exactly with the last spell.
+pub fn replay<'r>(
+    book: &'r Rulebook,
+    rules: &[Rule],
+    player: &Player,
+    boss: &Boss,
+    spells: &[Spell],
+) -> AocResult<BattleLog<'r>> {
+    let mut state = State::new(book, rules, player, boss);
+    let mut turns = Vec::new();
+    let mut spells = spells.iter().cloned();
+
+    let end = |mut turns: Vec<Turn>, turn: Turn, winner: Side, spells: &mut dyn Iterator<Item = Spell>| {
+        turns.push(turn);
+
+        match spells.count() {
+            0 => Ok(BattleLog { book, turns, winner }),
+            left => Err(AocError::runtime(format!("The battle ends with {} spells left to cast", left))),
+        }
+    };
+
+    loop {
+        let mut turn = Turn::start(Side::Player, &state);
+        let bleeding = state.bleeding();
+
+        if bleeding > 0 {
+            turn.events.push(Event::Bleed { hp: bleeding });
+
+            if state.hurt_player(bleeding) == ActionResult::PlayerDead {
+                return end(turns, turn, Side::Boss, &mut spells);
+            }
+        }
+
+        if turn.apply_effects(&mut state) == ActionResult::BossDead {
+            return end(turns, turn, Side::Player, &mut spells);
+        }
+
+        let spell = spells.next().ok_or_else(|| {
+            AocError::runtime(format!("The spells run out on turn {}, with the boss still alive", turns.len() + 1))
+        })?;
+
+        if !state.castable_spells().contains(&spell) {
+            return Err(AocError::runtime(format!(
+                "{} cannot be cast on turn {}",
+                book.name(spell),
+                turns.len() + 1
+            )));
+        }
+
+        turn.events.push(Event::Cast(spell));
+
+        if state.cast(spell) == ActionResult::BossDead {
+            return end(turns, turn, Side::Player, &mut spells);
+        }
+
+        turns.push(turn);
+
+        let mut turn = Turn::start(Side::Boss, &state);
+
+        if turn.apply_effects(&mut state) == ActionResult::BossDead {
+            return end(turns, turn, Side::Player, &mut spells);
+        }
+
+        let damage = state.boss_damage();
+
+        turn.events.push(Event::Attack {
+            damage,
+            armor: state.armor(),
+            dealt: state.damage_taken(damage),
+        });
+
+        if state.boss_action() == ActionResult::PlayerDead {
+            return end(turns, turn, Side::Boss, &mut spells);
+        }
+
+        turns.push(turn);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replay, Event, Side};
+    use rulebook::{Rulebook, Spell};
+    use {Boss, Player};
+
+    fn spells(book: &Rulebook, names: &[&str]) -> Vec<Spell> {
+        names.iter().map(|name| book.spell(name).unwrap()).collect()
+    }
+
+    const FIRST_BATTLE: &str = "-- Player turn --
+- Player has 10 hit points, 0 armor, 250 mana
+- Boss has 13 hit points
+Player casts Poison.
+
+-- Boss turn --
+- Player has 10 hit points, 0 armor, 77 mana
+- Boss has 13 hit points
+Poison deals 3 damage; its timer is now 5.
+Boss attacks for 8 damage.
+
+-- Player turn --
+- Player has 2 hit points, 0 armor, 77 mana
+- Boss has 10 hit points
+Poison deals 3 damage; its timer is now 4.
+Player casts Magic Missile, dealing 4 damage.
+
+-- Boss turn --
+- Player has 2 hit points, 0 armor, 24 mana
+- Boss has 3 hit points
+Poison deals 3 damage. This kills the boss, and the player wins.
+";
+
+    #[test]
+    fn replay_test() {
+        let book = Rulebook::standard();
+        let player = Player::new(10, 250);
+        let log = replay(&book, &[], &player, &Boss::new(13, 8), &spells(&book, &["Poison", "Magic Missile"])).unwrap();
+
+        assert_eq!(Side::Player, log.winner);
+        assert_eq!(FIRST_BATTLE, log.to_string());
+
+        let second = spells(&book, &["Recharge", "Shield", "Drain", "Poison", "Magic Missile"]);
+        let log = replay(&book, &[], &player, &Boss::new(14, 8), &second).unwrap();
+        let text = log.to_string();
+
+        assert_eq!(10, log.turns.len());
+        assert!(text.contains("Boss attacks for 8 - 7 = 1 damage!\n\n-- Player turn --\n- Player has 1 hit point, 7 armor"));
+        assert!(text.contains("Recharge provides 101 mana; its timer is now 0.\nRecharge wears off.\n"));
+        assert!(text.contains("Shield's timer is now 0.\nShield wears off, decreasing armor by 7.\n"));
+    }
+
+    #[test]
+    fn failed_replay_test() {
+        let book = Rulebook::standard();
+        let player = Player::new(10, 250);
+        let boss = Boss::new(13, 8);
+
+        assert!(replay(&book, &[], &player, &boss, &spells(&book, &["Poison", "Poison"])).is_err());
+        assert!(replay(&book, &[], &player, &boss, &spells(&book, &["Poison"])).is_err());
+        assert!(replay(&book, &[], &player, &boss, &spells(&book, &["Poison", "Magic Missile", "Drain"])).is_err());
+        assert!(replay(&book, &[], &player, &boss, &spells(&book, &["Recharge", "Recharge"])).is_err());
+
+        let log = replay(&book, book.difficulty("hard").unwrap(), &player, &boss, &spells(&book, &["Poison"])).unwrap();
+
+        assert_eq!(Side::Boss, log.winner);
+        assert_eq!(vec![Event::Bleed { hp: 1 }], log.turns[2].events);
+        assert!(log.to_string().ends_with("Player loses 1 hit point. This kills the player, and the boss wins.\n"));
+    }
+
+    #[test]
+    fn json_test() {
+        let book = Rulebook::standard();
+        let log = replay(&book, &[], &Player::new(10, 250), &Boss::new(13, 8), &spells(&book, &["Poison", "Magic Missile"])).unwrap();
+        let json = log.to_json();
+
+        assert!(json.starts_with(
+            "{\"winner\":\"player\",\"turns\":[{\"side\":\"player\",\"player_hp\":10,\"armor\":0,\"mana\":250,\"boss_hp\":13,\
+             \"events\":[{\"event\":\"cast\",\"spell\":\"Poison\",\"mana\":173}]},"
+        ));
+        assert!(json.contains("{\"event\":\"attack\",\"damage\":8,\"armor\":0,\"dealt\":8}"));
+        assert!(json.ends_with("\"events\":[{\"event\":\"effect\",\"spell\":\"Poison\",\"timer\":3}]}]}"));
+    }
+
+    #[test]
+    fn json_escape_test() {
+        let rules = include_str!("../rules.toml").replace("\"Magic Missile\"", "'Magic \"Missile\"\\'");
+        let book = rules.parse::<Rulebook>().unwrap();
+        let log = replay(&book, &[], &Player::new(10, 250), &Boss::new(4, 8), &spells(&book, &["Magic \"Missile\"\\"])).unwrap();
+
+        assert!(log.to_json().contains("{\"event\":\"cast\",\"spell\":\"Magic \\\"Missile\\\"\\\\\",\"mana\":53}"));
+    }
+}
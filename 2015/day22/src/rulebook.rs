@@ -0,0 +1,198 @@
+//! The spells a wizard knows and the difficulties a battle can be fought at, read from a TOML
+//! rulebook. The puzzle's own rules are in `rules.toml`.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use aoc_common::{AocError, AocResult};
+
+/// A spell of a rulebook, by its position in the book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Spell(usize);
+
+impl Spell {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// What an effect does at the start of each turn it is active, and the armor it gives meanwhile.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EffectDef {
+    pub turns: u8,
+    #[serde(default)]
+    pub damage: u32,
+    #[serde(default)]
+    pub heal: u32,
+    #[serde(default)]
+    pub mana: u32,
+    #[serde(default)]
+    pub armor: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpellDef {
+    pub name: String,
+    pub cost: u32,
+    /// Done to the boss when the spell is cast.
+    #[serde(default)]
+    pub damage: u32,
+    /// Healed when the spell is cast.
+    #[serde(default)]
+    pub heal: u32,
+    pub effect: Option<EffectDef>,
+}
+
+/// A difficulty modifier. A difficulty is a list of them, which all apply together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Rule {
+    /// The player loses hit points at the start of each of their turns.
+    Bleed { hp: u32 },
+    /// The boss deals more damage once its hit points are down to `below` or less.
+    Enrage { below: u32, damage: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rulebook {
+    #[serde(rename = "spell")]
+    spells: Vec<SpellDef>,
+    #[serde(default, rename = "difficulty")]
+    difficulties: BTreeMap<String, Vec<Rule>>,
+}
+
+impl Rulebook {
+    /// The rules of the puzzle.
+    pub fn standard() -> Rulebook {
+        include_str!("../rules.toml").parse().expect("the built-in rulebook is valid")
+    }
+
+    pub fn spells(&self) -> impl Iterator<Item = Spell> {
+        (0..self.spells.len()).map(Spell)
+    }
+
+    pub fn spell(&self, name: &str) -> Option<Spell> {
+        self.spells.iter().position(|def| def.name == name).map(Spell)
+    }
+
+    pub fn definition(&self, spell: Spell) -> &SpellDef {
+        &self.spells[spell.0]
+    }
+
+    pub fn name(&self, spell: Spell) -> &str {
+        &self.spells[spell.0].name
+    }
+
+    pub fn difficulty(&self, name: &str) -> AocResult<&[Rule]> {
+        self.difficulties
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| AocError::runtime(format!("The rulebook has no {:?} difficulty", name)))
+    }
+
+    pub fn difficulties(&self) -> impl Iterator<Item = &str> {
+        self.difficulties.keys().map(String::as_str)
+    }
+}
+
+/// The 1-based line and column of a byte offset.
+fn location(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+impl FromStr for Rulebook {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let book = toml::from_str::<Rulebook>(s).map_err(|e| {
+            let message = e.message().trim_end().to_string();
+
+            match e.span() {
+                Some(span) => {
+                    let (line, column) = location(s, span.start);
+                    AocError::parse_at(line, column, message)
+                }
+                None => AocError::parse(message),
+            }
+        })?;
+
+        if book.spells.is_empty() {
+            return Err(AocError::parse("The rulebook has no spells"));
+        }
+
+        for (idx, def) in book.spells.iter().enumerate() {
+            if book.spells[..idx].iter().any(|other| other.name == def.name) {
+                return Err(AocError::parse(format!("The spell {} is described twice", def.name)));
+            }
+
+            if def.effect.as_ref().is_some_and(|effect| effect.turns == 0) {
+                return Err(AocError::parse(format!("The effect of {} lasts no turns", def.name)));
+            }
+        }
+
+        Ok(book)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rule, Rulebook};
+    use aoc_common::AocError;
+
+    #[test]
+    fn standard_test() {
+        let book = Rulebook::standard();
+
+        assert_eq!(5, book.spells().count());
+
+        let shield = book.definition(book.spell("Shield").unwrap());
+        assert_eq!(113, shield.cost);
+        assert_eq!(Some(7), shield.effect.as_ref().map(|effect| effect.armor));
+
+        assert_eq!(Ok(&[][..]), book.difficulty("normal"));
+        assert_eq!(Ok(&[Rule::Bleed { hp: 1 }][..]), book.difficulty("hard"));
+        assert!(book.difficulty("easy").is_err());
+        assert_eq!(vec!["hard", "nightmare", "normal"], book.difficulties().collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn error_test() {
+        let error = |input: &str| input.parse::<Rulebook>().unwrap_err();
+
+        let spell = "[[spell]]\nname = \"Zap\"\ncost = 10\n";
+
+        assert!(format!("{}{}", spell, "[difficulty]\nodd = [{ rule = \"bleed\", hp = 1 }]").parse::<Rulebook>().is_ok());
+        assert_eq!(
+            AocError::parse("The spell Zap is described twice"),
+            error(&format!("{}{}", spell, spell))
+        );
+        assert_eq!(
+            AocError::parse("The effect of Zap lasts no turns"),
+            error(&format!("{}effect = {{ turns = 0, damage = 1 }}", spell))
+        );
+
+        match error(&format!("{}{}", spell, "colour = \"blue\"")) {
+            AocError::Parse { line, message, .. } => {
+                assert_eq!(Some(4), line);
+                assert!(message.contains("colour"), "{}", message);
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+
+        match error(&format!("{}{}", spell, "[difficulty]\nodd = [{ rule = \"blizzard\" }]")) {
+            AocError::Parse { line, message, .. } => {
+                assert_eq!(Some(5), line);
+                assert!(message.contains("blizzard"), "{}", message);
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+extern crate aoc2015_day23;
+extern crate aoc_common;
+extern crate criterion;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc2015_day23::{Program, Register, TuringLock};
+use aoc_common::compiled::CompiledMachine;
+use aoc_common::vm::{Machine, RegisterFile};
+
+fn interpreter_vs_compiled(c: &mut Criterion) {
+    let prog = include_str!("../input.txt").parse::<Program>().unwrap();
+
+    let mut regs = RegisterFile::new();
+    regs[Register::A] = 1;
+
+    let mut group = c.benchmark_group("2015/day23");
+
+    group.bench_function("interpreted", |b| {
+        b.iter(|| Machine::<TuringLock>::new(prog.instructions().to_vec(), regs.clone()).run())
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| CompiledMachine::<TuringLock>::new(prog.instructions().to_vec(), regs.clone()).run())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, interpreter_vs_compiled);
+criterion_main!(benches);
@@ -0,0 +1,393 @@
+//! Tools for watching a `Program` execute: an interactive debugger and a trace recorder.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use aoc_common::vm::{self, Status};
+use aoc_common::{AocError, AocResult};
+
+use super::{Program, Register};
+
+const HELP: &str = "commands:
+  break <pc>     stop before executing the instruction at pc
+  delete <pc>    remove a breakpoint
+  watch <reg>    stop after the register changes
+  unwatch <reg>  remove a watchpoint
+  step [n]       execute n instructions (default 1)
+  continue       run until a breakpoint, a watchpoint or the end of the program
+  print          show pc and registers
+  list           show the program with breakpoints and the current pc
+  reset          restart the program with the initial registers
+  quit";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Command {
+    Break(usize),
+    Delete(usize),
+    Watch(Register),
+    Unwatch(Register),
+    Step(usize),
+    Continue,
+    Print,
+    List,
+    Reset,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn register(name: &str) -> Result<Register, AocError> {
+            vm::Register::parse(name).ok_or_else(|| AocError::parse(format!("invalid register: {:?}", name)))
+        }
+
+        let words = s.split_whitespace().collect::<Vec<&str>>();
+
+        match words.as_slice() {
+            ["b", pc] | ["break", pc] => Ok(Command::Break(pc.parse()?)),
+            ["d", pc] | ["delete", pc] => Ok(Command::Delete(pc.parse()?)),
+            ["w", reg] | ["watch", reg] => Ok(Command::Watch(register(reg)?)),
+            ["unwatch", reg] => Ok(Command::Unwatch(register(reg)?)),
+            ["s"] | ["step"] => Ok(Command::Step(1)),
+            ["s", count] | ["step", count] => Ok(Command::Step(count.parse()?)),
+            ["c"] | ["continue"] => Ok(Command::Continue),
+            ["p"] | ["print"] => Ok(Command::Print),
+            ["l"] | ["list"] => Ok(Command::List),
+            ["reset"] => Ok(Command::Reset),
+            ["h"] | ["help"] => Ok(Command::Help),
+            ["q"] | ["quit"] => Ok(Command::Quit),
+            _ => Err(AocError::parse(format!("unknown command: {:?}", s.trim()))),
+        }
+    }
+}
+
+/// Why the debugger gave control back to the user.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Stop {
+    /// The requested number of instructions was executed.
+    Stepped,
+    /// The program counter reached a breakpoint.
+    Breakpoint(usize),
+    /// A watched register changed.
+    Watchpoint { reg: Register, old: usize, new: usize },
+    Halted,
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stop::Stepped => Ok(()),
+            Stop::Breakpoint(pc) => write!(f, "breakpoint at pc {}", pc),
+            Stop::Watchpoint { reg, old, new } => write!(f, "watchpoint: {} changed from {} to {}", reg, old, new),
+            Stop::Halted => write!(f, "program halted"),
+        }
+    }
+}
+
+pub struct Debugger {
+    initial: Program,
+    program: Program,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: Vec<Register>,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Debugger {
+        Debugger {
+            initial: program.clone(),
+            program,
+            breakpoints: BTreeSet::new(),
+            watchpoints: Vec::new(),
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    pub fn watch(&mut self, reg: Register) {
+        if !self.watchpoints.contains(&reg) {
+            self.watchpoints.push(reg);
+        }
+    }
+
+    pub fn unwatch(&mut self, reg: Register) {
+        self.watchpoints.retain(|watched| *watched != reg);
+    }
+
+    /// Executes a single instruction, reporting a watchpoint if it changed a watched register.
+    pub fn step(&mut self) -> AocResult<Stop> {
+        let before = self
+            .watchpoints
+            .iter()
+            .map(|reg| (*reg, self.program.reg(*reg)))
+            .collect::<Vec<(Register, usize)>>();
+
+        let status = self.program.single_step()?;
+
+        // A change made by the last instruction is reported before the halt
+        for (reg, old) in before {
+            let new = self.program.reg(reg);
+
+            if new != old {
+                return Ok(Stop::Watchpoint { reg, old, new });
+            }
+        }
+
+        Ok(if status == Status::Halted {
+            Stop::Halted
+        } else {
+            Stop::Stepped
+        })
+    }
+
+    /// Executes up to `count` instructions, stopping early at watchpoints and the end of the program.
+    pub fn step_n(&mut self, count: usize) -> AocResult<Stop> {
+        for _ in 0..count {
+            match self.step()? {
+                Stop::Stepped => {}
+                stop => return Ok(stop),
+            }
+        }
+
+        Ok(Stop::Stepped)
+    }
+
+    /// Runs until a breakpoint or watchpoint is hit or the program ends.
+    ///
+    /// A breakpoint at the current pc does not stop the first instruction, so continuing from a
+    /// breakpoint makes progress.
+    pub fn cont(&mut self) -> AocResult<Stop> {
+        loop {
+            match self.step()? {
+                Stop::Stepped => {}
+                stop => return Ok(stop),
+            }
+
+            if self.breakpoints.contains(&self.program.pc()) {
+                return Ok(Stop::Breakpoint(self.program.pc()));
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.program = self.initial.clone();
+    }
+
+    /// The program listing, marking breakpoints with `*` and the current instruction with `>`.
+    pub fn listing(&self) -> String {
+        self.program
+            .instructions()
+            .iter()
+            .enumerate()
+            .map(|(pc, instr)| {
+                format!(
+                    "{}{} {:3}  {}\n",
+                    if self.breakpoints.contains(&pc) { '*' } else { ' ' },
+                    if self.program.pc() == pc { '>' } else { ' ' },
+                    pc,
+                    instr
+                )
+            })
+            .collect()
+    }
+
+    /// Executes a command and returns what should be shown to the user, or `None` on `quit`.
+    pub fn execute(&mut self, cmd: Command) -> AocResult<Option<String>> {
+        let output = match cmd {
+            Command::Break(pc) => {
+                self.add_breakpoint(pc);
+                format!("breakpoint set at pc {}", pc)
+            }
+            Command::Delete(pc) => {
+                self.remove_breakpoint(pc);
+                format!("breakpoint at pc {} deleted", pc)
+            }
+            Command::Watch(reg) => {
+                self.watch(reg);
+                format!("watching {}", reg)
+            }
+            Command::Unwatch(reg) => {
+                self.unwatch(reg);
+                format!("stopped watching {}", reg)
+            }
+            Command::Step(count) => {
+                let stop = self.step_n(count)?;
+                self.describe(stop)
+            }
+            Command::Continue => {
+                let stop = self.cont()?;
+                self.describe(stop)
+            }
+            Command::Print => self.program.to_string(),
+            Command::List => self.listing().trim_end().to_string(),
+            Command::Reset => {
+                self.reset();
+                self.program.to_string()
+            }
+            Command::Help => HELP.to_string(),
+            Command::Quit => return Ok(None),
+        };
+
+        Ok(Some(output))
+    }
+
+    /// Reads commands line by line until `quit` or the end of the input. Errors in commands are
+    /// reported to the user and do not end the session.
+    pub fn run_session<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        write!(output, "(day23) ")?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                match line.parse().and_then(|cmd| self.execute(cmd)) {
+                    Ok(Some(text)) => writeln!(output, "{}", text)?,
+                    Ok(None) => return Ok(()),
+                    Err(e) => writeln!(output, "{}", e)?,
+                }
+            }
+
+            write!(output, "(day23) ")?;
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, stop: Stop) -> String {
+        let location = match self.program.instructions().get(self.program.pc()) {
+            Some(instr) => format!("{}  next: {}", self.program, instr),
+            None => self.program.to_string(),
+        };
+
+        match stop {
+            Stop::Stepped => location,
+            stop => format!("{}\n{}", stop, location),
+        }
+    }
+}
+
+/// Runs the program to the end, writing one JSON object per executed instruction.
+///
+/// Each line holds the step number, the address and text of the instruction, and the registers and
+/// program counter after executing it. Returns the number of executed instructions.
+pub fn write_trace<W: Write>(program: &mut Program, mut out: W) -> AocResult<u64> {
+    let mut steps = 0;
+
+    while let Some(instr) = program.instructions().get(program.pc()).cloned() {
+        let pc = program.pc();
+
+        program.single_step()?;
+        steps += 1;
+
+        writeln!(
+            out,
+            "{{\"step\":{},\"pc\":{},\"instr\":\"{}\",\"a\":{},\"b\":{},\"next_pc\":{}}}",
+            steps,
+            pc,
+            instr,
+            program.reg_a(),
+            program.reg_b(),
+            program.pc()
+        )
+        .map_err(|e| AocError::runtime(e.to_string()))?;
+    }
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_trace, Command, Debugger, Stop};
+    use super::super::{Program, Register};
+
+    const TEST_INPUT: &str = "inc a\n\
+                              jio a, +2\n\
+                              tpl a\n\
+                              inc a";
+
+    fn debugger() -> Debugger {
+        Debugger::new(TEST_INPUT.parse::<Program>().unwrap())
+    }
+
+    #[test]
+    fn command_test() {
+        assert_eq!(Command::Break(3), "break 3".parse().unwrap());
+        assert_eq!(Command::Watch(Register::B), "w b".parse().unwrap());
+        assert_eq!(Command::Step(1), "s".parse().unwrap());
+        assert_eq!(Command::Step(10), "step 10".parse().unwrap());
+        assert!("break x".parse::<Command>().is_err());
+        assert!("jump 3".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn breakpoint_test() {
+        let mut dbg = debugger();
+        dbg.add_breakpoint(3);
+
+        assert_eq!(Stop::Breakpoint(3), dbg.cont().unwrap());
+        assert_eq!((3, 1), (dbg.program().pc(), dbg.program().reg_a()));
+
+        // Continuing from the breakpoint runs the rest of the program
+        assert_eq!(Stop::Halted, dbg.cont().unwrap());
+        assert_eq!(2, dbg.program().reg_a());
+
+        dbg.reset();
+        assert_eq!(Stop::Stepped, dbg.step_n(2).unwrap());
+        assert_eq!(3, dbg.program().pc());
+    }
+
+    #[test]
+    fn watchpoint_test() {
+        let mut dbg = debugger();
+        dbg.watch(Register::A);
+
+        assert_eq!(Stop::Watchpoint { reg: Register::A, old: 0, new: 1 }, dbg.cont().unwrap());
+        assert_eq!(Stop::Watchpoint { reg: Register::A, old: 1, new: 2 }, dbg.cont().unwrap());
+        assert_eq!(Stop::Halted, dbg.cont().unwrap());
+    }
+
+    #[test]
+    fn session_test() {
+        let mut dbg = debugger();
+        let mut output = Vec::new();
+
+        dbg.run_session("break 1\nc\nfoo\np\nl\nq\ns\n".as_bytes(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("breakpoint at pc 1\npc: 1, a: 1, b: 0  next: jio a, +2\n"));
+        assert!(output.contains("unknown command"));
+        assert!(output.contains("*>   1  jio a, +2\n"));
+        // Nothing runs after quit
+        assert_eq!(1, dbg.program().pc());
+    }
+
+    #[test]
+    fn trace_test() {
+        let mut prog = TEST_INPUT.parse::<Program>().unwrap();
+        let mut out = Vec::new();
+
+        assert_eq!(3, write_trace(&mut prog, &mut out).unwrap());
+        assert_eq!(
+            "{\"step\":1,\"pc\":0,\"instr\":\"inc a\",\"a\":1,\"b\":0,\"next_pc\":1}\n\
+             {\"step\":2,\"pc\":1,\"instr\":\"jio a, +2\",\"a\":1,\"b\":0,\"next_pc\":3}\n\
+             {\"step\":3,\"pc\":3,\"instr\":\"inc a\",\"a\":2,\"b\":0,\"next_pc\":4}\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+}
@@ -0,0 +1,560 @@
+extern crate aoc_common;
+
+use std::str::FromStr;
+
+use std::fmt;
+
+pub mod debug;
+
+use aoc_common::compiled::{Compile, CompiledMachine, Op};
+use aoc_common::decompile::{Condition, Decompile, Flow};
+use aoc_common::vm::{self, Fault, InstructionSet, Machine, RegisterFile, Status, Step, VmError};
+use aoc_common::{parse_lines, AocError, AocResult, Solver};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reg_name = match *self {
+            Register::A => "a",
+            Register::B => "b",
+        };
+
+        write!(f, "{}", reg_name)
+    }
+}
+
+impl vm::Register for Register {
+    const COUNT: usize = 2;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Register {
+        [Register::A, Register::B][index]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Instruction {
+    Hlf(Register),
+    Tpl(Register),
+    Inc(Register),
+    Jmp(isize),
+    Jie(Register, isize),
+    Jio(Register, isize),
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        fn parse_regname(oper: &str) -> Result<Register, AocError> {
+            vm::Register::parse(oper).ok_or_else(|| AocError::parse(format!("Invalid register: {:?}", oper)))
+        }
+
+        fn parse_offset(oper: &str) -> Result<isize, AocError> {
+            oper.parse::<isize>().map_err(|_| AocError::parse(format!("Invalid offset: {:?}", oper)))
+        }
+
+        let (mnemonic, args) = vm::split_instruction(s)?;
+
+        match mnemonic {
+            "hlf" | "tpl" | "inc" => {
+                let [reg] = vm::operands(mnemonic, &args)?;
+                let reg = parse_regname(reg)?;
+
+                Ok(match mnemonic {
+                    "hlf" => Instruction::Hlf(reg),
+                    "tpl" => Instruction::Tpl(reg),
+                    _ => Instruction::Inc(reg),
+                })
+            }
+            "jmp" => {
+                let [offset] = vm::operands(mnemonic, &args)?;
+
+                Ok(Instruction::Jmp(parse_offset(offset)?))
+            }
+            "jie" | "jio" => {
+                let [reg, offset] = vm::operands(mnemonic, &args)?;
+                let (reg, offset) = (parse_regname(reg)?, parse_offset(offset)?);
+
+                Ok(if mnemonic == "jie" {
+                    Instruction::Jie(reg, offset)
+                } else {
+                    Instruction::Jio(reg, offset)
+                })
+            }
+            other => Err(AocError::parse(format!("Unknown instruction: {:?}", other))),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Instruction::Hlf(reg) => write!(f, "hlf {}", reg),
+            Instruction::Tpl(reg) => write!(f, "tpl {}", reg),
+            Instruction::Inc(reg) => write!(f, "inc {}", reg),
+            Instruction::Jmp(offset) => write!(f, "jmp {:+}", offset),
+            Instruction::Jie(reg, offset) => write!(f, "jie {}, {:+}", reg, offset),
+            Instruction::Jio(reg, offset) => write!(f, "jio {}, {:+}", reg, offset),
+        }
+    }
+}
+
+/// The instructions of the Turing lock computer. Registers can not go negative and overflows are
+/// reported as faults.
+pub struct TuringLock;
+
+impl InstructionSet for TuringLock {
+    type Instr = Instruction;
+    type State = RegisterFile<Register, usize>;
+    type Event = ();
+
+    fn execute(instr: &Instruction, regs: &mut Self::State) -> Result<Step<()>, Fault> {
+        match *instr {
+            Instruction::Hlf(reg) => {
+                regs[reg] /= 2;
+            }
+            Instruction::Tpl(reg) => {
+                regs[reg] = regs[reg].checked_mul(3).ok_or(Fault::Overflow)?;
+            }
+            Instruction::Inc(reg) => {
+                regs[reg] = regs[reg].checked_add(1).ok_or(Fault::Overflow)?;
+            }
+            Instruction::Jmp(offset) => {
+                return Ok(Step::Jump(offset));
+            }
+            Instruction::Jie(reg, offset) => {
+                if regs[reg] % 2 == 0 {
+                    return Ok(Step::Jump(offset));
+                }
+            }
+            Instruction::Jio(reg, offset) => {
+                if regs[reg] == 1 {
+                    return Ok(Step::Jump(offset));
+                }
+            }
+        }
+
+        Ok(Step::Next)
+    }
+}
+
+impl Compile for TuringLock {
+    fn compile(instr: &Instruction) -> Op<TuringLock> {
+        match *instr {
+            Instruction::Hlf(reg) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    regs.slots_mut()[reg] /= 2;
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Tpl(reg) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    let slot = &mut regs.slots_mut()[reg];
+                    *slot = slot.checked_mul(3).ok_or(Fault::Overflow)?;
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Inc(reg) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    let slot = &mut regs.slots_mut()[reg];
+                    *slot = slot.checked_add(1).ok_or(Fault::Overflow)?;
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Jmp(offset) => Box::new(move |_| Ok(Step::Jump(offset))),
+            Instruction::Jie(reg, offset) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    Ok(if regs.slots()[reg] % 2 == 0 {
+                        Step::Jump(offset)
+                    } else {
+                        Step::Next
+                    })
+                })
+            }
+            Instruction::Jio(reg, offset) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    Ok(if regs.slots()[reg] == 1 {
+                        Step::Jump(offset)
+                    } else {
+                        Step::Next
+                    })
+                })
+            }
+        }
+    }
+}
+
+impl Decompile for TuringLock {
+    fn flow(instr: &Instruction) -> Flow {
+        match *instr {
+            Instruction::Jmp(offset) => Flow::Jump(offset),
+            Instruction::Jie(reg, offset) => {
+                Flow::Branch(offset, Condition::new(format!("{} % 2 == 0", reg), format!("{} % 2 != 0", reg)))
+            }
+            Instruction::Jio(reg, offset) => {
+                Flow::Branch(offset, Condition::new(format!("{} == 1", reg), format!("{} != 1", reg)))
+            }
+            _ => Flow::Next,
+        }
+    }
+
+    fn statement(instr: &Instruction) -> String {
+        match *instr {
+            Instruction::Hlf(reg) => format!("{} /= 2", reg),
+            Instruction::Tpl(reg) => format!("{} *= 3", reg),
+            Instruction::Inc(reg) => format!("{} += 1", reg),
+            ref instr => instr.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    machine: Machine<TuringLock>,
+}
+
+impl FromStr for Program {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        let instructions = parse_lines::<Instruction>(s)?;
+
+        Ok(Program {
+            machine: Machine::new(instructions, RegisterFile::new()),
+        })
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "pc: {}, a: {}, b: {}", self.pc(), self.reg_a(), self.reg_b())
+    }
+}
+
+impl Program {
+    pub fn instructions(&self) -> &[Instruction] {
+        self.machine.program()
+    }
+
+    pub fn pc(&self) -> usize {
+        self.machine.pc()
+    }
+
+    pub fn reg(&self, reg: Register) -> usize {
+        self.machine.state()[reg]
+    }
+
+    pub fn set_reg(&mut self, reg: Register, val: usize) {
+        self.machine.state_mut()[reg] = val;
+    }
+
+    pub fn reg_a(&self) -> usize {
+        self.reg(Register::A)
+    }
+
+    pub fn reg_b(&self) -> usize {
+        self.reg(Register::B)
+    }
+
+    pub fn run(&mut self) -> Result<(), VmError> {
+        self.machine.run().map(|_| ())
+    }
+
+    pub fn single_step(&mut self) -> Result<Status<()>, VmError> {
+        self.machine.step()
+    }
+
+    pub fn reset(&mut self) {
+        self.machine.reset();
+    }
+}
+
+
+fn run_with_reg_a(prog: &Program, reg_a: usize) -> AocResult<usize> {
+    let mut regs = RegisterFile::new();
+    regs[Register::A] = reg_a;
+
+    let mut comp = CompiledMachine::<TuringLock>::new(prog.instructions().to_vec(), regs);
+    comp.run()?;
+
+    Ok(comp.state()[Register::B])
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Program;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Program::from_str(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        run_with_reg_a(input, 0).map(|reg_b| reg_b.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        run_with_reg_a(input, 1).map(|reg_b| reg_b.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::Program;
+    use super::Instruction;
+    use super::Register;
+    use super::TuringLock;
+    use aoc_common::compiled::CompiledMachine;
+    use aoc_common::decompile::{self, Cfg, Target};
+    use aoc_common::vm::{Fault, RegisterFile, Status};
+    use std::str::FromStr;
+
+    const TEST_INPUT: &str = "inc a\n\
+                                      jio a, +2\n\
+                                      tpl b\n\
+                                      inc b";
+
+
+    const TEST_INPUT_2: &str = "tpl a\n\
+                                        inc a\n\
+                                        jio b, +8\n\
+                                        inc b\n\
+                                        jie a, +4\n\
+                                        tpl a\n\
+                                        inc a\n\
+                                        jmp +2\n\
+                                        hlf a\n\
+                                        jmp -7";
+
+    #[test]
+    fn parse_test() {
+        let prog1 = Program::from_str(TEST_INPUT).unwrap();
+
+        let expected1 = vec![
+            Instruction::Inc(Register::A),
+            Instruction::Jio(Register::A, 2),
+            Instruction::Tpl(Register::B),
+            Instruction::Inc(Register::B),
+        ];
+
+        assert_eq!(prog1.instructions(), expected1);
+
+        let prog2 = TEST_INPUT_2.parse::<Program>().unwrap();
+
+        let expected2 = vec![
+            Instruction::Tpl(Register::A),
+            Instruction::Inc(Register::A),
+            Instruction::Jio(Register::B, 8),
+            Instruction::Inc(Register::B),
+            Instruction::Jie(Register::A, 4),
+            Instruction::Tpl(Register::A),
+            Instruction::Inc(Register::A),
+            Instruction::Jmp(2),
+            Instruction::Hlf(Register::A),
+            Instruction::Jmp(-7),
+        ];
+
+        assert_eq!(prog2.instructions(), expected2);
+    }
+
+    #[test]
+    fn execution_test() {
+        let mut prog1 = Program::from_str(TEST_INPUT).unwrap();
+
+        assert_eq!(prog1.pc(), 0);
+        assert_eq!(prog1.reg_a(), 0);
+        assert_eq!(prog1.reg_b(), 0);
+
+        // inc a
+        assert!(prog1.single_step().is_ok());
+        assert_eq!(prog1.pc(), 1);
+        assert_eq!(prog1.reg_a(), 1);
+        assert_eq!(prog1.reg_b(), 0);
+
+        // jio a, +2
+        assert!(prog1.single_step().is_ok());
+        assert_eq!(prog1.pc(), 3);
+        assert_eq!(prog1.reg_a(), 1);
+        assert_eq!(prog1.reg_b(), 0);
+
+        // inc b
+        assert!(prog1.single_step().is_ok());
+        assert_eq!(prog1.pc(), 4);
+        assert_eq!(prog1.reg_a(), 1);
+        assert_eq!(prog1.reg_b(), 1);
+
+        assert_eq!(prog1.single_step(), Ok(Status::Halted));
+
+        prog1.reset();
+
+        assert!(prog1.run().is_ok());
+        assert_eq!(prog1.pc(), 4);
+        assert_eq!(prog1.reg_a(), 1);
+        assert_eq!(prog1.reg_b(), 1);
+
+
+        let mut prog2 = Program::from_str(TEST_INPUT_2).unwrap();
+
+        assert_eq!(prog2.pc(), 0);
+        assert_eq!(prog2.reg_a(), 0);
+        assert_eq!(prog2.reg_b(), 0);
+
+
+        // tpl a
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 1);
+        assert_eq!(prog2.reg_a(), 0);
+        assert_eq!(prog2.reg_b(), 0);
+
+        // inc a
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 2);
+        assert_eq!(prog2.reg_a(), 1);
+        assert_eq!(prog2.reg_b(), 0);
+
+        // jio b, +8
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 3);
+        assert_eq!(prog2.reg_a(), 1);
+        assert_eq!(prog2.reg_b(), 0);
+
+        // inc b
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 4);
+        assert_eq!(prog2.reg_a(), 1);
+        assert_eq!(prog2.reg_b(), 1);
+
+        // jie a, +4
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 5);
+        assert_eq!(prog2.reg_a(), 1);
+        assert_eq!(prog2.reg_b(), 1);
+
+        // tpl a
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 6);
+        assert_eq!(prog2.reg_a(), 3);
+        assert_eq!(prog2.reg_b(), 1);
+
+        // inc a
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 7);
+        assert_eq!(prog2.reg_a(), 4);
+        assert_eq!(prog2.reg_b(), 1);
+
+        // jmp +2
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 9);
+        assert_eq!(prog2.reg_a(), 4);
+        assert_eq!(prog2.reg_b(), 1);
+
+        // jmp -7
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 2);
+        assert_eq!(prog2.reg_a(), 4);
+        assert_eq!(prog2.reg_b(), 1);
+
+        // jio b, +8
+        assert!(prog2.single_step().is_ok());
+        assert_eq!(prog2.pc(), 10);
+        assert_eq!(prog2.reg_a(), 4);
+        assert_eq!(prog2.reg_b(), 1);
+
+        assert_eq!(prog1.single_step(), Ok(Status::Halted));
+
+
+        prog2.reset();
+
+        assert!(prog2.run().is_ok());
+        assert_eq!(prog2.pc(), 10);
+        assert_eq!(prog2.reg_a(), 4);
+        assert_eq!(prog2.reg_b(), 1);
+    }
+
+    #[test]
+    fn overflow_test() {
+        let mut prog = Program::from_str("inc a\ntpl a\njmp -1").unwrap();
+
+        assert_eq!(prog.run().err().unwrap().fault,
+                   Fault::Overflow);
+
+        prog.machine.set_pc(0);
+        prog.machine.state_mut()[Register::A] = usize::MAX;
+
+        assert_eq!(prog.single_step().err().unwrap().fault,
+                   Fault::Overflow);
+    }
+
+    #[test]
+    fn compiled_test() {
+        for input in &[TEST_INPUT, TEST_INPUT_2, "inc a\ntpl a\njmp -1"] {
+            let mut prog = Program::from_str(input).unwrap();
+            let mut compiled = CompiledMachine::<TuringLock>::new(prog.instructions().to_vec(), RegisterFile::new());
+
+            assert_eq!(prog.run(), compiled.run().map(|_| ()));
+            assert_eq!(prog.pc(), compiled.pc());
+            assert_eq!(prog.reg_a(), compiled.state()[Register::A]);
+            assert_eq!(prog.reg_b(), compiled.state()[Register::B]);
+        }
+    }
+
+    #[test]
+    fn decompile_test() {
+        let prog = Program::from_str(TEST_INPUT_2).unwrap();
+
+        let expected = "\
+a *= 3
+a += 1
+while b != 1 {
+    b += 1
+    if a % 2 != 0 {
+        a *= 3
+        a += 1
+    } else {
+        a /= 2
+    }
+}
+";
+
+        assert_eq!(expected, decompile::render(&decompile::decompile::<TuringLock>(prog.instructions())));
+
+        let prog = Program::from_str(TEST_INPUT).unwrap();
+        let expected = "\
+a += 1
+if a != 1 {
+    b *= 3
+}
+b += 1
+";
+
+        assert_eq!(expected, decompile::render(&decompile::decompile::<TuringLock>(prog.instructions())));
+
+        let cfg = Cfg::new::<TuringLock>(prog.instructions());
+        let targets = cfg.blocks.iter().map(|block| block.edges.iter().map(|edge| edge.target).collect()).collect::<Vec<Vec<_>>>();
+
+        assert_eq!(vec![vec![Target::Block(2), Target::Block(1)], vec![Target::Block(2)], vec![Target::Exit]], targets);
+    }
+}
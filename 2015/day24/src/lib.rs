@@ -1,8 +1,6 @@
+extern crate aoc_common;
 
-use std::io;
-use std::io::prelude::*;
-
-use std::u64;
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug)]
 struct Arrangement {
@@ -20,13 +18,13 @@ impl Arrangement {
         presents.reverse();
 
         let locations = vec![0; presents.len()];
-        let sum = presents.iter().fold(0, |acc, &x| acc + x) / (num_groups as u32);
+        let sum = presents.iter().sum::<u32>() / (num_groups as u32);
 
         Arrangement {
             present_locations: locations,
             present_sizes: presents,
             single_group_sum: sum,
-            num_groups: num_groups,
+            num_groups,
         }
     }
 
@@ -53,12 +51,7 @@ impl Arrangement {
                 for pres_idx in 0..self.present_locations.len() {
                     if self.present_locations[pres_idx] == 1 {
                         head_len += 1;
-                        qe = if let Some(val) =
-                                    qe.checked_mul(self.present_sizes[pres_idx] as u64) {
-                            val
-                        } else {
-                            u64::MAX
-                        };
+                        qe = qe.saturating_mul(self.present_sizes[pres_idx] as u64);
 
                     } else {
                         tail.push(self.present_sizes[pres_idx]);
@@ -78,11 +71,7 @@ impl Arrangement {
         let mut min_valid_qe_seen = self.present_sizes
             .iter()
             .fold(1u64, |acc, &weight| {
-                if let Some(val) = acc.checked_mul(weight as u64) {
-                    val
-                } else {
-                    u64::MAX
-                }
+                acc.saturating_mul(weight as u64)
             });
 
 
@@ -129,28 +118,28 @@ impl Arrangement {
 }
 
 
-fn main() {
+pub struct Solution;
 
-    let stdin = io::stdin();
-    let presents = stdin.lock()
-        .lines()
-        .map(|weight_str| {
-            weight_str.unwrap()
-                .parse::<u32>()
-                .unwrap()
-        })
-        .collect::<Vec<u32>>();
+impl Solver for Solution {
+    type Input = Vec<u32>;
 
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.lines()
+            .map(|weight_str| weight_str.parse::<u32>().map_err(AocError::from))
+            .collect()
+    }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut arr = Arrangement::new(input.clone(), 3);
 
-    let mut arr = Arrangement::new(presents.clone(), 3);
-    println!("Best QE if divided into three groups: {}",
-             arr.get_best_arrangement());
+        Ok(arr.get_best_arrangement().to_string())
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut arr = Arrangement::new(input.clone(), 4);
 
-    let mut arr2 = Arrangement::new(presents, 4);
-    println!("Best QE if divided into four groups: {}",
-             arr2.get_best_arrangement());
+        Ok(arr.get_best_arrangement().to_string())
+    }
 }
 
 
@@ -1,5 +1,6 @@
+extern crate aoc_common;
 
-use std::env;
+use aoc_common::{AocError, AocResult, Solver};
 
 fn find_code(code_pos: (usize, usize)) -> u64 {
     if code_pos.0 == 0 || code_pos.1 == 0 {
@@ -27,19 +28,38 @@ fn find_code(code_pos: (usize, usize)) -> u64 {
 }
 
 
-fn main() {
-    let mut a = env::args();
+fn parse_position(input: &str) -> AocResult<(usize, usize)> {
+    let mut numbers = input.split(|ch: char| !ch.is_ascii_digit())
+                           .filter(|num_str| !num_str.is_empty())
+                           .map(|num_str| num_str.parse::<usize>().map_err(AocError::from));
 
-    a.next(); // The first argument is the binary name/path
+    let row = numbers.next().ok_or_else(|| AocError::parse("Missing row number"))??;
+    let col = numbers.next().ok_or_else(|| AocError::parse("Missing column number"))??;
 
-    let row = a.next().unwrap().parse::<usize>().unwrap();
-    let col = a.next().unwrap().parse::<usize>().unwrap();
+    if row == 0 || col == 0 {
+        return Err(AocError::parse(format!("Invalid position in matrix: {:?}", (row, col))));
+    }
+
+    Ok((row, col))
+}
 
+pub struct Solution;
 
-    println!("The code at row {}, column {}: {}",
-             row,
-             col,
-             find_code((row, col)));
+impl Solver for Solution {
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_position(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(find_code(*input).to_string())
+    }
+
+    fn part_b(_input: &Self::Input) -> AocResult<String> {
+        // There is no second puzzle on Christmas day.
+        Ok("Merry Christmas!".to_string())
+    }
 }
 
 
@@ -59,18 +79,16 @@ mod tests {
         //  5 |    77061  17552253  28094349   6899651   9250759  31663883
         //  6 | 33071741   6796745  25397450  24659492   1534922  27995004
 
-        let expected = vec![
-            vec![20151125, 18749137, 17289845, 30943339, 10071777, 33511524],
+        let expected = [vec![20151125, 18749137, 17289845, 30943339, 10071777, 33511524],
             vec![31916031, 21629792, 16929656,  7726640, 15514188,  4041754],
             vec![16080970,  8057251,  1601130,  7981243, 11661866, 16474243],
             vec![24592653, 32451966, 21345942,  9380097, 10600672, 31527494],
             vec![   77061, 17552253, 28094349,  6899651,  9250759, 31663883],
-            vec![33071741,  6796745, 25397450, 24659492,  1534922, 27995004],
-        ];
+            vec![33071741,  6796745, 25397450, 24659492,  1534922, 27995004]];
 
-        for row in 0..expected.len() {
-            for col in 0..expected[row].len() {
-                assert_eq!(find_code((row + 1, col + 1)), expected[row][col]);
+        for (row, expected_row) in expected.iter().enumerate() {
+            for (col, expected_code) in expected_row.iter().enumerate() {
+                assert_eq!(find_code((row + 1, col + 1)), *expected_code);
             }
         }
     }
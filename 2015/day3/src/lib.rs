@@ -0,0 +1,56 @@
+extern crate aoc_common;
+
+use std::collections::HashSet;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+fn parse_directions(input: &str) -> AocResult<Vec<(isize, isize)>> {
+    input
+        .trim()
+        .chars()
+        .map(|ch| match ch {
+            '<' => Ok((-1, 0)),
+            '>' => Ok((1, 0)),
+            'v' => Ok((0, -1)),
+            '^' => Ok((0, 1)),
+            other_dir => Err(AocError::parse(format!("Unknown direction: {}", other_dir))),
+        })
+        .collect()
+}
+
+/// Counts the unique houses visited when the santas take turns following the directions.
+fn count_visited_houses(directions: &[(isize, isize)], num_santas: usize) -> usize {
+    let mut visited = HashSet::new();
+
+    let mut curr_locs = vec![(0, 0); num_santas];
+    visited.insert((0, 0));
+
+    for (i, dir) in directions.iter().enumerate() {
+        let loc = &mut curr_locs[i % num_santas];
+
+        loc.0 += dir.0;
+        loc.1 += dir.1;
+
+        visited.insert(*loc);
+    }
+
+    visited.len()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<(isize, isize)>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_directions(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(count_visited_houses(input, 1).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(count_visited_houses(input, 2).to_string())
+    }
+}
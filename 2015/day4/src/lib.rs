@@ -0,0 +1,41 @@
+extern crate aoc_common;
+extern crate crypto;
+
+use crypto::md5::Md5;
+use crypto::digest::Digest;
+
+use aoc_common::{AocResult, Solver};
+
+fn find_hash(puzzle_input: &str, prefix: &str) -> usize {
+    let mut curr_num = 1;
+
+    loop {
+        let mut sum = Md5::new();
+
+        sum.input_str(&(puzzle_input.to_string() + &curr_num.to_string()));
+
+        if sum.result_str().starts_with(prefix) {
+            return curr_num;
+        }
+
+        curr_num += 1;
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(find_hash(input, "00000").to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(find_hash(input, "000000").to_string())
+    }
+}
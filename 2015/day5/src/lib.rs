@@ -0,0 +1,66 @@
+extern crate aoc_common;
+
+use aoc_common::{AocResult, Solver};
+
+fn is_nice(line: &str) -> bool {
+    let bytes = line.as_bytes();
+
+    let vowel_count = bytes.iter().filter(|ch| b"aeiou".contains(ch)).count();
+    let has_double = bytes.windows(2).any(|pair| pair[0] == pair[1]);
+    let has_forbidden = ["ab", "cd", "pq", "xy"].iter().any(|pat| line.contains(pat));
+
+    vowel_count >= 3 && has_double && !has_forbidden
+}
+
+fn is_nice_b(line: &str) -> bool {
+    let bytes = line.as_bytes();
+
+    // A pair of letters appearing at least twice without overlapping
+    let has_repeated_pair = (0..bytes.len().saturating_sub(1))
+        .any(|i| line[(i + 2)..].contains(&line[i..(i + 2)]));
+    let has_sandwich = bytes.windows(3).any(|triple| triple[0] == triple[2]);
+
+    has_repeated_pair && has_sandwich
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.iter().filter(|line| is_nice(line)).count().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(input.iter().filter(|line| is_nice_b(line)).count().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_nice;
+    use super::is_nice_b;
+
+    #[test]
+    fn nice_test() {
+        assert!(is_nice("ugknbfddgicrmopn"));
+        assert!(is_nice("aaa"));
+        assert!(!is_nice("jchzalrnumimnmhp"));
+        assert!(!is_nice("haegwjzuvuyypxyu"));
+        assert!(!is_nice("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn nice_b_test() {
+        assert!(is_nice_b("qjhvhtzxzqqjkmpb"));
+        assert!(is_nice_b("xxyxx"));
+        assert!(!is_nice_b("uurcxstgmygtbstg"));
+        assert!(!is_nice_b("ieodomkazucvgmuy"));
+        assert!(!is_nice_b("aaa"));
+    }
+}
@@ -0,0 +1,127 @@
+extern crate aoc_common;
+extern crate regex;
+
+use std::collections::VecDeque;
+
+use regex::Regex;
+
+use aoc_common::{AocError, AocResult, Grid, Point, Solver};
+
+/// The lights form a square this many lights wide.
+const SIZE: usize = 1000;
+
+#[derive(Debug)]
+struct Range((usize, usize), (usize, usize));
+
+#[derive(Debug)]
+enum OperationType {
+    On,
+    Off,
+    Toggle,
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    op_type: OperationType,
+    range: Range,
+}
+
+fn parse_operations(input: &str) -> AocResult<VecDeque<Operation>> {
+    let mut oper_list = VecDeque::new();
+
+    let re = Regex::new("^(turn on|turn off|toggle) ([0-9]+),([0-9]+) through \
+                         ([0-9]+),([0-9]+)$")
+                 .unwrap();
+
+    for line in input.lines() {
+        let capture = re.captures(line).ok_or_else(|| AocError::parse(format!("Invalid operation: {:?}", line)))?;
+
+        let start_point = (capture.at(2).unwrap().parse::<usize>().unwrap(),
+                           capture.at(3).unwrap().parse::<usize>().unwrap());
+
+        let end_point = (capture.at(4).unwrap().parse::<usize>().unwrap(),
+                         capture.at(5).unwrap().parse::<usize>().unwrap());
+
+        if start_point.0 > end_point.0 || start_point.1 > end_point.1 || end_point.0 >= SIZE || end_point.1 >= SIZE {
+            return Err(AocError::parse(format!("Invalid range: {:?}", line)));
+        }
+
+        let op_str = capture.at(1).unwrap();
+        let op_range = Range(start_point, end_point);
+
+        let op_type = match op_str {
+            "turn on" => OperationType::On,
+            "turn off" => OperationType::Off,
+            "toggle" => OperationType::Toggle,
+            _ => return Err(AocError::parse(format!("Unknown operation: {:?}", op_str))),
+        };
+
+        oper_list.push_back(Operation {
+            op_type,
+            range: op_range,
+        });
+    }
+
+    Ok(oper_list)
+}
+
+impl Range {
+    /// Applies the change to every light in the range, both corners included.
+    fn apply<T, F>(&self, lights: &mut Grid<T>, mut change: F)
+    where
+        F: FnMut(&mut T),
+    {
+        let origin = Point::new((self.0).0 as isize, (self.0).1 as isize);
+        let (width, height) = ((self.1).0 - (self.0).0 + 1, (self.1).1 - (self.0).1 + 1);
+
+        for (_, light) in lights.view_mut(origin, width, height).iter_mut() {
+            change(light);
+        }
+    }
+}
+
+fn count_lit(oper_list: &VecDeque<Operation>) -> usize {
+    let mut lights = Grid::new(SIZE, SIZE, false);
+
+    for oper in oper_list.iter() {
+        oper.range.apply(&mut lights, |light| match oper.op_type {
+            OperationType::On => *light = true,
+            OperationType::Off => *light = false,
+            OperationType::Toggle => *light = !*light,
+        });
+    }
+
+    lights.values().filter(|light| **light).count()
+}
+
+fn total_brightness(oper_list: &VecDeque<Operation>) -> u32 {
+    let mut lights = Grid::new(SIZE, SIZE, 0_u32);
+
+    for oper in oper_list.iter() {
+        oper.range.apply(&mut lights, |light| match oper.op_type {
+            OperationType::On => *light += 1,
+            OperationType::Off => *light = light.saturating_sub(1),
+            OperationType::Toggle => *light += 2,
+        });
+    }
+
+    lights.values().sum()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = VecDeque<Operation>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_operations(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(count_lit(input).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(total_brightness(input).to_string())
+    }
+}
@@ -0,0 +1,339 @@
+//! The wires of a circuit as a graph, evaluated lazily.
+//!
+//! Every wire is driven by exactly one `Operation`. Asking for the value of a wire evaluates the
+//! cone of logic feeding it and nothing else, and remembers the values. Changing the driver of a
+//! wire forgets the values of the wires fed by it, so the next query only recomputes those.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use aoc_common::AocError;
+
+use {OperArg, Operation, WireName};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CircuitError {
+    /// The wires each read the next one, and the last reads the first.
+    Cycle(Vec<WireName>),
+    /// A wire is read, but nothing drives it. `reader` is `None` when the wire was queried directly.
+    Undriven { wire: WireName, reader: Option<WireName> },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CircuitError::Cycle(ref wires) => {
+                write!(f, "wires form a cycle: {} -> {}", wires.join(" -> "), wires[0])
+            }
+            CircuitError::Undriven {
+                ref wire,
+                reader: Some(ref reader),
+            } => write!(f, "wire {} read by {} has no driver", wire, reader),
+            CircuitError::Undriven { ref wire, reader: None } => write!(f, "wire {} has no driver", wire),
+        }
+    }
+}
+
+impl From<CircuitError> for AocError {
+    fn from(e: CircuitError) -> AocError {
+        AocError::unsolvable(e.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    drivers: HashMap<WireName, Operation>,
+    /// The wires reading each wire.
+    fanout: HashMap<WireName, Vec<WireName>>,
+    values: HashMap<WireName, u16>,
+}
+
+impl Circuit {
+    pub fn new(drivers: HashMap<WireName, Operation>) -> Circuit {
+        let mut fanout: HashMap<WireName, Vec<WireName>> = HashMap::new();
+
+        for (wire, op) in &drivers {
+            for input in op.inputs() {
+                fanout.entry(input.clone()).or_default().push(wire.clone());
+            }
+        }
+
+        Circuit {
+            drivers,
+            fanout,
+            values: HashMap::new(),
+        }
+    }
+
+    /// The driven wires, in no particular order.
+    pub fn wires(&self) -> impl Iterator<Item = &WireName> {
+        self.drivers.keys()
+    }
+
+    pub fn driver(&self, wire: &str) -> Option<&Operation> {
+        self.drivers.get(wire)
+    }
+
+    /// The value of the wire if it is known without evaluating anything.
+    pub fn cached(&self, wire: &str) -> Option<u16> {
+        self.values.get(wire).cloned()
+    }
+
+    /// Replaces the driver of the wire, forgetting the values it influenced.
+    pub fn set_driver(&mut self, wire: &str, op: Operation) {
+        if let Some(old) = self.drivers.get(wire) {
+            for input in old.inputs() {
+                if let Some(readers) = self.fanout.get_mut(input) {
+                    readers.retain(|reader| reader != wire);
+                }
+            }
+        }
+
+        for input in op.inputs() {
+            self.fanout.entry(input.clone()).or_default().push(wire.to_string());
+        }
+
+        self.drivers.insert(wire.to_string(), op);
+        self.invalidate(wire);
+    }
+
+    /// Drives the wire with a constant, as part B does with `b`.
+    pub fn override_wire(&mut self, wire: &str, value: u16) {
+        self.set_driver(wire, Operation::Alias(OperArg::Val(value)));
+    }
+
+    fn invalidate(&mut self, wire: &str) {
+        let mut pending = vec![wire.to_string()];
+
+        while let Some(wire) = pending.pop() {
+            // A wire without a value can't have fed a value to anything
+            if self.values.remove(&wire).is_none() {
+                continue;
+            }
+
+            if let Some(readers) = self.fanout.get(&wire) {
+                pending.extend(readers.iter().cloned());
+            }
+        }
+    }
+
+    /// Evaluates the wire and, on the way, all the wires it depends on.
+    pub fn value_of(&mut self, wire: &str) -> Result<u16, CircuitError> {
+        if let Some(&value) = self.values.get(wire) {
+            return Ok(value);
+        }
+
+        if !self.drivers.contains_key(wire) {
+            return Err(CircuitError::Undriven {
+                wire: wire.to_string(),
+                reader: None,
+            });
+        }
+
+        // Each wire on the path reads the next one, which is evaluated first
+        let mut path = vec![wire.to_string()];
+
+        while let Some(current) = path.last().cloned() {
+            let op = &self.drivers[&current];
+
+            match op.inputs().find(|input| !self.values.contains_key(*input)) {
+                None => {
+                    let value = op.evaluate(&self.values).expect("all inputs are evaluated");
+
+                    self.values.insert(current, value);
+                    path.pop();
+                }
+                Some(input) => {
+                    if !self.drivers.contains_key(input) {
+                        return Err(CircuitError::Undriven {
+                            wire: input.clone(),
+                            reader: Some(current),
+                        });
+                    }
+
+                    if let Some(start) = path.iter().position(|wire| wire == input) {
+                        return Err(CircuitError::Cycle(path.split_off(start)));
+                    }
+
+                    path.push(input.clone());
+                }
+            }
+        }
+
+        Ok(self.values[wire])
+    }
+
+    /// The wires in an order where every wire comes after the wires it reads. Ties are broken by
+    /// name, so the order is always the same for the same circuit.
+    pub fn topological_order(&self) -> Result<Vec<WireName>, CircuitError> {
+        let mut unread_inputs = HashMap::new();
+
+        for (wire, op) in &self.drivers {
+            for input in op.inputs() {
+                if !self.drivers.contains_key(input) {
+                    return Err(CircuitError::Undriven {
+                        wire: input.clone(),
+                        reader: Some(wire.clone()),
+                    });
+                }
+            }
+
+            unread_inputs.insert(wire, op.inputs().count());
+        }
+
+        let mut ready = unread_inputs
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&wire, _)| wire)
+            .collect::<BTreeSet<&WireName>>();
+
+        let mut order = Vec::with_capacity(self.drivers.len());
+
+        while let Some(wire) = ready.iter().next().cloned() {
+            ready.remove(wire);
+            order.push(wire.clone());
+
+            // A wire reading the same input twice is listed twice
+            for reader in self.fanout.get(wire).into_iter().flatten() {
+                let count = unread_inputs.get_mut(reader).unwrap();
+                *count -= 1;
+
+                if *count == 0 {
+                    ready.insert(reader);
+                }
+            }
+        }
+
+        if order.len() == self.drivers.len() {
+            Ok(order)
+        } else {
+            let stuck = unread_inputs
+                .into_iter()
+                .filter(|&(_, count)| count > 0)
+                .map(|(wire, _)| wire)
+                .collect::<BTreeSet<&WireName>>();
+
+            Err(CircuitError::Cycle(self.find_cycle(&stuck)))
+        }
+    }
+
+    /// Walks from a stuck wire along stuck inputs until a wire repeats. Every stuck wire reads a
+    /// stuck wire, so the walk always runs into a cycle.
+    fn find_cycle(&self, stuck: &BTreeSet<&WireName>) -> Vec<WireName> {
+        let mut path: Vec<&WireName> = vec![stuck.iter().next().unwrap()];
+
+        loop {
+            let next = self.drivers[*path.last().unwrap()]
+                .inputs()
+                .filter(|input| stuck.contains(input))
+                .min()
+                .unwrap();
+
+            if let Some(start) = path.iter().position(|&wire| wire == next) {
+                return path[start..].iter().map(|&wire| wire.clone()).collect();
+            }
+
+            path.push(next);
+        }
+    }
+
+    /// Evaluates every wire.
+    pub fn evaluate_all(&mut self) -> Result<HashMap<WireName, u16>, CircuitError> {
+        for wire in self.topological_order()? {
+            self.value_of(&wire)?;
+        }
+
+        Ok(self.values.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Circuit, CircuitError};
+    use parse_circuit;
+
+    const TEST_INPUT: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    fn circuit(input: &str) -> Circuit {
+        parse_circuit(input).unwrap()
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let mut circuit = circuit(TEST_INPUT);
+        let values = circuit.evaluate_all().unwrap();
+
+        let expected = [("d", 72), ("e", 507), ("f", 492), ("g", 114), ("h", 65412), ("i", 65079), ("x", 123), ("y", 456)];
+
+        assert_eq!(expected.len(), values.len());
+
+        for &(wire, value) in &expected {
+            assert_eq!(value, values[wire], "wire {}", wire);
+        }
+
+        assert_eq!(
+            vec!["x", "f", "h", "y", "d", "e", "g", "i"],
+            circuit.topological_order().unwrap()
+        );
+    }
+
+    #[test]
+    fn incremental_test() {
+        let mut circuit = circuit(TEST_INPUT);
+
+        // only the cone of d is evaluated
+        assert_eq!(Ok(72), circuit.value_of("d"));
+        assert_eq!(Some(123), circuit.cached("x"));
+        assert_eq!(None, circuit.cached("e"));
+
+        assert_eq!(Ok(65079), circuit.value_of("i"));
+
+        circuit.override_wire("x", 0xf0);
+
+        assert_eq!(None, circuit.cached("d"));
+        assert_eq!(Some(456), circuit.cached("y"));
+        assert_eq!(Some(65079), circuit.cached("i"));
+        assert_eq!(Ok(0xc0), circuit.value_of("d"));
+    }
+
+    #[test]
+    fn cycle_test() {
+        let mut circuit = circuit("b -> a\nc AND 1 -> b\nNOT b -> c\n1 -> d\nd OR c -> e");
+
+        let cycle = CircuitError::Cycle(vec!["b".to_string(), "c".to_string()]);
+
+        assert_eq!(Err(cycle.clone()), circuit.topological_order());
+        assert_eq!(Err(cycle), circuit.value_of("a"));
+        assert_eq!(Ok(1), circuit.value_of("d"));
+
+        assert_eq!(
+            "wires form a cycle: c -> b -> c",
+            circuit.value_of("e").unwrap_err().to_string()
+        );
+
+        circuit.override_wire("c", 7);
+        assert_eq!(Ok(1), circuit.value_of("a"));
+        assert_eq!(Ok(7), circuit.value_of("e"));
+    }
+
+    #[test]
+    fn undriven_test() {
+        let mut circuit = circuit("x AND y -> d\n1 -> x");
+
+        let undriven = CircuitError::Undriven {
+            wire: "y".to_string(),
+            reader: Some("d".to_string()),
+        };
+
+        assert_eq!(Err(undriven.clone()), circuit.value_of("d"));
+        assert_eq!(Err(undriven), circuit.topological_order());
+        assert_eq!("wire z has no driver", circuit.value_of("z").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,264 @@
+//! Exporters turning a circuit into Verilog for hardware tools and into a Graphviz graph.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write;
+
+use circuit::{Circuit, CircuitError};
+use {OperArg, Operation, WireName};
+
+/// The Verilog-2005 keywords a wire name can clash with, which are written as escaped identifiers.
+const KEYWORDS: &[&str] = &[
+    "always", "and", "assign", "automatic", "begin", "buf", "case", "casex", "casez", "cell", "cmos", "config",
+    "deassign", "default", "defparam", "design", "disable", "edge", "else", "end", "endcase", "endconfig",
+    "endfunction", "endgenerate", "endmodule", "endprimitive", "endspecify", "endtable", "endtask", "event", "for",
+    "force", "forever", "fork", "function", "generate", "genvar", "if", "ifnone", "incdir", "include", "initial",
+    "inout", "input", "instance", "integer", "join", "large", "liblist", "library", "localparam", "macromodule",
+    "medium", "module", "nand", "negedge", "nmos", "nor", "noshowcancelled", "not", "or", "output", "parameter",
+    "pmos", "posedge", "primitive", "pulldown", "pullup", "rcmos", "real", "realtime", "reg", "release", "repeat",
+    "rnmos", "rpmos", "rtran", "scalared", "showcancelled", "signed", "small", "specify", "specparam", "table",
+    "task", "time", "tran", "tri", "triand", "trior", "trireg", "unsigned", "use", "uwire", "vectored", "wait",
+    "wand", "while", "wire", "wor", "xnor", "xor",
+];
+
+fn identifier(wire: &str) -> String {
+    if KEYWORDS.contains(&wire) {
+        // escaped identifiers end at the next whitespace
+        format!("\\{} ", wire)
+    } else {
+        wire.to_string()
+    }
+}
+
+fn verilog_arg(arg: &OperArg) -> String {
+    match *arg {
+        OperArg::Wire(ref wire) => identifier(wire),
+        OperArg::Val(val) => format!("16'd{}", val),
+    }
+}
+
+fn verilog_expr(op: &Operation) -> String {
+    match *op {
+        Operation::And(ref in1, ref in2) => format!("{} & {}", verilog_arg(in1), verilog_arg(in2)),
+        Operation::Or(ref in1, ref in2) => format!("{} | {}", verilog_arg(in1), verilog_arg(in2)),
+        Operation::LShift(ref in1, ref in2) => format!("{} << {}", verilog_arg(in1), verilog_arg(in2)),
+        Operation::RShift(ref in1, ref in2) => format!("{} >> {}", verilog_arg(in1), verilog_arg(in2)),
+        Operation::Not(ref input) => format!("~{}", verilog_arg(input)),
+        Operation::Alias(ref input) => verilog_arg(input),
+    }
+}
+
+/// Writes the circuit as a Verilog module of continuous assignments, one `wire [15:0]` per wire.
+///
+/// The wires no other wire reads are the outputs of the module, it has no inputs. A cycle would
+/// be a combinational loop and is rejected, as are wires without a driver.
+pub fn to_verilog(circuit: &Circuit, module: &str) -> Result<String, CircuitError> {
+    let order = circuit.topological_order()?;
+
+    let read = order
+        .iter()
+        .flat_map(|wire| circuit.driver(wire).unwrap().inputs())
+        .collect::<HashSet<&WireName>>();
+
+    let outputs = order
+        .iter()
+        .filter(|wire| !read.contains(wire))
+        .collect::<BTreeSet<&WireName>>();
+
+    let mut verilog = String::new();
+
+    if outputs.is_empty() {
+        writeln!(verilog, "module {};", module).unwrap();
+    } else {
+        writeln!(verilog, "module {} (", module).unwrap();
+
+        let ports = outputs
+            .iter()
+            .map(|wire| format!("    output wire [15:0] {}", identifier(wire)))
+            .collect::<Vec<String>>();
+
+        writeln!(verilog, "{}", ports.join(",\n")).unwrap();
+        writeln!(verilog, ");").unwrap();
+    }
+
+    let internal = order
+        .iter()
+        .filter(|wire| !outputs.contains(wire))
+        .collect::<BTreeSet<&WireName>>();
+
+    if !internal.is_empty() {
+        writeln!(verilog).unwrap();
+
+        for wire in internal {
+            writeln!(verilog, "    wire [15:0] {};", identifier(wire)).unwrap();
+        }
+    }
+
+    writeln!(verilog).unwrap();
+
+    for wire in &order {
+        let op = circuit.driver(wire).unwrap();
+
+        writeln!(verilog, "    assign {} = {};", identifier(wire), verilog_expr(op)).unwrap();
+    }
+
+    writeln!(verilog, "endmodule").unwrap();
+
+    Ok(verilog)
+}
+
+/// The wire and all the wires its value depends on, sorted by name.
+pub fn fan_in(circuit: &Circuit, wire: &str) -> Result<BTreeSet<WireName>, CircuitError> {
+    let mut cone = BTreeSet::new();
+    let mut pending = vec![(wire.to_string(), None)];
+
+    while let Some((wire, reader)) = pending.pop() {
+        if cone.contains(&wire) {
+            continue;
+        }
+
+        let op = circuit.driver(&wire).ok_or_else(|| CircuitError::Undriven {
+            wire: wire.clone(),
+            reader,
+        })?;
+
+        pending.extend(op.inputs().map(|input| (input.clone(), Some(wire.clone()))));
+        cone.insert(wire);
+    }
+
+    Ok(cone)
+}
+
+/// Draws the circuit as a Graphviz graph, with the wires as ellipses and the gates driving them
+/// as boxes. With `wire` given, only its fan-in is drawn. Cycles are drawn as they are.
+pub fn to_dot(circuit: &Circuit, wire: Option<&str>) -> Result<String, CircuitError> {
+    let wires = match wire {
+        Some(wire) => fan_in(circuit, wire)?,
+        None => circuit.wires().cloned().collect(),
+    };
+
+    let mut dot = String::new();
+
+    writeln!(dot, "digraph circuit {{").unwrap();
+    writeln!(dot, "    rankdir=LR;").unwrap();
+
+    for wire in &wires {
+        let op = circuit.driver(wire).expect("only driven wires are drawn");
+
+        writeln!(dot, "    \"{}\" [shape=ellipse];", wire).unwrap();
+
+        // assignments connect straight to the wire, everything else goes through a gate
+        let (gate, target) = match *op {
+            Operation::Alias(_) => (None, format!("\"{}\"", wire)),
+            Operation::And(..) => (Some("AND"), format!("\"{}/gate\"", wire)),
+            Operation::Or(..) => (Some("OR"), format!("\"{}/gate\"", wire)),
+            Operation::LShift(..) => (Some("LSHIFT"), format!("\"{}/gate\"", wire)),
+            Operation::RShift(..) => (Some("RSHIFT"), format!("\"{}/gate\"", wire)),
+            Operation::Not(..) => (Some("NOT"), format!("\"{}/gate\"", wire)),
+        };
+
+        if let Some(gate) = gate {
+            writeln!(dot, "    {} [shape=box, label=\"{}\"];", target, gate).unwrap();
+            writeln!(dot, "    {} -> \"{}\";", target, wire).unwrap();
+        }
+
+        for (i, arg) in op.args().into_iter().enumerate() {
+            match *arg {
+                OperArg::Wire(ref input) => writeln!(dot, "    \"{}\" -> {};", input, target).unwrap(),
+                OperArg::Val(val) => {
+                    writeln!(dot, "    \"{}/{}\" [shape=plaintext, label=\"{}\"];", wire, i, val).unwrap();
+                    writeln!(dot, "    \"{}/{}\" -> {};", wire, i, target).unwrap();
+                }
+            }
+        }
+    }
+
+    dot.push_str("}\n");
+    Ok(dot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fan_in, to_dot, to_verilog};
+    use parse_circuit;
+
+    const TEST_INPUT: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> if
+NOT x -> h
+NOT y -> i
+h -> z";
+
+    #[test]
+    fn verilog_test() {
+        let circuit = parse_circuit(TEST_INPUT).unwrap();
+
+        let expected = "\
+module circuit (
+    output wire [15:0] d,
+    output wire [15:0] e,
+    output wire [15:0] f,
+    output wire [15:0] i,
+    output wire [15:0] \\if ,
+    output wire [15:0] z
+);
+
+    wire [15:0] h;
+    wire [15:0] x;
+    wire [15:0] y;
+
+    assign x = 16'd123;
+    assign f = x << 16'd2;
+    assign h = ~x;
+    assign y = 16'd456;
+    assign d = x & y;
+    assign e = x | y;
+    assign i = ~y;
+    assign \\if  = y >> 16'd2;
+    assign z = h;
+endmodule
+";
+
+        assert_eq!(expected, to_verilog(&circuit, "circuit").unwrap());
+
+        let circuit = parse_circuit("a -> b\nb -> a").unwrap();
+        assert!(to_verilog(&circuit, "circuit").is_err());
+    }
+
+    #[test]
+    fn dot_test() {
+        let circuit = parse_circuit(TEST_INPUT).unwrap();
+
+        let expected = "\
+digraph circuit {
+    rankdir=LR;
+    \"d\" [shape=ellipse];
+    \"d/gate\" [shape=box, label=\"AND\"];
+    \"d/gate\" -> \"d\";
+    \"x\" -> \"d/gate\";
+    \"y\" -> \"d/gate\";
+    \"x\" [shape=ellipse];
+    \"x/0\" [shape=plaintext, label=\"123\"];
+    \"x/0\" -> \"x\";
+    \"y\" [shape=ellipse];
+    \"y/0\" [shape=plaintext, label=\"456\"];
+    \"y/0\" -> \"y\";
+}
+";
+
+        assert_eq!(expected, to_dot(&circuit, Some("d")).unwrap());
+
+        let names = |wire| fan_in(&circuit, wire).unwrap().into_iter().collect::<Vec<String>>();
+        assert_eq!(vec!["h", "x", "z"], names("z"));
+
+        let full = to_dot(&circuit, None).unwrap();
+        assert_eq!(9, full.matches("[shape=ellipse]").count());
+        assert!(full.contains("\"h\" -> \"z\";"));
+
+        // cycles are fine in drawings
+        let circuit = parse_circuit("a -> b\nNOT b -> a").unwrap();
+        assert_eq!(2, to_dot(&circuit, Some("a")).unwrap().matches("[shape=ellipse]").count());
+    }
+}
@@ -0,0 +1,174 @@
+extern crate aoc_common;
+extern crate regex;
+
+use regex::Regex;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+use std::collections::HashMap;
+
+pub mod circuit;
+pub mod export;
+
+use circuit::Circuit;
+
+pub type WireName = String;
+
+#[derive(Debug, Clone)]
+pub enum OperArg {
+    Wire(WireName),
+    Val(u16)
+}
+
+impl OperArg {
+    fn value(&self, values: &HashMap<WireName, u16>) -> Option<u16> {
+        match *self {
+            OperArg::Val(v) => Some(v),
+            OperArg::Wire(ref w_name) => values.get(w_name).cloned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    And(OperArg, OperArg),
+    LShift(OperArg, OperArg),
+    Or(OperArg, OperArg),
+    RShift(OperArg, OperArg),
+    Not(OperArg),
+    Alias(OperArg),
+}
+
+impl Operation {
+    pub fn args(&self) -> Vec<&OperArg> {
+        match *self {
+            Operation::And(ref input1, ref input2) |
+            Operation::LShift(ref input1, ref input2) |
+            Operation::Or(ref input1, ref input2) |
+            Operation::RShift(ref input1, ref input2) => vec![input1, input2],
+            Operation::Not(ref input) |
+            Operation::Alias(ref input) => vec![input],
+        }
+    }
+
+    /// The wires the operation reads.
+    pub fn inputs(&self) -> impl Iterator<Item = &WireName> {
+        self.args().into_iter().filter_map(|arg| match *arg {
+            OperArg::Wire(ref w_name) => Some(w_name),
+            OperArg::Val(_) => None,
+        })
+    }
+
+    /// The output of the operation, or `None` while one of its input wires has no value. Shifting
+    /// by 16 bits or more gives 0, like in hardware.
+    pub fn evaluate(&self, values: &HashMap<WireName, u16>) -> Option<u16> {
+        let args = self
+            .args()
+            .into_iter()
+            .map(|arg| arg.value(values))
+            .collect::<Option<Vec<u16>>>()?;
+
+        Some(match *self {
+            Operation::And(..) => args[0] & args[1],
+            Operation::LShift(..) => args[0].checked_shl(u32::from(args[1])).unwrap_or(0),
+            Operation::Or(..) => args[0] | args[1],
+            Operation::RShift(..) => args[0].checked_shr(u32::from(args[1])).unwrap_or(0),
+            Operation::Not(..) => !args[0],
+            Operation::Alias(..) => args[0],
+        })
+    }
+}
+
+pub fn parse_circuit(input: &str) -> AocResult<Circuit> {
+
+    let mut wire_ops: HashMap<WireName, Operation> = HashMap::new();
+
+    // Build a model of the circuit
+    let re = Regex::new("^(?:(?P<in1>[a-z]+|[0-9]+) )?(?:(?P<oper>AND|LSHIFT|OR|RSHIFT|NOT) \
+                         )?(?P<in2>[a-z]+|[0-9]+)? -> (?P<out>[a-z]+)$")
+                 .unwrap();
+
+
+    for line in input.lines() {
+        let caps = re.captures(line).ok_or_else(|| AocError::parse(format!("Invalid instruction: {:?}", line)))?;
+
+
+        let wire_name = caps.name("out").unwrap().to_string();
+
+        if wire_ops.contains_key(&wire_name) {
+            return Err(AocError::parse(format!("Wire {} has more than one driver: {:?}", wire_name, line)));
+        }
+
+        let in2_str = caps.name("in2").ok_or_else(|| AocError::parse(format!("Missing input: {:?}", line)))?;
+
+        let in2 = match in2_str.parse::<u16>() {
+            Ok(num) => OperArg::Val(num),
+            Err(_) => OperArg::Wire(in2_str.to_string())
+        };
+
+        match caps.name("oper") {
+            Some(oper) => {
+                // Some operation performed.
+                if oper == "NOT" {
+                    wire_ops.insert(wire_name, Operation::Not(in2));
+                } else {
+                    let in1_str = caps.name("in1").ok_or_else(|| AocError::parse(format!("Missing input: {:?}", line)))?;
+
+                    let in1 = match in1_str.parse::<u16>() {
+                        Ok(num) => OperArg::Val(num),
+                        Err(_) => OperArg::Wire(in1_str.to_string())
+                    };
+
+                    match oper {
+                        "AND" => {
+                            wire_ops.insert(wire_name, Operation::And(in1, in2));
+                        }
+                        "LSHIFT" => {
+                            wire_ops.insert(wire_name, Operation::LShift(in1, in2));
+                        }
+                        "OR" => {
+                            wire_ops.insert(wire_name, Operation::Or(in1, in2));
+                        }
+                        "RSHIFT" => {
+                            wire_ops.insert(wire_name, Operation::RShift(in1, in2));
+                        }
+                        _ => return Err(AocError::parse(format!("Unknown operation: {:?}", oper))),
+                    }
+                }
+            }
+            None => {
+                // Simple assignment of a number or of one wire to another.
+                wire_ops.insert(wire_name, Operation::Alias(in2));
+            }
+        }
+
+    }
+
+    Ok(Circuit::new(wire_ops))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Circuit;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_circuit(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut circuit = input.clone();
+
+        Ok(circuit.value_of("a")?.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut circuit = input.clone();
+        let a_val = circuit.value_of("a")?;
+
+        // Override wire b with the signal from wire a, only the wires fed by b are evaluated again.
+        circuit.override_wire("b", a_val);
+
+        Ok(circuit.value_of("a")?.to_string())
+    }
+}
@@ -0,0 +1,66 @@
+extern crate aoc_common;
+extern crate regex;
+
+use regex::Regex;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+#[derive(Debug)]
+pub struct Counts {
+    byte_count: usize,
+    character_count: usize,
+    escaped_byte_count: usize,
+}
+
+fn count_characters(input: &str) -> AocResult<Counts> {
+
+    let mut character_count = 0;
+    let mut byte_count = 0;
+    let mut escaped_byte_count = 0;
+
+    let line_quotes = Regex::new(r#"^".*""#).unwrap();
+    let two_char_escape = Regex::new(r#"\\\\|\\""#).unwrap();
+    let hex_escape = Regex::new(r#"\\x[:xdigit:]{2}"#).unwrap();
+
+    for (idx, line) in input.lines().enumerate() {
+        byte_count += line.len();
+        character_count += line.len();
+
+        // +2 because the escaped string is supposed to be surrounded in quotes '"'
+        escaped_byte_count += line.escape_default().count() + 2;
+
+        if line_quotes.is_match(line) {
+            character_count -= 2;
+        } else {
+            return Err(AocError::parse_at(idx + 1, 1, format!(r#"Expected line to start and end with quotes '"': {}"#, line)));
+        }
+
+        character_count -= two_char_escape.find_iter(line).count();
+
+        character_count -= 3 * hex_escape.find_iter(line).count();
+    }
+
+    Ok(Counts {
+        byte_count,
+        character_count,
+        escaped_byte_count,
+    })
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Counts;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        count_characters(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok((input.byte_count - input.character_count).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok((input.escaped_byte_count - input.byte_count).to_string())
+    }
+}
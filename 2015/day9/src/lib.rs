@@ -1,50 +1,51 @@
-
+extern crate aoc_common;
 extern crate regex;
 extern crate permutohedron;
 
 use regex::Regex;
 
-use std::io;
-use std::io::prelude::*;
-
 use std::collections::HashMap;
 
 use permutohedron::Heap;
 
-fn main() {
+use aoc_common::{AocError, AocResult, Solver};
 
-    let mut distances = HashMap::new();
+type Distances = HashMap<String, HashMap<String, usize>>;
 
-    let re = Regex::new("^(?P<from>[:alpha:]+) to (?P<to>[:alpha:]+) = (?P<dist>[:digit:]+)$").unwrap();
+fn parse_distances(input: &str) -> AocResult<Distances> {
 
-    let stdin = io::stdin();
+    let mut distances = HashMap::new();
 
-    for wline in stdin.lock().lines() {
-        let line = wline.unwrap();
+    let re = Regex::new("^(?P<from>[:alpha:]+) to (?P<to>[:alpha:]+) = (?P<dist>[:digit:]+)$").unwrap();
 
-        let cap = re.captures(&line).unwrap();
+    for line in input.lines() {
+        let cap = re.captures(line).ok_or_else(|| AocError::parse(format!("Invalid distance: {:?}", line)))?;
 
         let from = cap.name("from").unwrap().to_string();
         let to = cap.name("to").unwrap().to_string();
-        let dist = cap.name("dist").unwrap().parse::<usize>().unwrap();
+        let dist = cap.name("dist").unwrap().parse::<usize>().map_err(AocError::from)?;
 
         {
-            let from_dist = distances.entry(from.clone()).or_insert(HashMap::new());
+            let from_dist = distances.entry(from.clone()).or_insert_with(HashMap::new);
             from_dist.insert(to.clone(), dist);
         }
 
         {
-            let to_dist = distances.entry(to).or_insert(HashMap::new());
+            let to_dist = distances.entry(to).or_insert_with(HashMap::new);
             to_dist.insert(from, dist);
         }
     }
 
+    Ok(distances)
+}
+
+fn find_min_max_route(distances: &Distances) -> (usize, usize) {
     let mut cities: Vec<String> = distances.keys().cloned().collect();
 
     let path_permutations = Heap::new(&mut cities);
 
     let mut max = 0;
-    let mut min = std::usize::MAX;
+    let mut min = usize::MAX;
 
     for path in path_permutations {
 
@@ -58,13 +59,31 @@ fn main() {
         }
 
         if dist > max {
-            println!("MAX - {} - {:?}", dist, path);
             max = dist;
         }
 
         if dist < min {
-            println!("MIN - {} - {:?}", dist, path);
             min = dist;
         }
     }
+
+    (min, max)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Distances;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_distances(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(find_min_max_route(input).0.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(find_min_max_route(input).1.to_string())
+    }
 }
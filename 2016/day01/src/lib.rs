@@ -1,9 +1,8 @@
-
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
 
 use std::collections::BTreeSet;
 
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(PartialEq, Eq, Debug)]
 enum Decision {
@@ -34,7 +33,7 @@ impl<'a> From<&'a str> for Decision {
     }
 }
 
-struct Path {
+pub struct Path {
     path: Vec<Decision>
 }
 
@@ -106,7 +105,7 @@ impl Path {
             pos.1 += diff_y
         }
 
-        pos.0.abs() as u64 + pos.1.abs() as u64
+        pos.0.unsigned_abs() + pos.1.unsigned_abs()
     }
 
     fn get_twice_visited_dist(&self) -> Option<u64> {
@@ -124,7 +123,7 @@ impl Path {
                     let new_pos = (x_pos, pos.1);
 
                     if already_visited.contains(&new_pos) {
-                        return Some(new_pos.0.abs() as u64 + new_pos.1.abs() as u64);
+                        return Some(new_pos.0.unsigned_abs() + new_pos.1.unsigned_abs());
                     }
 
                     already_visited.insert(new_pos);
@@ -135,7 +134,7 @@ impl Path {
                     let new_pos = (x_pos, pos.1);
 
                     if already_visited.contains(&new_pos) {
-                        return Some(new_pos.0.abs() as u64 + new_pos.1.abs() as u64);
+                        return Some(new_pos.0.unsigned_abs() + new_pos.1.unsigned_abs());
                     }
 
                     already_visited.insert(new_pos);
@@ -147,7 +146,7 @@ impl Path {
                     let new_pos = (pos.0, y_pos);
 
                     if already_visited.contains(&new_pos) {
-                        return Some(new_pos.0.abs() as u64 + new_pos.1.abs() as u64);
+                        return Some(new_pos.0.unsigned_abs() + new_pos.1.unsigned_abs());
                     }
 
                     already_visited.insert(new_pos);
@@ -158,7 +157,7 @@ impl Path {
                     let new_pos = (pos.0, y_pos);
 
                     if already_visited.contains(&new_pos) {
-                        return Some(new_pos.0.abs() as u64 + new_pos.1.abs() as u64);
+                        return Some(new_pos.0.unsigned_abs() + new_pos.1.unsigned_abs());
                     }
 
                     already_visited.insert(new_pos);
@@ -173,31 +172,36 @@ impl Path {
     }
 }
 
-fn main() {
-    let mut path_str = String::new();
-    io::stdin().read_to_string(&mut path_str).expect("Invalid input string!");
+pub struct Solution;
 
-    let path = Path::from(path_str.as_str());
+impl Solver for Solution {
+    type Input = Path;
 
-    println!("The final distance is: {}", path.get_dist());
-    if let Some(dist) = path.get_twice_visited_dist() {
-        println!("The distance of the first location visited twice is: {}", dist);
-    } else {
-        println!("No place was visited twice.");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(Path::from(input))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.get_dist().to_string())
     }
-}
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        input.get_twice_visited_dist()
+             .map(|dist| dist.to_string())
+             .ok_or_else(|| AocError::unsolvable("No place was visited twice."))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::Decision;
     use super::Path;
 
-    const INPUT_1: &'static str = "R2, L3";
-    const INPUT_2: &'static str = "R2, R2, R2";
-    const INPUT_3: &'static str = "R5, L5, R5, R3";
+    const INPUT_1: &str = "R2, L3";
+    const INPUT_2: &str = "R2, R2, R2";
+    const INPUT_3: &str = "R5, L5, R5, R3";
 
-    const INPUT_4: &'static str = "R8, R4, R4, R8";
+    const INPUT_4: &str = "R8, R4, R4, R8";
 
     #[test]
     fn parse_test() {
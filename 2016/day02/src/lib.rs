@@ -1,8 +1,9 @@
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
+
+use aoc_common::{AocResult, Solver};
 
 #[derive(Debug, PartialEq, Eq)]
-enum Step {
+pub enum Step {
     Up,
     Right,
     Down,
@@ -111,14 +112,22 @@ fn get_code_b(directions: &Vec<Vec<Step>>) -> Vec<char> {
     code
 }
 
-fn main() {
-    let mut dir_str = String::new();
-    io::stdin().read_to_string(&mut dir_str).expect("Invalid input string!");
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Vec<Step>>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input))
+    }
 
-    let directions = parse_input(&dir_str);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(get_code(input).into_iter().collect())
+    }
 
-    println!("The bathroom code is: {:?}", get_code(&directions));
-    println!("The second bathroom code is: {:?}", get_code_b(&directions));
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(get_code_b(input).into_iter().collect())
+    }
 }
 
 #[cfg(test)]
@@ -128,7 +137,7 @@ mod tests {
     use super::get_code;
     use super::get_code_b;
 
-    const TEST_INPUT: &'static str = "ULL\n\
+    const TEST_INPUT: &str = "ULL\n\
                                       RRDDD\n\
                                       LURDL\n\
                                       UUUUD\n";
@@ -1,13 +1,11 @@
-
+extern crate aoc_common;
 #[macro_use]
 extern crate itertools;
 
-
 use std::str::FromStr;
 use std::num::ParseIntError;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug, PartialEq, Eq)]
 struct Shape {
@@ -18,15 +16,15 @@ impl FromStr for Shape {
     type Err = ParseIntError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sides = try!(s.split_whitespace()
+        let sides = s.split_whitespace()
             .map(|side| side.parse::<u32>())
-            .collect());
+            .collect::<Result<Vec<u32>, _>>()?;
 
-        Ok(Shape { sides: sides })
+        Ok(Shape { sides })
     }
 }
 
-impl<'a> From<(u32, u32, u32)> for Shape {
+impl From<(u32, u32, u32)> for Shape {
     fn from(sides_tuple: (u32, u32, u32)) -> Shape {
         Shape { sides: vec![sides_tuple.0, sides_tuple.1, sides_tuple.2] }
     }
@@ -45,7 +43,7 @@ impl Shape {
 }
 
 fn parse_input(input: &str) -> Result<Vec<Shape>, ParseIntError> {
-    Ok(try!(input.lines().map(|line| line.parse()).collect()))
+    input.lines().map(|line| line.parse()).collect()
 }
 
 fn parse_input_vertical(input: &str) -> Vec<Shape> {
@@ -65,58 +63,53 @@ fn parse_input_vertical(input: &str) -> Vec<Shape> {
 
         let transposed = izip!(three_lines[0].iter().cloned(),
                                three_lines[1].iter().cloned(),
-                               three_lines[2].iter().cloned())
-            .collect::<Vec<(u32, u32, u32)>>();
+                               three_lines[2].iter().cloned());
 
-        for i in 0..3 {
-            shapes.push(transposed[i].into());
-        }
+        shapes.extend(transposed.map(Shape::from));
     }
 
     shapes
 }
 
 
-fn main() {
-    let mut shape_str = String::new();
-    io::stdin().read_to_string(&mut shape_str).expect("Invalid input string!");
+fn count_valid_triangles(shapes: &[Shape]) -> usize {
+    shapes.iter().filter(|shape| shape.is_valid_triangle()).count()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
 
-    let shapes = parse_input(&shape_str).unwrap();
-    let num_valid_triangles = shapes.iter().fold(0, |acc, shape| if shape.is_valid_triangle() {
-        acc + 1
-    } else {
-        acc
-    });
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
-    println!("A: The number of valid triangles is: {}",
-             num_valid_triangles);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let shapes = parse_input(input).map_err(AocError::from)?;
 
-    let shapes_b = parse_input_vertical(&shape_str);
-    let num_valid_triangles_b = shapes_b.iter().fold(0, |acc, shape| if shape.is_valid_triangle() {
-        acc + 1
-    } else {
-        acc
-    });
+        Ok(count_valid_triangles(&shapes).to_string())
+    }
 
-    println!("B: The number of valid triangles is: {}",
-             num_valid_triangles_b);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(count_valid_triangles(&parse_input_vertical(input)).to_string())
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::parse_input;
     use super::Shape;
     use super::parse_input_vertical;
 
-    const TEST_STRING: &'static str = "566  477  376\n\
+    const TEST_STRING: &str = "566  477  376\n\
                                        575  488  365\n\
                                         50   18  156\n\
                                        558  673  498\n\
                                        133  112  510\n\
                                        670  613   25\n";
 
-    const TEST_STRING_2: &'static str = "101 301 501\n\
+    const TEST_STRING_2: &str = "101 301 501\n\
                                          102 302 502\n\
                                          103 303 503\n\
                                          201 401 601\n\
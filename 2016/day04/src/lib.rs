@@ -1,3 +1,4 @@
+extern crate aoc_common;
 extern crate regex;
 extern crate itertools;
 
@@ -9,9 +10,7 @@ use itertools::Itertools;
 
 use std::cmp::Ordering;
 
-use std::io;
-use std::io::prelude::*;
-
+use aoc_common::{AocError, AocResult, Solver};
 
 fn get_valid_rooms(input: &str) -> Vec<(String, u64)> {
     let re = Regex::new(r"^(?P<name>[a-z-]+)-(?P<id>[0-9]+)\[(?P<csum>[a-z]+)\]").unwrap();
@@ -60,28 +59,33 @@ fn rotate_str(input: &str, num_steps: u64) -> String {
         .collect()
 }
 
-fn main() {
-    let mut room_str = String::new();
-    io::stdin().read_to_string(&mut room_str).expect("Invalid input string!");
+pub struct Solution;
 
-    let valid_rooms = get_valid_rooms(&room_str);
+impl Solver for Solution {
+    type Input = Vec<(String, u64)>;
 
-    println!("The sum of real room sector IDs is: {}",
-             valid_rooms.iter().map(|&(ref _name, id)| id).sum::<u64>());
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(get_valid_rooms(input))
+    }
 
-    println!("Decrypted room names:");
-    for &(ref room_name, id) in &valid_rooms {
-        println!("{} - {}", id, rotate_str(room_name, id));
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.iter().map(|&(ref _name, id)| id).sum::<u64>().to_string())
     }
-}
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        input.iter()
+             .find(|&&(ref room_name, id)| rotate_str(room_name, id).contains("northpole"))
+             .map(|&(_, id)| id.to_string())
+             .ok_or_else(|| AocError::unsolvable("The North Pole object storage room was not found."))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::get_valid_rooms;
     use super::rotate_str;
 
-    const TEST_STRING: &'static str = "aaaaa-bbb-z-y-x-123[abxyz]\n\
+    const TEST_STRING: &str = "aaaaa-bbb-z-y-x-123[abxyz]\n\
                                        a-b-c-d-e-f-g-h-987[abcde]\n\
                                        not-a-real-room-404[oarel]\n\
                                        totally-real-room-200[decoy]\n";
@@ -1,9 +1,10 @@
+extern crate aoc_common;
 extern crate crypto;
 
 use crypto::md5::Md5;
 use crypto::digest::Digest;
 
-use std::env;
+use aoc_common::{AocResult, Solver};
 
 fn generate_password(base: &str) -> String {
     let mut md5 = Md5::new();
@@ -37,7 +38,7 @@ fn generate_password_b(base: &str) -> String {
         let out_str = md5.result_str();
 
         if &out_str[0..5] == "00000" {
-            let pos = (out_str.as_bytes()[5] - ('0' as u8)) as usize;
+            let pos = (out_str.as_bytes()[5] - b'0') as usize;
             if pos < 8 {
                 let ch = out_str.as_bytes()[6] as char;
 
@@ -53,18 +54,22 @@ fn generate_password_b(base: &str) -> String {
     out_chars.into_iter().collect::<Option<String>>().unwrap()
 }
 
-fn main() {
-    let mut a = env::args();
+pub struct Solution;
 
-    a.next(); // The first argument is the binary name/path
+impl Solver for Solution {
+    type Input = String;
 
-    let input_str = a.next().unwrap();
-
-    println!("The first password is: {}", generate_password(&input_str));
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
 
-    println!("The second password is: {}",
-             generate_password_b(&input_str));
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(generate_password(input))
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(generate_password_b(input))
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,8 @@
-#![feature(iter_max_by, iter_min_by)]
+extern crate aoc_common;
 
 use std::collections::HashMap;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
 enum DecodingMethod {
     MostLikely,
@@ -38,23 +37,30 @@ fn decode_message(input: &str, method: DecodingMethod) -> String {
         .collect()
 }
 
-fn main() {
-    let mut message_str = String::new();
-    io::stdin().read_to_string(&mut message_str).expect("Invalid input string!");
+pub struct Solution;
 
-    println!("The initial decoded message is: {}",
-             decode_message(&message_str, DecodingMethod::MostLikely));
-    println!("The actual decoded message is: {}",
-             decode_message(&message_str, DecodingMethod::LeastLikely));
-}
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(decode_message(input, DecodingMethod::MostLikely))
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(decode_message(input, DecodingMethod::LeastLikely))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::decode_message;
     use super::DecodingMethod;
 
-    const TEST_INPUT: &'static str = "eedadn\n\
+    const TEST_INPUT: &str = "eedadn\n\
                                       drvtee\n\
                                       eandsr\n\
                                       raavrd\n\
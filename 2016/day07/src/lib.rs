@@ -0,0 +1,108 @@
+extern crate aoc_common;
+
+use aoc_common::{AocResult, Solver};
+
+/// Splits an address into its supernet and hypernet (bracketed) sections.
+fn split_address(line: &str) -> (Vec<&str>, Vec<&str>) {
+    let mut supernets = Vec::new();
+    let mut hypernets = Vec::new();
+
+    for (idx, section) in line.split(['[', ']']).enumerate() {
+        if idx % 2 == 0 {
+            supernets.push(section);
+        } else {
+            hypernets.push(section);
+        }
+    }
+
+    (supernets, hypernets)
+}
+
+fn has_abba(section: &str) -> bool {
+    section.as_bytes()
+        .windows(4)
+        .any(|w| w[0] == w[3] && w[1] == w[2] && w[0] != w[1])
+}
+
+fn validate_ip_tls(input: &str) -> Vec<String> {
+    input.lines()
+        .filter(|line| {
+            let (supernets, hypernets) = split_address(line);
+
+            !hypernets.iter().any(|section| has_abba(section)) &&
+            supernets.iter().any(|section| has_abba(section))
+        })
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn validate_ip_ssl(input: &str) -> Vec<String> {
+    input.lines()
+        .filter(|line| {
+            let (supernets, hypernets) = split_address(line);
+
+            // Find all ABAs and look for the corresponding BAB in the hypernet sections
+            supernets.iter().any(|section| {
+                section.as_bytes()
+                    .windows(3)
+                    .filter(|w| w[0] == w[2] && w[0] != w[1])
+                    .any(|w| {
+                        let bab = [w[1], w[0], w[1]];
+
+                        hypernets.iter().any(|hypernet| {
+                            hypernet.as_bytes().windows(3).any(|h| h == bab)
+                        })
+                    })
+            })
+        })
+        .map(|line| line.to_string())
+        .collect()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(validate_ip_tls(input).len().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(validate_ip_ssl(input).len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_ip_tls;
+    use super::validate_ip_ssl;
+
+    #[test]
+    fn test_tls_validation() {
+        let test_str = "abba[mnop]qrst\n\
+                        abcd[bddb]xyyx\n\
+                        aaaa[qwer]tyui\n\
+                        ioxxoj[asdfgh]zxcvbn";
+
+        assert_eq!(validate_ip_tls(test_str),
+                   vec!["abba[mnop]qrst".to_string(), "ioxxoj[asdfgh]zxcvbn".to_string()]);
+    }
+
+    #[test]
+    fn test_ssl_validation() {
+        let test_str = "aba[bab]xyz\n\
+                        xyx[xyx]xyx\n\
+                        aaa[kek]eke\n\
+                        zazbz[bzb]cdb";
+
+        assert_eq!(validate_ip_ssl(test_str),
+                   vec!["aba[bab]xyz".to_string(),
+                        "aaa[kek]eke".to_string(),
+                        "zazbz[bzb]cdb".to_string()]);
+    }
+}
@@ -0,0 +1,170 @@
+extern crate aoc_common;
+#[macro_use]
+extern crate lazy_static;
+
+extern crate regex;
+
+use std::str::FromStr;
+use std::fmt;
+
+use regex::Regex;
+
+use aoc_common::{parse_lines, AocError, AocResult, Grid, Point, Solver};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Instruction {
+    Rect(usize, usize),
+    RotateRow(usize, usize),
+    RotateCol(usize, usize),
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RECT_RE: Regex =
+                Regex::new("rect (?P<x_size>[0-9]+)x(?P<y_size>[0-9]+)").unwrap();
+            static ref ROT_ROW_RE: Regex =
+                Regex::new("rotate row y=(?P<row_num>[0-9]+) by (?P<rot_by>[0-9]+)").unwrap();
+            static ref ROT_COL_RE: Regex =
+                Regex::new("rotate column x=(?P<col_num>[0-9]+) by (?P<rot_by>[0-9]+)").unwrap();
+        }
+
+        fn number(caps: &regex::Captures, name: &str) -> Result<usize, AocError> {
+            caps.name(name)
+                .ok_or_else(|| AocError::parse(format!("Missing {}", name)))
+                .and_then(|num_str| num_str.parse().map_err(AocError::from))
+        }
+
+        if let Some(caps) = RECT_RE.captures(s) {
+            Ok(Instruction::Rect(number(&caps, "x_size")?, number(&caps, "y_size")?))
+        } else if let Some(caps) = ROT_ROW_RE.captures(s) {
+            Ok(Instruction::RotateRow(number(&caps, "row_num")?, number(&caps, "rot_by")?))
+        } else if let Some(caps) = ROT_COL_RE.captures(s) {
+            Ok(Instruction::RotateCol(number(&caps, "col_num")?, number(&caps, "rot_by")?))
+        } else {
+            Err(AocError::parse(format!("Invalid instruction: {:?}", s)))
+        }
+    }
+}
+
+struct Screen(Grid<bool>);
+
+impl Screen {
+    fn new(width: usize, height: usize) -> Screen {
+        Screen(Grid::new(width, height, false))
+    }
+
+    fn execute_instruction(&mut self, instr: Instruction) {
+        match instr {
+            Instruction::Rect(width, height) => {
+                self.0.view_mut(Point::new(0, 0), width, height).fill(true);
+            }
+            Instruction::RotateRow(row_idx, offset) => self.0.shift_row(row_idx, offset),
+            Instruction::RotateCol(col_idx, offset) => self.0.shift_column(col_idx, offset),
+        }
+    }
+
+    fn get_num_pixels_lit(&self) -> u64 {
+        self.0.values().filter(|state| **state).count() as u64
+    }
+}
+
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0.render(|state| if *state { '#' } else { '.' }))
+    }
+}
+
+
+fn parse_instructions(s: &str) -> AocResult<Vec<Instruction>> {
+    parse_lines(s)
+}
+
+fn run_screen(instructions: &[Instruction]) -> Screen {
+    let mut screen = Screen::new(50, 6);
+
+    for instr in instructions {
+        screen.execute_instruction(*instr);
+    }
+
+    screen
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(run_screen(input).get_num_pixels_lit().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        // The code is spelled out in lit pixels on the screen.
+        Ok(format!("\n{}", run_screen(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_instructions;
+    use super::Instruction;
+    use super::Screen;
+
+    const TEST_STR: &str = "rect 3x2\n\
+                                    rotate column x=1 by 1\n\
+                                    rotate row y=0 by 4\n\
+                                    rotate column x=1 by 1";
+    #[test]
+    fn parse_test() {
+        assert_eq!(parse_instructions(TEST_STR).unwrap(),
+                   vec![Instruction::Rect(3, 2),
+                        Instruction::RotateCol(1, 1),
+                        Instruction::RotateRow(0, 4),
+                        Instruction::RotateCol(1, 1)]);
+    }
+
+    #[test]
+    fn instructions_test() {
+        let instructions = parse_instructions(TEST_STR).unwrap();
+
+        let mut screen = Screen::new(7, 3);
+
+        assert_eq!(format!("{}", screen),
+                   ".......\n\
+                    .......\n\
+                    .......\n");
+
+        screen.execute_instruction(instructions[0]);
+        assert_eq!(format!("{}", screen),
+                   "###....\n\
+                    ###....\n\
+                    .......\n");
+
+        screen.execute_instruction(instructions[1]);
+        assert_eq!(format!("{}", screen),
+                   "#.#....\n\
+                    ###....\n\
+                    .#.....\n");
+
+        screen.execute_instruction(instructions[2]);
+        assert_eq!(format!("{}", screen),
+                   "....#.#\n\
+                    ###....\n\
+                    .#.....\n");
+
+        screen.execute_instruction(instructions[3]);
+        assert_eq!(format!("{}", screen),
+                   ".#..#.#\n\
+                    #.#....\n\
+                    .#.....\n");
+
+    }
+}
@@ -1,9 +1,9 @@
+extern crate aoc_common;
 extern crate regex;
 
 use regex::Regex;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
 fn get_decompressed_length(untrimmed_input: &str) -> usize {
     let input = untrimmed_input.trim();
@@ -70,17 +70,23 @@ fn get_decompressed_length_recursive(untrimmed_input: &str) -> usize {
 }
 
 
+pub struct Solution;
 
-fn main() {
-    let mut compressed_str = String::new();
-    io::stdin().read_to_string(&mut compressed_str).expect("Invalid input string!");
+impl Solver for Solution {
+    type Input = String;
 
-    println!("The decompressed length of the input string is: {}",
-             get_decompressed_length(&compressed_str));
-    println!("The recursively decompressed length of the input string is: {}",
-             get_decompressed_length_recursive(&compressed_str));
-}
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(get_decompressed_length(input).to_string())
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(get_decompressed_length_recursive(input).to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1,54 +1,47 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 use regex::Regex;
 
 use std::collections::{HashMap, HashSet};
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
+
+pub mod simulator;
 
-use std::cell::RefCell;
+use simulator::Simulator;
 
 #[derive(Debug, PartialEq, Eq)]
-struct Output {
+#[derive(Default)]
+pub struct Output {
     contained_chips: HashSet<usize>,
 }
 
 impl Output {
+    pub fn chips(&self) -> &HashSet<usize> {
+        &self.contained_chips
+    }
+
     fn put_chip(&mut self, chip_id: usize) {
         self.contained_chips.insert(chip_id);
     }
 }
 
-impl Default for Output {
-    fn default() -> Output {
-        Output { contained_chips: HashSet::new() }
-    }
-}
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
-enum Action {
+pub enum Action {
     GiveToBot(usize),
     PutIntoOutput(usize),
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Bot {
+#[derive(Default)]
+pub struct Bot {
     chips: (Option<usize>, Option<usize>),
     action_lower: Option<Action>,
     action_higher: Option<Action>,
 }
 
-impl Default for Bot {
-    fn default() -> Bot {
-        Bot {
-            chips: (None, None),
-            action_lower: None,
-            action_higher: None,
-        }
-    }
-}
 
 impl Bot {
     fn give_chip(&mut self, new_chip: usize) {
@@ -65,19 +58,17 @@ impl Bot {
         self.chips.0.is_none() || self.chips.1.is_none()
     }
 
+    /// Where the low and the high chip go, once the bot holds two chips and has instructions.
     fn get_actions(&self) -> Option<((Action, usize), (Action, usize))> {
-        if self.chips.0.is_some() && self.chips.1.is_some() {
-            let left = self.chips.0.unwrap();
-            let right = self.chips.1.unwrap();
-
+        if let (Some(left), Some(right)) = self.chips {
             let (low, high) = if left <= right {
                 (left, right)
             } else {
                 (right, left)
             };
 
-            Some(((self.action_lower.unwrap(), low), (
-                self.action_higher.unwrap(),
+            Some(((self.action_lower?, low), (
+                self.action_higher?,
                 high,
             )))
         } else {
@@ -91,7 +82,7 @@ impl Bot {
 }
 
 
-fn parse_input(input: &str) -> (HashMap<usize, Output>, HashMap<usize, RefCell<Bot>>) {
+fn parse_input(input: &str) -> AocResult<(HashMap<usize, Output>, HashMap<usize, Bot>)> {
     let mut outputs = HashMap::new();
     let mut bots = HashMap::new();
 
@@ -104,8 +95,13 @@ fn parse_input(input: &str) -> (HashMap<usize, Output>, HashMap<usize, RefCell<B
             let chip_id = value_caps.name("chip_id").unwrap().parse().unwrap();
             let bot_id = value_caps.name("bot_id").unwrap().parse().unwrap();
 
-            let bot = bots.entry(bot_id).or_insert(RefCell::new(Bot::default()));
-            bot.get_mut().give_chip(chip_id);
+            let bot: &mut Bot = bots.entry(bot_id).or_default();
+
+            if !bot.can_accept_chip() {
+                return Err(AocError::parse(format!("Bot {} is given more than two chips: {:?}", bot_id, line)));
+            }
+
+            bot.give_chip(chip_id);
 
         } else if let Some(bot_instr_caps) = bot_instr_re.captures(line) {
             let bot_id = bot_instr_caps.name("bot_id").unwrap().parse().unwrap();
@@ -119,103 +115,34 @@ fn parse_input(input: &str) -> (HashMap<usize, Output>, HashMap<usize, RefCell<B
                 .unwrap();
 
             if dest_low == "bot" {
-                let bot = bots.entry(bot_id).or_insert(RefCell::new(Bot::default()));
-                bot.get_mut().action_lower = Some(Action::GiveToBot(dest_low_id));
+                let bot = bots.entry(bot_id).or_default();
+                bot.action_lower = Some(Action::GiveToBot(dest_low_id));
 
             } else if dest_low == "output" {
-                let bot = bots.entry(bot_id).or_insert(RefCell::new(Bot::default()));
-                bot.get_mut().action_lower = Some(Action::PutIntoOutput(dest_low_id));
+                let bot = bots.entry(bot_id).or_default();
+                bot.action_lower = Some(Action::PutIntoOutput(dest_low_id));
 
                 outputs.entry(dest_low_id).or_insert(Output::default());
             }
 
             if dest_high == "bot" {
-                let bot = bots.entry(bot_id).or_insert(RefCell::new(Bot::default()));
-                bot.get_mut().action_higher = Some(Action::GiveToBot(dest_high_id));
+                let bot = bots.entry(bot_id).or_default();
+                bot.action_higher = Some(Action::GiveToBot(dest_high_id));
 
             } else if dest_high == "output" {
-                let bot = bots.entry(bot_id).or_insert(RefCell::new(Bot::default()));
-                bot.get_mut().action_higher = Some(Action::PutIntoOutput(dest_high_id));
+                let bot = bots.entry(bot_id).or_default();
+                bot.action_higher = Some(Action::PutIntoOutput(dest_high_id));
 
                 outputs.entry(dest_high_id).or_insert(Output::default());
             }
 
         } else {
-            panic!();
+            return Err(AocError::parse(format!("Invalid instruction: {:?}", line)));
         }
     }
 
 
-    (outputs, bots)
-}
-
-fn find_ready_bot<'a, 'b>(
-    bots: &'a HashMap<usize, RefCell<Bot>>,
-    unusable_bot_ids: &'b HashSet<usize>,
-) -> Option<(&'a usize, &'a RefCell<Bot>)> {
-    bots.iter()
-        .filter(|&(id, bot)| {
-            !unusable_bot_ids.contains(id) && bot.borrow().get_actions().is_some()
-        })
-        .next()
-}
-
-
-fn process_chips(outputs: &mut HashMap<usize, Output>, bots: &mut HashMap<usize, RefCell<Bot>>) {
-
-    let mut unusable_bot_ids = HashSet::new();
-
-    while let Some((id, bot)) = find_ready_bot(bots, &unusable_bot_ids) {
-
-        let ((low_action, low_chip_id), (high_action, high_chip_id)) =
-            bot.borrow().get_actions().unwrap();
-
-        if low_chip_id == 17 && high_chip_id == 61 {
-            println!("The bot you're looking for is #{}", id);
-        }
-
-        if let Action::GiveToBot(low_bot_id) = low_action {
-            let low_bot = bots.get(&low_bot_id).unwrap().borrow_mut();
-            if !low_bot.can_accept_chip() {
-                unusable_bot_ids.insert(*id);
-                continue;
-            }
-        }
-
-        if let Action::GiveToBot(high_bot_id) = high_action {
-            let high_bot = bots.get(&high_bot_id).unwrap().borrow_mut();
-            if !high_bot.can_accept_chip() {
-                unusable_bot_ids.insert(*id);
-                continue;
-            }
-        }
-
-        match low_action {
-            Action::GiveToBot(tgt_bot_id) => {
-                bots.get(&tgt_bot_id).unwrap().borrow_mut().give_chip(
-                    low_chip_id,
-                );
-            }
-            Action::PutIntoOutput(output_id) => {
-                outputs.get_mut(&output_id).unwrap().put_chip(low_chip_id);
-            }
-        }
-
-        match high_action {
-            Action::GiveToBot(tgt_bot_id) => {
-                bots.get(&tgt_bot_id).unwrap().borrow_mut().give_chip(
-                    high_chip_id,
-                );
-            }
-            Action::PutIntoOutput(output_id) => {
-                outputs.get_mut(&output_id).unwrap().put_chip(high_chip_id);
-            }
-        }
-
-        bot.borrow_mut().take_chips();
-        unusable_bot_ids.clear();
-    }
-
+    Ok((outputs, bots))
 }
 
 fn get_product_of_outputs(outputs: &HashMap<usize, Output>) -> usize {
@@ -231,36 +158,50 @@ fn get_product_of_outputs(outputs: &HashMap<usize, Output>) -> usize {
         .product()
 }
 
-fn main() {
-    let mut instruction_str = String::new();
-    io::stdin().read_to_string(&mut instruction_str).expect(
-        "Invalid input string!",
-    );
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
 
-    let (mut outputs, mut bots) = parse_input(&instruction_str);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
-    process_chips(&mut outputs, &mut bots);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let (outputs, bots) = parse_input(input)?;
+        let mut simulator = Simulator::new(outputs, bots);
 
-    println!(
-        "Product of outputs 1, 2, 3 = {}",
-        get_product_of_outputs(&outputs)
-    );
-}
+        let stall = simulator.run().err();
+
+        match (simulator.who_compared(17, 61), stall) {
+            (Some(bot_id), _) => Ok(bot_id.to_string()),
+            (None, Some(stall)) => Err(stall.into()),
+            (None, None) => Err(AocError::unsolvable("No bot compared chips 17 and 61.")),
+        }
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let (outputs, bots) = parse_input(input)?;
+        let mut simulator = Simulator::new(outputs, bots);
 
+        simulator.run()?;
+
+        Ok(get_product_of_outputs(simulator.outputs()).to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
-    use std::cell::RefCell;
 
     use super::parse_input;
-    use super::process_chips;
+    use super::simulator::Simulator;
     use super::get_product_of_outputs;
     use super::Bot;
     use super::Output;
     use super::Action;
 
-    const TEST_INPUT: &'static str = "value 5 goes to bot 2\n\
+    const TEST_INPUT: &str = "value 5 goes to bot 2\n\
                                       bot 2 gives low to bot 1 and high to bot 0\n\
                                       value 3 goes to bot 1\n\
                                       bot 1 gives low to output 1 and high to bot 0\n\
@@ -277,32 +218,32 @@ mod tests {
         let mut expected_bots = HashMap::new();
         expected_bots.insert(
             0,
-            RefCell::new(Bot {
+            Bot {
                 chips: (None, None),
                 action_lower: Some(Action::PutIntoOutput(2)),
                 action_higher: Some(Action::PutIntoOutput(0)),
-            }),
+            },
         );
 
         expected_bots.insert(
             1,
-            RefCell::new(Bot {
+            Bot {
                 chips: (Some(3), None),
                 action_lower: Some(Action::PutIntoOutput(1)),
                 action_higher: Some(Action::GiveToBot(0)),
-            }),
+            },
         );
 
         expected_bots.insert(
             2,
-            RefCell::new(Bot {
+            Bot {
                 chips: (Some(5), Some(2)),
                 action_lower: Some(Action::GiveToBot(1)),
                 action_higher: Some(Action::GiveToBot(0)),
-            }),
+            },
         );
 
-        let (outputs, bots) = parse_input(TEST_INPUT);
+        let (outputs, bots) = parse_input(TEST_INPUT).unwrap();
 
         assert_eq!(expected_outputs, outputs);
 
@@ -311,10 +252,13 @@ mod tests {
 
     #[test]
     fn run_test() {
-        let (mut outputs, mut bots) = parse_input(&TEST_INPUT);
+        let (outputs, bots) = parse_input(TEST_INPUT).unwrap();
+        let mut simulator = Simulator::new(outputs, bots);
 
-        process_chips(&mut outputs, &mut bots);
+        simulator.run().unwrap();
+        assert_eq!(Some(2), simulator.who_compared(2, 5));
 
+        let outputs = simulator.outputs();
 
         let mut out_0 = Output::default();
         out_0.put_chip(5);
@@ -330,10 +274,11 @@ mod tests {
 
     #[test]
     fn product_test() {
-        let (mut outputs, mut bots) = parse_input(&TEST_INPUT);
+        let (outputs, bots) = parse_input(TEST_INPUT).unwrap();
+        let mut simulator = Simulator::new(outputs, bots);
 
-        process_chips(&mut outputs, &mut bots);
+        simulator.run().unwrap();
 
-        assert_eq!(30, get_product_of_outputs(&outputs));
+        assert_eq!(30, get_product_of_outputs(simulator.outputs()));
     }
 }
@@ -0,0 +1,466 @@
+//! Event driven simulation of the bot factory, keeping the whole history.
+//!
+//! Bots holding two chips wait in a queue for their turn. A bot whose destination has its hands
+//! full waits for that bot instead, and is queued again once it has given its chips away, so no
+//! bot is ever looked at without being able to act. Everything still holding chips when the
+//! queue runs dry is stuck, and so are bots handing the same chips around in a circle.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use aoc_common::AocError;
+
+use {Action, Bot, Output};
+
+/// A place a chip can be in.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Location {
+    Bot(usize),
+    Output(usize),
+}
+
+impl From<Action> for Location {
+    fn from(action: Action) -> Location {
+        match action {
+            Action::GiveToBot(bot_id) => Location::Bot(bot_id),
+            Action::PutIntoOutput(output_id) => Location::Output(output_id),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Location::Bot(bot_id) => write!(f, "bot {}", bot_id),
+            Location::Output(output_id) => write!(f, "output {}", output_id),
+        }
+    }
+}
+
+/// A bot comparing its two chips and handing them on.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Comparison {
+    pub bot: usize,
+    pub low: usize,
+    pub high: usize,
+    pub low_to: Location,
+    pub high_to: Location,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "bot {} compared {} and {}: low to {}, high to {}",
+            self.bot, self.low, self.high, self.low_to, self.high_to
+        )
+    }
+}
+
+/// Why a bot could not give its chips away.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Reason {
+    /// The bot holds a single chip and no second one arrived.
+    MissingChip,
+    /// The bot holds two chips but was never told where to put them.
+    NoInstructions,
+    /// The bot holds two chips, but the bot it has to give one to has its hands full.
+    Blocked(usize),
+    /// The bot is one of those passing the same chips around in a circle.
+    Cycling,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StuckBot {
+    pub bot: usize,
+    pub chips: Vec<usize>,
+    pub reason: Reason,
+}
+
+/// The bots left holding chips when nothing could happen any more, or the bots passing chips
+/// around in a circle, sorted by id.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Stall {
+    pub bots: Vec<StuckBot>,
+}
+
+impl fmt::Display for Stall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bots = self
+            .bots
+            .iter()
+            .map(|stuck| {
+                let reason = match stuck.reason {
+                    Reason::MissingChip => "waits for a second chip".to_string(),
+                    Reason::NoInstructions => "has no instructions".to_string(),
+                    Reason::Blocked(other) => format!("waits for bot {} to free its hands", other),
+                    Reason::Cycling => "keeps passing chips around in a circle".to_string(),
+                };
+
+                format!("bot {} holds {:?} and {}", stuck.bot, stuck.chips, reason)
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, "the factory stalled: {}", bots.join("; "))
+    }
+}
+
+impl From<Stall> for AocError {
+    fn from(stall: Stall) -> AocError {
+        AocError::unsolvable(stall.to_string())
+    }
+}
+
+fn chips_of(bot: &Bot) -> Vec<usize> {
+    vec![bot.chips.0, bot.chips.1].into_iter().flatten().collect()
+}
+
+/// Everything deciding what the factory does next: the chips each bot holds, the queue and the
+/// waiting bots.
+type Snapshot = (Vec<(usize, Vec<usize>)>, Vec<usize>, Vec<(usize, Vec<usize>)>);
+
+pub struct Simulator {
+    bots: HashMap<usize, Bot>,
+    outputs: HashMap<usize, Output>,
+    /// Bots holding two chips, in the order they got the second one.
+    queue: VecDeque<usize>,
+    /// Bots waiting for the key bot to give its chips away.
+    waiting: HashMap<usize, Vec<usize>>,
+    history: Vec<Comparison>,
+    paths: HashMap<usize, Vec<Location>>,
+}
+
+impl Simulator {
+    pub fn new(outputs: HashMap<usize, Output>, bots: HashMap<usize, Bot>) -> Simulator {
+        let mut ready = bots
+            .iter()
+            .filter(|&(_, bot)| chips_of(bot).len() == 2)
+            .map(|(&id, _)| id)
+            .collect::<Vec<usize>>();
+
+        // the order of the initial chips is not known, queue the bots by id to always get the same run
+        ready.sort();
+
+        let paths = bots
+            .iter()
+            .flat_map(|(&id, bot)| chips_of(bot).into_iter().map(move |chip| (chip, vec![Location::Bot(id)])))
+            .collect();
+
+        Simulator {
+            bots,
+            outputs,
+            queue: ready.into_iter().collect(),
+            waiting: HashMap::new(),
+            history: Vec::new(),
+            paths,
+        }
+    }
+
+    pub fn outputs(&self) -> &HashMap<usize, Output> {
+        &self.outputs
+    }
+
+    /// Every comparison so far, in the order they happened.
+    pub fn history(&self) -> &[Comparison] {
+        &self.history
+    }
+
+    /// The bot that compared the two chips, given in any order.
+    pub fn who_compared(&self, chip_1: usize, chip_2: usize) -> Option<usize> {
+        let (low, high) = if chip_1 <= chip_2 { (chip_1, chip_2) } else { (chip_2, chip_1) };
+
+        self.history
+            .iter()
+            .find(|comparison| comparison.low == low && comparison.high == high)
+            .map(|comparison| comparison.bot)
+    }
+
+    pub fn comparisons_by(&self, bot_id: usize) -> Vec<&Comparison> {
+        self.history.iter().filter(|comparison| comparison.bot == bot_id).collect()
+    }
+
+    /// Every place the chip has been in, starting with the bot it was given to.
+    pub fn path_of(&self, chip: usize) -> Option<&[Location]> {
+        self.paths.get(&chip).map(Vec::as_slice)
+    }
+
+    /// Handles the next bot in the queue, returning the comparison it made if it could act.
+    /// Returns `None` as well when the queue is empty, `is_idle` tells the two apart.
+    pub fn step(&mut self) -> Option<Comparison> {
+        let id = self.queue.pop_front()?;
+
+        // missing instructions are reported by `stall`
+        let ((low_action, low_chip), (high_action, high_chip)) = self.bots.get(&id).and_then(Bot::get_actions)?;
+
+        // The chips leave before they arrive, so a bot can give chips to itself
+        let mut needed = HashMap::new();
+
+        for action in &[low_action, high_action] {
+            if let Action::GiveToBot(dest) = *action {
+                *needed.entry(dest).or_insert(0) += 1;
+            }
+        }
+
+        for (&dest, &count) in &needed {
+            let held = if dest == id {
+                0
+            } else {
+                self.bots.get(&dest).map_or(0, |bot| chips_of(bot).len())
+            };
+
+            if held + count > 2 {
+                self.waiting.entry(dest).or_default().push(id);
+                return None;
+            }
+        }
+
+        self.bots.get_mut(&id).unwrap().take_chips();
+
+        for &(action, chip) in &[(low_action, low_chip), (high_action, high_chip)] {
+            match action {
+                Action::GiveToBot(dest) => {
+                    let bot = self.bots.entry(dest).or_default();
+                    bot.give_chip(chip);
+
+                    if chips_of(bot).len() == 2 {
+                        self.queue.push_back(dest);
+                    }
+                }
+                Action::PutIntoOutput(output_id) => self.outputs.entry(output_id).or_default().put_chip(chip),
+            }
+
+            self.paths.entry(chip).or_default().push(action.into());
+        }
+
+        if let Some(waiting) = self.waiting.remove(&id) {
+            self.queue.extend(waiting);
+        }
+
+        let comparison = Comparison {
+            bot: id,
+            low: low_chip,
+            high: high_chip,
+            low_to: low_action.into(),
+            high_to: high_action.into(),
+        };
+
+        self.history.push(comparison);
+        Some(comparison)
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Runs until nothing can happen any more, failing if chips are left in bots, or once the
+    /// factory is back where it was and would go round in circles forever.
+    pub fn run(&mut self) -> Result<(), Stall> {
+        // the number of comparisons made by the time of each snapshot
+        let mut seen = HashMap::new();
+
+        while !self.is_idle() {
+            if let Some(start) = seen.insert(self.snapshot(), self.history.len()) {
+                return Err(self.cycle(start));
+            }
+
+            self.step();
+        }
+
+        match self.stall() {
+            Some(stall) => Err(stall),
+            None => Ok(()),
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        let mut holdings = self
+            .bots
+            .iter()
+            .map(|(&id, bot)| {
+                let mut chips = chips_of(bot);
+                chips.sort();
+
+                (id, chips)
+            })
+            .filter(|(_, chips)| !chips.is_empty())
+            .collect::<Vec<(usize, Vec<usize>)>>();
+        holdings.sort();
+
+        let mut waiting = self.waiting.iter().map(|(&id, bots)| (id, bots.clone())).collect::<Vec<_>>();
+        waiting.sort();
+
+        (holdings, self.queue.iter().cloned().collect(), waiting)
+    }
+
+    /// The bots that compared chips since the comparison `start`, going round in circles.
+    fn cycle(&self, start: usize) -> Stall {
+        let mut ids = self.history[start..].iter().map(|comparison| comparison.bot).collect::<Vec<usize>>();
+        ids.sort();
+        ids.dedup();
+
+        let bots = ids
+            .into_iter()
+            .map(|id| StuckBot {
+                bot: id,
+                chips: self.bots.get(&id).map_or_else(Vec::new, chips_of),
+                reason: Reason::Cycling,
+            })
+            .collect();
+
+        Stall { bots }
+    }
+
+    /// The bots still holding chips, once the simulation is idle.
+    pub fn stall(&self) -> Option<Stall> {
+        let mut bots = self
+            .bots
+            .iter()
+            .filter(|&(_, bot)| !chips_of(bot).is_empty())
+            .map(|(&id, bot)| {
+                let chips = chips_of(bot);
+
+                let reason = if chips.len() < 2 {
+                    Reason::MissingChip
+                } else if bot.get_actions().is_none() {
+                    Reason::NoInstructions
+                } else {
+                    let blocker = self.waiting.iter().find(|&(_, waiting)| waiting.contains(&id));
+                    Reason::Blocked(*blocker.expect("a stuck bot with two chips is waiting").0)
+                };
+
+                StuckBot { bot: id, chips, reason }
+            })
+            .collect::<Vec<StuckBot>>();
+
+        if bots.is_empty() {
+            None
+        } else {
+            bots.sort_by_key(|stuck| stuck.bot);
+            Some(Stall { bots })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comparison, Location, Reason, Simulator};
+    use parse_input;
+
+    const TEST_INPUT: &str = "value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2";
+
+    fn simulator(input: &str) -> Simulator {
+        let (outputs, bots) = parse_input(input).unwrap();
+        Simulator::new(outputs, bots)
+    }
+
+    #[test]
+    fn history_test() {
+        let mut simulator = simulator(TEST_INPUT);
+
+        assert_eq!(Ok(()), simulator.run());
+
+        assert_eq!(
+            vec![
+                "bot 2 compared 2 and 5: low to bot 1, high to bot 0",
+                "bot 1 compared 2 and 3: low to output 1, high to bot 0",
+                "bot 0 compared 3 and 5: low to output 2, high to output 0",
+            ],
+            simulator.history().iter().map(Comparison::to_string).collect::<Vec<String>>()
+        );
+
+        assert_eq!(Some(2), simulator.who_compared(5, 2));
+        assert_eq!(Some(0), simulator.who_compared(3, 5));
+        assert_eq!(None, simulator.who_compared(2, 4));
+        assert_eq!(1, simulator.comparisons_by(1).len());
+
+        assert_eq!(
+            Some(&[Location::Bot(2), Location::Bot(1), Location::Output(1)][..]),
+            simulator.path_of(2)
+        );
+        assert_eq!(Some(&[Location::Bot(1), Location::Bot(0), Location::Output(2)][..]), simulator.path_of(3));
+        assert_eq!(None, simulator.path_of(4));
+    }
+
+    #[test]
+    fn blocked_test() {
+        // bot 0 has to wait for bot 1 to pass its chips on before it can give it its own
+        let mut simulator = simulator(
+            "value 1 goes to bot 0
+value 2 goes to bot 0
+value 3 goes to bot 1
+value 4 goes to bot 1
+bot 0 gives low to bot 1 and high to bot 1
+bot 1 gives low to output 1 and high to output 2",
+        );
+
+        assert_eq!(Ok(()), simulator.run());
+        assert_eq!(vec![1, 0, 1], simulator.history().iter().map(|c| c.bot).collect::<Vec<usize>>());
+        assert_eq!(Some(0), simulator.who_compared(1, 2));
+        assert_eq!(2, simulator.comparisons_by(1).len());
+        assert_eq!(
+            Some(&[Location::Bot(0), Location::Bot(1), Location::Output(1)][..]),
+            simulator.path_of(1)
+        );
+    }
+
+    #[test]
+    fn stall_test() {
+        let mut simulator = simulator(
+            "value 1 goes to bot 0
+value 2 goes to bot 0
+value 3 goes to bot 1
+value 4 goes to bot 1
+value 5 goes to bot 2
+value 6 goes to bot 3
+value 7 goes to bot 3
+bot 0 gives low to bot 1 and high to output 0
+bot 1 gives low to bot 0 and high to output 1",
+        );
+
+        let stall = simulator.run().unwrap_err();
+        let reasons = stall.bots.iter().map(|stuck| (stuck.bot, stuck.reason)).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (0, Reason::Blocked(1)),
+                (1, Reason::Blocked(0)),
+                (2, Reason::MissingChip),
+                (3, Reason::NoInstructions),
+            ],
+            reasons
+        );
+        assert_eq!(vec![6, 7], stall.bots[3].chips);
+        assert!(simulator.history().is_empty());
+        assert!(stall.to_string().ends_with("bot 2 holds [5] and waits for a second chip; bot 3 holds [6, 7] and has no instructions"));
+    }
+
+    #[test]
+    fn cycle_test() {
+        // bot 0 gives both chips to bot 1, which gives them back
+        let mut circle = simulator(
+            "value 1 goes to bot 0
+value 2 goes to bot 0
+value 3 goes to bot 2
+bot 0 gives low to bot 1 and high to bot 1
+bot 1 gives low to bot 0 and high to bot 0",
+        );
+
+        let stall = circle.run().unwrap_err();
+
+        assert_eq!(
+            vec![(0, vec![1, 2], Reason::Cycling), (1, vec![], Reason::Cycling)],
+            stall.bots.iter().map(|stuck| (stuck.bot, stuck.chips.clone(), stuck.reason)).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![0, 1], circle.history().iter().map(|c| c.bot).collect::<Vec<usize>>());
+
+        // a bot keeping its own chips
+        let mut selfish = simulator("value 1 goes to bot 4\nvalue 2 goes to bot 4\nbot 4 gives low to bot 4 and high to bot 4");
+
+        assert_eq!(vec![4], selfish.run().unwrap_err().bots.iter().map(|stuck| stuck.bot).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,330 @@
+//! The radioisotope testing facility, reduced to what matters for the search.
+//!
+//! The elements are interchangeable: swapping the floors of the thulium and the plutonium items
+//! gives a state that takes exactly as many moves to solve. So a state is only the elevator floor
+//! and the sorted list of (generator floor, chip floor) pairs, packed into an integer, and every
+//! class of equivalent states is explored once.
+
+use itertools::Itertools;
+
+use aoc_common::search::{self, SearchResult, SearchState};
+use aoc_common::{AocError, AocResult};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Facility {
+    floors: usize,
+    elevator: usize,
+    /// The floors of the generator and of the chip of each element, sorted.
+    pairs: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Item {
+    Generator(usize),
+    Chip(usize),
+}
+
+impl Facility {
+    /// A facility with the elevator on the first floor. Floors are numbered from 0.
+    pub fn new(floors: usize, mut pairs: Vec<(usize, usize)>) -> Facility {
+        pairs.sort();
+
+        Facility {
+            floors,
+            elevator: 0,
+            pairs,
+        }
+    }
+
+    /// The same facility, with one more element.
+    pub fn with_pair(&self, generator: usize, chip: usize) -> Facility {
+        let mut pairs = self.pairs.clone();
+        pairs.push((generator, chip));
+
+        Facility {
+            elevator: self.elevator,
+            ..Facility::new(self.floors, pairs)
+        }
+    }
+
+    pub fn floors(&self) -> usize {
+        self.floors
+    }
+
+    pub fn elevator(&self) -> usize {
+        self.elevator
+    }
+
+    pub fn pairs(&self) -> &[(usize, usize)] {
+        &self.pairs
+    }
+
+    /// Whether no chip is on a floor with another element's generator, without its own.
+    pub fn is_safe(&self) -> bool {
+        self.pairs
+            .iter()
+            .all(|&(generator, chip)| generator == chip || self.pairs.iter().all(|&(other, _)| other != chip))
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pairs.iter().all(|&(generator, chip)| generator == self.floors - 1 && chip == self.floors - 1)
+    }
+
+    /// A lower bound of the moves left. Taking `n` items past a floor takes at least `2n - 3`
+    /// moves, as the elevator carries two items up and has to bring one back down each time.
+    pub fn heuristic(&self) -> usize {
+        let mut below = 0;
+        let mut moves = 0;
+
+        for floor in 0..self.floors - 1 {
+            below += self
+                .pairs
+                .iter()
+                .map(|&(generator, chip)| (generator == floor) as usize + (chip == floor) as usize)
+                .sum::<usize>();
+
+            moves += match below {
+                0 => 0,
+                1 | 2 => 1,
+                n => 2 * n - 3,
+            };
+        }
+
+        moves
+    }
+
+    fn items_on(&self, floor: usize) -> Vec<Item> {
+        let mut items = Vec::new();
+
+        for (element, &(generator, chip)) in self.pairs.iter().enumerate() {
+            if generator == floor {
+                items.push(Item::Generator(element));
+            }
+
+            if chip == floor {
+                items.push(Item::Chip(element));
+            }
+        }
+
+        items
+    }
+
+    fn carry(&self, items: &[Item], to: usize) -> Facility {
+        let mut pairs = self.pairs.clone();
+
+        for item in items {
+            match *item {
+                Item::Generator(element) => pairs[element].0 = to,
+                Item::Chip(element) => pairs[element].1 = to,
+            }
+        }
+
+        pairs.sort();
+
+        Facility {
+            floors: self.floors,
+            elevator: to,
+            pairs,
+        }
+    }
+
+    /// The safe states one elevator ride away.
+    fn moves(&self) -> Vec<Facility> {
+        let items = self.items_on(self.elevator);
+
+        let loads = items
+            .iter()
+            .map(|&item| vec![item])
+            .chain(items.iter().tuple_combinations().map(|(&first, &second)| vec![first, second]))
+            .collect::<Vec<Vec<Item>>>();
+
+        let mut targets = Vec::new();
+
+        if self.elevator + 1 < self.floors {
+            targets.push(self.elevator + 1);
+        }
+
+        // taking items down to floors that are already empty never helps
+        let anything_below = self
+            .pairs
+            .iter()
+            .any(|&(generator, chip)| generator < self.elevator || chip < self.elevator);
+
+        if self.elevator > 0 && anything_below {
+            targets.push(self.elevator - 1);
+        }
+
+        targets
+            .into_iter()
+            .flat_map(|to| loads.iter().map(move |load| self.carry(load, to)))
+            .filter(Facility::is_safe)
+            .collect()
+    }
+}
+
+/// How the floors and elements of a facility are packed into a `u64`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+struct Shape {
+    floors: usize,
+    elements: usize,
+    bits_per_floor: u32,
+}
+
+impl Shape {
+    fn of(facility: &Facility) -> AocResult<Shape> {
+        let bits_per_floor = (usize::BITS - (facility.floors - 1).leading_zeros()).max(1);
+        let elements = facility.pairs.len();
+
+        if bits_per_floor as usize * (2 * elements + 1) > 64 {
+            return Err(AocError::runtime(format!(
+                "{} elements on {} floors are too many to search",
+                elements, facility.floors
+            )));
+        }
+
+        Ok(Shape {
+            floors: facility.floors,
+            elements,
+            bits_per_floor,
+        })
+    }
+
+    fn encode(&self, facility: &Facility) -> u64 {
+        let mut code = facility.elevator as u64;
+
+        for &(generator, chip) in &facility.pairs {
+            code = (code << self.bits_per_floor | generator as u64) << self.bits_per_floor | chip as u64;
+        }
+
+        code
+    }
+
+    fn decode(&self, mut code: u64) -> Facility {
+        let mask = (1 << self.bits_per_floor) - 1;
+        let mut pairs = Vec::with_capacity(self.elements);
+
+        for _ in 0..self.elements {
+            let chip = (code & mask) as usize;
+            code >>= self.bits_per_floor;
+            let generator = (code & mask) as usize;
+            code >>= self.bits_per_floor;
+
+            pairs.push((generator, chip));
+        }
+
+        pairs.reverse();
+
+        Facility {
+            floors: self.floors,
+            elevator: code as usize,
+            pairs,
+        }
+    }
+}
+
+/// A facility in its canonical, packed form.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Packed {
+    code: u64,
+    shape: Shape,
+}
+
+impl Packed {
+    pub fn new(facility: &Facility) -> AocResult<Packed> {
+        let shape = Shape::of(facility)?;
+
+        Ok(Packed {
+            code: shape.encode(facility),
+            shape,
+        })
+    }
+
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    pub fn unpack(&self) -> Facility {
+        self.shape.decode(self.code)
+    }
+}
+
+impl SearchState for Packed {
+    type Cost = usize;
+
+    fn neighbors(&self) -> Vec<(Packed, usize)> {
+        self.unpack()
+            .moves()
+            .iter()
+            .map(|facility| {
+                let packed = Packed {
+                    code: self.shape.encode(facility),
+                    shape: self.shape,
+                };
+
+                (packed, 1)
+            })
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.unpack().is_done()
+    }
+
+    fn heuristic(&self) -> usize {
+        self.unpack().heuristic()
+    }
+}
+
+/// Searches for the fewest elevator rides bringing everything to the top floor.
+pub fn find_solution(facility: &Facility) -> AocResult<Option<SearchResult<Packed>>> {
+    Ok(search::a_star(Packed::new(facility)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_solution, Facility, Packed};
+    use aoc_common::search;
+
+    /// The hydrogen and lithium chips on the first floor, their generators on the second and third.
+    fn example() -> Facility {
+        Facility::new(4, vec![(1, 0), (2, 0)])
+    }
+
+    #[test]
+    fn find_solution_test() {
+        let result = find_solution(&example()).unwrap().unwrap();
+
+        assert_eq!(11, result.steps());
+        assert!(result.goal().unpack().is_done());
+        assert_eq!(Some(11), search::bfs(Packed::new(&example()).unwrap()).map(|result| result.steps()));
+
+        // the elevator does not move empty
+        assert!(find_solution(&Facility::new(3, vec![(1, 1)])).unwrap().is_none());
+    }
+
+    #[test]
+    fn canonical_test() {
+        let swapped = Facility::new(4, vec![(2, 0), (1, 0)]);
+        let packed = Packed::new(&example()).unwrap();
+
+        assert_eq!(packed, Packed::new(&swapped).unwrap());
+        assert_eq!(0b00_01_00_10_00, packed.code());
+        assert_eq!(example(), packed.unpack());
+
+        // 3 bits for each of 21 slots
+        assert!(Packed::new(&Facility::new(8, vec![(0, 0); 10])).is_ok());
+        assert!(Packed::new(&Facility::new(8, vec![(0, 0); 11])).is_err());
+    }
+
+    #[test]
+    fn safety_test() {
+        assert!(example().is_safe());
+        assert!(!Facility::new(4, vec![(1, 0), (0, 0)]).is_safe());
+        assert!(!Facility::new(4, vec![(1, 1), (0, 1)]).is_safe());
+        assert!(Facility::new(4, vec![(1, 1), (1, 0)]).is_safe());
+        assert!(!Facility::new(4, vec![(2, 1), (1, 3)]).is_safe());
+
+        assert_eq!(0, Facility::new(4, vec![(3, 3)]).heuristic());
+        assert!(example().heuristic() <= 11);
+    }
+}
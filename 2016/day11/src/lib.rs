@@ -0,0 +1,34 @@
+extern crate aoc_common;
+extern crate itertools;
+
+pub mod facility;
+mod parser;
+
+use aoc_common::{AocError, AocResult, Solver};
+
+use facility::{find_solution, Facility};
+
+fn solve(facility: &Facility) -> AocResult<String> {
+    find_solution(facility)?
+        .map(|result| result.steps().to_string())
+        .ok_or_else(|| AocError::unsolvable("The equipment cannot be moved to the top floor."))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Facility;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        solve(input)
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        // an elerium and a dilithium generator and chip turn up on the first floor
+        solve(&input.with_pair(0, 0).with_pair(0, 0))
+    }
+}
@@ -0,0 +1,276 @@
+//! Parser for the floor descriptions of the puzzle input:
+//!
+//! ```text
+//! The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+//! The second floor contains a hydrogen generator.
+//! The third floor contains a lithium generator.
+//! The fourth floor contains nothing relevant.
+//! ```
+//!
+//! Any elements and up to ten floors are accepted, in any order, as long as every floor from the
+//! first up is described once. Every element needs both a generator and a microchip, and no chip
+//! may already be fried when the elevator starts.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::AocError;
+
+use facility::Facility;
+
+const ORDINALS: [&str; 10] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth",
+];
+
+/// Where an item was found: its floor, and the line and column it is described at.
+#[derive(Debug, Clone, Copy)]
+struct Found {
+    floor: usize,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Default)]
+struct Element {
+    generator: Option<Found>,
+    chip: Option<Found>,
+}
+
+/// The items of a list like "a thulium generator, a plutonium generator, and a strontium generator".
+fn split_items(list: &str) -> Vec<&str> {
+    list.split(", ")
+        .flat_map(|part| part.split(" and "))
+        .map(|item| item.strip_prefix("and ").unwrap_or(item))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+impl FromStr for Facility {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut described_floors: Vec<bool> = Vec::new();
+        // the elements in the order they are first mentioned
+        let mut names: Vec<&str> = Vec::new();
+        let mut elements: HashMap<&str, Element> = HashMap::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let column_of = |part: &str| part.as_ptr() as usize - line.as_ptr() as usize + 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (ordinal, contents) = line
+                .trim()
+                .strip_prefix("The ")
+                .and_then(|rest| rest.split_once(" floor contains "))
+                .ok_or_else(|| AocError::parse_at(idx + 1, 1, format!("Invalid line: {:?}", line.trim())))?;
+
+            let floor = ORDINALS
+                .iter()
+                .position(|&known| known == ordinal)
+                .ok_or_else(|| AocError::parse_at(idx + 1, column_of(ordinal), format!("Unknown floor: {:?}", ordinal)))?;
+
+            if described_floors.len() <= floor {
+                described_floors.resize(floor + 1, false);
+            }
+
+            if described_floors[floor] {
+                return Err(AocError::parse_at(
+                    idx + 1,
+                    column_of(ordinal),
+                    format!("The {} floor is described twice", ordinal),
+                ));
+            }
+
+            described_floors[floor] = true;
+
+            let contents = contents
+                .strip_suffix('.')
+                .ok_or_else(|| AocError::parse_at(idx + 1, line.len() + 1, "Missing the full stop"))?;
+
+            if contents == "nothing relevant" {
+                continue;
+            }
+
+            for item in split_items(contents) {
+                let found = Found {
+                    floor,
+                    line: idx + 1,
+                    column: column_of(item),
+                };
+                let invalid = || AocError::parse_at(found.line, found.column, format!("Invalid item: {:?}", item));
+
+                let described = item.strip_prefix("a ").or_else(|| item.strip_prefix("an ")).ok_or_else(invalid)?;
+
+                let (name, is_generator) = if let Some(name) = described.strip_suffix(" generator") {
+                    (name, true)
+                } else if let Some(name) = described.strip_suffix("-compatible microchip") {
+                    (name, false)
+                } else {
+                    return Err(invalid());
+                };
+
+                if name.is_empty() || name.contains(' ') {
+                    return Err(invalid());
+                }
+
+                if !elements.contains_key(name) {
+                    names.push(name);
+                }
+
+                let element = elements.entry(name).or_default();
+                let slot = if is_generator {
+                    &mut element.generator
+                } else {
+                    &mut element.chip
+                };
+
+                if slot.is_some() {
+                    return Err(AocError::parse_at(found.line, found.column, format!("There are two {}s", described)));
+                }
+
+                *slot = Some(found);
+            }
+        }
+
+        if described_floors.is_empty() {
+            return Err(AocError::parse("No floors are described"));
+        }
+
+        if let Some(missing) = described_floors.iter().position(|&described| !described) {
+            return Err(AocError::parse(format!("The {} floor is not described", ORDINALS[missing])));
+        }
+
+        let mut pairs = Vec::with_capacity(names.len());
+
+        for name in &names {
+            match elements[name] {
+                Element {
+                    generator: Some(generator),
+                    chip: Some(chip),
+                } => pairs.push((generator, chip)),
+                Element {
+                    generator: Some(found),
+                    chip: None,
+                } => {
+                    return Err(AocError::parse_at(
+                        found.line,
+                        found.column,
+                        format!("The {} generator has no microchip", name),
+                    ))
+                }
+                Element {
+                    chip: Some(found), ..
+                } => {
+                    return Err(AocError::parse_at(
+                        found.line,
+                        found.column,
+                        format!("The {}-compatible microchip has no generator", name),
+                    ))
+                }
+                _ => unreachable!("elements are only added with an item"),
+            }
+        }
+
+        // a chip is fried when it shares its floor with another element's generator, but not its own
+        for (name, &(generator, chip)) in names.iter().zip(&pairs) {
+            if generator.floor == chip.floor {
+                continue;
+            }
+
+            if let Some((other, _)) = names.iter().zip(&pairs).find(|&(_, &(other, _))| other.floor == chip.floor) {
+                return Err(AocError::parse_at(
+                    chip.line,
+                    chip.column,
+                    format!(
+                        "The {}-compatible microchip is fried by the {} generator on the {} floor",
+                        name, other, ORDINALS[chip.floor]
+                    ),
+                ));
+            }
+        }
+
+        Ok(Facility::new(
+            described_floors.len(),
+            pairs.iter().map(|&(generator, chip)| (generator.floor, chip.floor)).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ORDINALS;
+    use aoc_common::AocError;
+    use facility::Facility;
+
+    const TEST_INPUT: &str = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.";
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Facility::new(4, vec![(1, 0), (2, 0)]), TEST_INPUT.parse().unwrap());
+
+        let shuffled = "The second floor contains a cobalt generator, a curium generator, and an iron generator.
+The first floor contains a cobalt-compatible microchip.
+The third floor contains a curium-compatible microchip and an iron-compatible microchip.";
+        assert_eq!(Facility::new(3, vec![(1, 0), (1, 2), (1, 2)]), shuffled.parse().unwrap());
+
+        let facility = include_str!("../input.txt").parse::<Facility>().unwrap();
+        assert_eq!(&[(0, 0), (0, 1), (0, 1), (2, 2), (2, 2)], facility.pairs());
+    }
+
+    #[test]
+    fn error_test() {
+        let error = |input: &str| input.parse::<Facility>().unwrap_err();
+
+        assert_eq!(
+            AocError::parse_at(3, 26, "The lithium generator has no microchip"),
+            error(&TEST_INPUT.replacen(" and a lithium-compatible microchip", "", 1))
+        );
+        assert_eq!(
+            AocError::parse_at(1, 26, "The hydrogen-compatible microchip has no generator"),
+            error(&TEST_INPUT.replacen("a hydrogen generator", "nothing relevant", 1))
+        );
+
+        let fried = TEST_INPUT
+            .replacen("a hydrogen generator", "a helium generator", 1)
+            .replacen("microchip.", "microchip and a hydrogen generator.", 1)
+            .replacen("nothing relevant", "a helium-compatible microchip", 1);
+        assert_eq!(
+            AocError::parse_at(1, 62, "The lithium-compatible microchip is fried by the hydrogen generator on the first floor"),
+            error(&fried)
+        );
+        assert_eq!(
+            AocError::parse_at(3, 26, "Invalid item: \"a lithium reactor\""),
+            error(&TEST_INPUT.replacen("lithium generator", "lithium reactor", 1))
+        );
+        assert_eq!(
+            AocError::parse_at(2, 5, "The first floor is described twice"),
+            error(&TEST_INPUT.replacen("second", "first", 1))
+        );
+        assert_eq!(
+            AocError::parse("The third floor is not described"),
+            error(&TEST_INPUT.replacen("The third floor contains a lithium generator.\n", "", 1))
+        );
+        assert_eq!(
+            AocError::parse_at(2, 5, "Unknown floor: \"2nd\""),
+            error(&TEST_INPUT.replacen("second", "2nd", 1))
+        );
+
+        // the tenth floor is the highest one
+        let ten = ORDINALS
+            .iter()
+            .map(|ordinal| format!("The {} floor contains nothing relevant.", ordinal))
+            .collect::<Vec<String>>()
+            .join("\n");
+        assert_eq!(10, ten.parse::<Facility>().unwrap().floors());
+        assert_eq!(
+            AocError::parse_at(11, 5, "Unknown floor: \"eleventh\""),
+            error(&format!("{}\nThe eleventh floor contains nothing relevant.", ten))
+        );
+    }
+}
@@ -0,0 +1,29 @@
+extern crate aoc2016_day12;
+extern crate aoc_common;
+extern crate criterion;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc2016_day12::{Assembunny, Program, Registers};
+use aoc_common::compiled::CompiledMachine;
+use aoc_common::vm::Machine;
+
+fn interpreter_vs_compiled(c: &mut Criterion) {
+    let prog = include_str!("../input.txt").parse::<Program>().unwrap();
+
+    let regs = Registers::new();
+
+    let mut group = c.benchmark_group("2016/day12");
+
+    group.bench_function("interpreted", |b| {
+        b.iter(|| Machine::<Assembunny>::new(prog.instructions().to_vec(), regs.clone()).run())
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| CompiledMachine::<Assembunny>::new(prog.instructions().to_vec(), regs.clone()).run())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, interpreter_vs_compiled);
+criterion_main!(benches);
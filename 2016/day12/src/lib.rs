@@ -0,0 +1,509 @@
+extern crate aoc_common;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use aoc_common::compiled::{Compile, CompiledMachine, Op, Slot};
+use aoc_common::vm::{self, Fault, InstructionSet, Machine, Operand, RegisterFile, Status, Step, VmError};
+use aoc_common::{parse_lines, AocError, AocResult, Solver};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl vm::Register for Register {
+    const COUNT: usize = 4;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Register {
+        [Register::A, Register::B, Register::C, Register::D][index]
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reg_name = match *self {
+            Register::A => "a",
+            Register::B => "b",
+            Register::C => "c",
+            Register::D => "d",
+        };
+
+        write!(f, "{}", reg_name)
+    }
+}
+
+pub type Value = Operand<Register, i64>;
+
+pub type Registers = RegisterFile<Register, i64>;
+
+/// An assembunny instruction.
+///
+/// Toggling can turn any instruction into any other one with the same number of operands, so all
+/// operands are values. The parser still only accepts registers where an instruction writes.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Instruction {
+    Cpy(Value, Value),
+    Inc(Value),
+    Dec(Value),
+    Jnz(Value, Value),
+    Tgl(Value),
+    Out(Value),
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn register(name: &str) -> Result<Value, AocError> {
+            vm::Register::parse(name)
+                .map(Operand::Reg)
+                .ok_or_else(|| AocError::parse(format!("invalid register: {:?}", name)))
+        }
+
+        let (mnemonic, args) = vm::split_instruction(s)?;
+
+        match mnemonic {
+            "cpy" => {
+                let [from, to] = vm::operands(mnemonic, &args)?;
+                Ok(Instruction::Cpy(from.parse()?, register(to)?))
+            }
+            "inc" => {
+                let [reg] = vm::operands(mnemonic, &args)?;
+                Ok(Instruction::Inc(register(reg)?))
+            }
+            "dec" => {
+                let [reg] = vm::operands(mnemonic, &args)?;
+                Ok(Instruction::Dec(register(reg)?))
+            }
+            "jnz" => {
+                let [cond, offset] = vm::operands(mnemonic, &args)?;
+                Ok(Instruction::Jnz(cond.parse()?, offset.parse()?))
+            }
+            "tgl" => {
+                let [offset] = vm::operands(mnemonic, &args)?;
+                Ok(Instruction::Tgl(offset.parse()?))
+            }
+            "out" => {
+                let [val] = vm::operands(mnemonic, &args)?;
+                Ok(Instruction::Out(val.parse()?))
+            }
+            _ => Err(AocError::parse(format!("unknown instruction: {:?}", s))),
+        }
+    }
+}
+
+/// The assembunny instructions. `out` pauses the machine with the value sent.
+pub struct Assembunny;
+
+impl InstructionSet for Assembunny {
+    type Instr = Instruction;
+    type State = Registers;
+    type Event = i64;
+
+    fn execute(instr: &Instruction, regs: &mut Registers) -> Result<Step<i64>, Fault> {
+        match *instr {
+            Instruction::Cpy(val, Operand::Reg(to_reg)) => regs[to_reg] = val.value(regs),
+            Instruction::Inc(Operand::Reg(reg)) => regs[reg] += 1,
+            Instruction::Dec(Operand::Reg(reg)) => regs[reg] -= 1,
+            Instruction::Jnz(val, offset) => {
+                if val.value(regs) != 0 {
+                    return Ok(Step::Jump(offset.value(regs) as isize));
+                }
+            }
+            Instruction::Tgl(offset) => return Ok(Step::Rewrite(offset.value(regs) as isize)),
+            Instruction::Out(val) => return Ok(Step::Emit(val.value(regs))),
+            // Toggled instructions writing to an immediate value are skipped.
+            Instruction::Cpy(..) | Instruction::Inc(_) | Instruction::Dec(_) => {}
+        }
+
+        Ok(Step::Next)
+    }
+
+    fn rewrite(instr: &Instruction) -> Result<Instruction, Fault> {
+        Ok(match *instr {
+            Instruction::Inc(arg) => Instruction::Dec(arg),
+            Instruction::Dec(arg) | Instruction::Tgl(arg) | Instruction::Out(arg) => Instruction::Inc(arg),
+            Instruction::Jnz(arg_1, arg_2) => Instruction::Cpy(arg_1, arg_2),
+            Instruction::Cpy(arg_1, arg_2) => Instruction::Jnz(arg_1, arg_2),
+        })
+    }
+}
+
+impl Compile for Assembunny {
+    fn compile(instr: &Instruction) -> Op<Assembunny> {
+        match *instr {
+            Instruction::Cpy(Operand::Imm(val), Operand::Reg(to_reg)) => {
+                let to_reg = vm::Register::index(to_reg);
+
+                Box::new(move |regs| {
+                    regs.slots_mut()[to_reg] = val;
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Cpy(Operand::Reg(from_reg), Operand::Reg(to_reg)) => {
+                let (from_reg, to_reg) = (vm::Register::index(from_reg), vm::Register::index(to_reg));
+
+                Box::new(move |regs| {
+                    let slots = regs.slots_mut();
+                    slots[to_reg] = slots[from_reg];
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Inc(Operand::Reg(reg)) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    regs.slots_mut()[reg] += 1;
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Dec(Operand::Reg(reg)) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |regs| {
+                    regs.slots_mut()[reg] -= 1;
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Jnz(Operand::Imm(0), _) => Box::new(|_| Ok(Step::Next)),
+            Instruction::Jnz(Operand::Imm(_), Operand::Imm(offset)) => {
+                let offset = offset as isize;
+
+                Box::new(move |_| Ok(Step::Jump(offset)))
+            }
+            Instruction::Jnz(Operand::Reg(reg), Operand::Imm(offset)) => {
+                let (reg, offset) = (vm::Register::index(reg), offset as isize);
+
+                Box::new(move |regs| {
+                    Ok(if regs.slots()[reg] != 0 {
+                        Step::Jump(offset)
+                    } else {
+                        Step::Next
+                    })
+                })
+            }
+            Instruction::Jnz(val, offset) => {
+                let (val, offset) = (Slot::from(val), Slot::from(offset));
+
+                Box::new(move |regs| {
+                    Ok(if val.value(regs.slots()) != 0 {
+                        Step::Jump(offset.value(regs.slots()) as isize)
+                    } else {
+                        Step::Next
+                    })
+                })
+            }
+            Instruction::Tgl(offset) => {
+                let offset = Slot::from(offset);
+
+                Box::new(move |regs| Ok(Step::Rewrite(offset.value(regs.slots()) as isize)))
+            }
+            Instruction::Out(val) => {
+                let val = Slot::from(val);
+
+                Box::new(move |regs| Ok(Step::Emit(val.value(regs.slots()))))
+            }
+            Instruction::Cpy(..) | Instruction::Inc(_) | Instruction::Dec(_) => Box::new(|_| Ok(Step::Next)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Program(Vec<Instruction>);
+
+impl Program {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.0
+    }
+}
+
+impl FromStr for Program {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lines(s).map(Program)
+    }
+}
+
+/// Receives the values sent by `out` instructions.
+pub trait Sink {
+    /// Takes a value, returns `false` to stop the program.
+    fn send(&mut self, value: i64) -> bool;
+}
+
+impl Sink for Vec<i64> {
+    fn send(&mut self, value: i64) -> bool {
+        self.push(value);
+        true
+    }
+}
+
+/// Ignores all values.
+pub struct Discard;
+
+impl Sink for Discard {
+    fn send(&mut self, _value: i64) -> bool {
+        true
+    }
+}
+
+/// Runs assembunny programs. Toggled instructions stay toggled until the computer is reset.
+pub struct Computer {
+    machine: Machine<Assembunny>,
+}
+
+impl Computer {
+    pub fn new(prog: &Program, regs: Registers) -> Computer {
+        Computer {
+            machine: Machine::new(prog.0.clone(), regs),
+        }
+    }
+
+    pub fn with_step_limit(self, limit: u64) -> Computer {
+        Computer {
+            machine: self.machine.with_step_limit(limit),
+        }
+    }
+
+    pub fn registers(&self) -> &Registers {
+        self.machine.state()
+    }
+
+    /// The program, including toggled instructions.
+    pub fn program(&self) -> &[Instruction] {
+        self.machine.program()
+    }
+
+    /// Rewinds the program, untoggles its instructions and clears the registers.
+    pub fn reset(&mut self) {
+        self.machine.reset();
+    }
+
+    /// Runs the program until it halts or `sink` refuses a value.
+    pub fn run_program<S: Sink>(&mut self, sink: &mut S) -> Result<(), VmError> {
+        while let Status::Paused(value) = self.machine.run()? {
+            if !sink.send(value) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the program sends 0, 1, 0, 1, ... forever when started with `reg_a` in register a.
+///
+/// The signal is known to repeat once the whole machine, toggled instructions included, is back in
+/// a state it was in when it sent an earlier value. Programs halting or running `step_limit`
+/// instructions before that are not clocks.
+fn is_clock_signal(prog: &Program, reg_a: i64, step_limit: u64) -> AocResult<bool> {
+    let mut regs = Registers::new();
+    regs[Register::A] = reg_a;
+
+    let mut comp = Machine::<Assembunny>::new(prog.0.clone(), regs).with_step_limit(step_limit);
+    let mut seen = HashMap::new();
+
+    for sent in 0.. {
+        match comp.run() {
+            Ok(Status::Paused(value)) if value == sent % 2 => {}
+            Ok(_) => return Ok(false),
+            Err(VmError {
+                fault: Fault::StepLimit(_),
+                ..
+            }) => return Ok(false),
+            Err(e) => return Err(e.into()),
+        }
+
+        let state = (comp.pc(), comp.state().clone(), comp.program().to_vec());
+
+        // An odd cycle would repeat the last value, which the next round rejects.
+        if let Some(earlier) = seen.insert(state, sent) {
+            if (sent - earlier) % 2 == 0 {
+                return Ok(true);
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// The smallest value of register a, among `candidates`, making the program send a clock signal.
+pub fn find_clock_signal(prog: &Program, candidates: Range<i64>, step_limit: u64) -> AocResult<i64> {
+    for reg_a in candidates.clone() {
+        if is_clock_signal(prog, reg_a, step_limit)? {
+            return Ok(reg_a);
+        }
+    }
+
+    Err(AocError::unsolvable(format!(
+        "no value of register a in {:?} produces a clock signal",
+        candidates
+    )))
+}
+
+fn run_with_reg_c(prog: &Program, reg_c: i64) -> AocResult<i64> {
+    let mut regs = Registers::new();
+    regs[Register::C] = reg_c;
+
+    let mut comp = CompiledMachine::<Assembunny>::new(prog.0.clone(), regs);
+    while let Status::Paused(_) = comp.run()? {}
+
+    Ok(comp.state()[Register::A])
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Program;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        run_with_reg_c(input, 0).map(|reg_a| reg_a.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        run_with_reg_c(input, 1).map(|reg_a| reg_a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Program;
+    use super::Register;
+    use super::Registers;
+    use super::Instruction;
+    use super::Assembunny;
+    use super::{find_clock_signal, Computer};
+    use aoc_common::compiled::CompiledMachine;
+    use aoc_common::vm::{Machine, Operand, Status};
+
+    const TEST_PROGRAM_STR: &str = "cpy 1 a\n\
+                                    cpy -21 b\n\
+                                    jnz c 2\n\
+                                    jnz 1 5\n\
+                                    jnz -3 23\n\
+                                    inc d\n\
+                                    dec c\n\
+                                    jnz c -2\n\
+                                    cpy a c\n";
+
+    const TEST_PROGRAM_STR_2: &str = "cpy 41 a\n\
+                                      inc a\n\
+                                      inc a\n\
+                                      dec a\n\
+                                      jnz a 2\n\
+                                      dec a\n";
+
+
+    #[test]
+    fn parse_test() {
+        let expected_prog = Program(vec![
+            Instruction::Cpy(Operand::Imm(1), Operand::Reg(Register::A)),
+            Instruction::Cpy(Operand::Imm(-21), Operand::Reg(Register::B)),
+            Instruction::Jnz(Operand::Reg(Register::C), Operand::Imm(2)),
+            Instruction::Jnz(Operand::Imm(1), Operand::Imm(5)),
+            Instruction::Jnz(Operand::Imm(-3), Operand::Imm(23)),
+            Instruction::Inc(Operand::Reg(Register::D)),
+            Instruction::Dec(Operand::Reg(Register::C)),
+            Instruction::Jnz(Operand::Reg(Register::C), Operand::Imm(-2)),
+            Instruction::Cpy(Operand::Reg(Register::A), Operand::Reg(Register::C)),
+        ]);
+
+        assert_eq!(
+            expected_prog,
+            TEST_PROGRAM_STR.parse().expect(
+                "could not parse test input",
+            )
+        );
+
+        assert!("cpy 1 2".parse::<Program>().is_err());
+    }
+
+    #[test]
+    fn prog_test() {
+        let prog: Program = TEST_PROGRAM_STR_2.parse().unwrap();
+
+        let mut comp = Machine::<Assembunny>::new(prog.0, Registers::new());
+
+        assert_eq!(Ok(Status::Halted), comp.run());
+
+        assert_eq!(42, comp.state()[Register::A]);
+    }
+
+    #[test]
+    fn toggle_test() {
+        let prog: Program = "cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a".parse().unwrap();
+
+        let mut comp = Computer::new(&prog, Registers::new());
+        comp.run_program(&mut Vec::new()).unwrap();
+
+        assert_eq!(3, comp.registers()[Register::A]);
+        assert_eq!(Instruction::Jnz(Operand::Imm(1), Operand::Reg(Register::A)), comp.program()[4]);
+
+        comp.reset();
+        assert_eq!(prog.0.as_slice(), comp.program());
+    }
+
+    #[test]
+    fn output_test() {
+        let prog: Program = "out 1\nout 0\ncpy 2 a\nout a\ninc a\nout a".parse().unwrap();
+
+        let mut output = Vec::new();
+        Computer::new(&prog, Registers::new()).run_program(&mut output).unwrap();
+
+        assert_eq!(vec![1, 0, 2, 3], output);
+
+        struct Take(usize);
+
+        impl super::Sink for Take {
+            fn send(&mut self, _value: i64) -> bool {
+                self.0 -= 1;
+                self.0 > 0
+            }
+        }
+
+        let mut comp = Computer::new(&prog, Registers::new());
+        comp.run_program(&mut Take(2)).unwrap();
+
+        assert_eq!(0, comp.registers()[Register::A]);
+    }
+
+    #[test]
+    fn clock_signal_test() {
+        let prog: Program = "dec a\ndec a\ndec a\nout a\ninc a\nout a\ndec a\njnz 1 -4".parse().unwrap();
+
+        assert_eq!(Ok(3), find_clock_signal(&prog, 0..10, 1000));
+        assert!(find_clock_signal(&prog, 4..10, 1000).is_err());
+
+        // sends 0, 1, 1, 0, 1, 1, ... which only alternates for a while
+        let prog: Program = "out 0\nout 1\nout 1\njnz 1 -3".parse().unwrap();
+        assert!(find_clock_signal(&prog, 0..1, 1000).is_err());
+    }
+
+    #[test]
+    fn compiled_test() {
+        for source in &[TEST_PROGRAM_STR, TEST_PROGRAM_STR_2, "cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a"] {
+            let prog: Program = source.parse().unwrap();
+
+            let mut comp = Machine::<Assembunny>::new(prog.0.clone(), Registers::new());
+            let mut compiled = CompiledMachine::<Assembunny>::new(prog.0, Registers::new());
+
+            assert_eq!(comp.run(), compiled.run());
+            assert_eq!(comp.state(), compiled.state());
+            assert_eq!(comp.program(), compiled.program());
+        }
+    }
+}
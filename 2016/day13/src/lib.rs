@@ -0,0 +1,212 @@
+extern crate aoc_common;
+
+use std::collections::HashMap;
+
+use aoc_common::search::{self, SearchState};
+use aoc_common::{AocError, AocResult, Solver};
+
+// -----> x
+// |
+// |
+// v
+// y
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Maze {
+    fav_num: usize
+}
+
+impl Maze {
+    fn new(fav_num: usize) -> Maze {
+        Maze { fav_num }
+    }
+
+    fn is_wall(&self, pos: (usize, usize)) -> bool {
+        let val = pos.0 * pos.0 + 3 * pos.0 + 2 * pos.0 * pos.1 + pos.1 + pos.1 * pos.1 + self.fav_num;
+
+        !val.count_ones().is_multiple_of(2)
+    }
+
+    fn movement_options(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+
+        let mut opts = Vec::new();
+
+        // up
+        if pos.1 > 0 && !self.is_wall((pos.0, pos.1 - 1)) {
+            opts.push((pos.0, pos.1 - 1));
+        }
+
+        // down
+        if !self.is_wall((pos.0, pos.1 + 1)) {
+            opts.push((pos.0, pos.1 + 1));
+        }
+
+        // left
+        if pos.0 > 0 && !self.is_wall((pos.0 - 1, pos.1)) {
+            opts.push((pos.0 - 1, pos.1));
+        }
+
+        // right
+        if !self.is_wall((pos.0 + 1, pos.1)) {
+            opts.push((pos.0 + 1, pos.1));
+        }
+
+        opts
+    }
+}
+
+#[cfg(test)]
+fn format_maze_with_path(maze: &Maze, size: (usize, usize), path: &[(usize, usize)]) -> String {
+    let mut out = String::with_capacity((size.0 + 1) * size.1);
+
+    for y in 0..size.1 {
+        for x in 0..size.0 {
+
+            let ch = if path.contains(&(x, y)) { 'O' } else if maze.is_wall((x, y)) { '#' } else { '.' };
+
+            out.push(ch);
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+
+/// A position in the maze, on the way to a target.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+struct Place {
+    maze: Maze,
+    pos: (usize, usize),
+    target: (usize, usize),
+}
+
+impl SearchState for Place {
+    type Cost = usize;
+
+    fn neighbors(&self) -> Vec<(Place, usize)> {
+        self.maze
+            .movement_options(self.pos)
+            .into_iter()
+            .map(|pos| (Place { pos, ..*self }, 1))
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.pos == self.target
+    }
+
+    fn heuristic(&self) -> usize {
+        self.pos.0.abs_diff(self.target.0) + self.pos.1.abs_diff(self.target.1)
+    }
+}
+
+
+fn find_path(maze: &Maze, start_pos: (usize, usize), end_pos: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+    let start = Place {
+        maze: *maze,
+        pos: start_pos,
+        target: end_pos,
+    };
+
+    search::a_star(start).map(|result| result.path.iter().map(|place| place.pos).collect())
+}
+
+
+fn find_places(maze: &Maze, start_pos: (usize, usize), max_depth: usize) -> HashMap<(usize, usize), usize> {
+    let start = Place {
+        maze: *maze,
+        pos: start_pos,
+        target: start_pos,
+    };
+
+    search::reachable(start, max_depth)
+        .into_iter()
+        .map(|(place, depth)| (place.pos, depth))
+        .collect()
+}
+
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Maze;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        let fav_num = input.trim().parse::<usize>().map_err(AocError::from)?;
+
+        Ok(Maze::new(fav_num))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let path = find_path(input, (1, 1), (31, 39)).ok_or_else(|| AocError::unsolvable("The target cannot be reached."))?;
+
+        Ok((path.len() - 1).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(find_places(input, (1, 1), 50).len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Maze;
+    use super::format_maze_with_path;
+    use super::find_path;
+    use super::find_places;
+
+    const TEST_MAZE: &str = ".#.####.##\n\
+                             ..#..#...#\n\
+                             #....##...\n\
+                             ###.#.###.\n\
+                             .##..#..#.\n\
+                             ..##....#.\n\
+                             #...##.###\n";
+
+    const TEST_MAZE_WITH_PATH: &str = ".#.####.##\n\
+                                       .O#..#...#\n\
+                                       #OOO.##...\n\
+                                       ###O#.###.\n\
+                                       .##OO#OO#.\n\
+                                       ..##OOO.#.\n\
+                                       #...##.###\n";
+
+    #[test]
+    fn wall_calc_test() {
+        let maze = Maze::new(10);
+
+        assert_eq!(TEST_MAZE, format_maze_with_path(&maze, (10, 7), &[]));
+    }
+
+    #[test]
+    fn path_format_test() {
+
+        let maze = Maze::new(10);
+        let path = vec![(1, 1), (1, 2), (2, 2), (3, 2), (3, 3), (3, 4), (4, 4), (4, 5), (5, 5), (6, 5), (6, 4), (7, 4)];
+
+        assert_eq!(TEST_MAZE_WITH_PATH, format_maze_with_path(&maze, (10, 7), &path));
+    }
+
+    #[test]
+    fn find_solution_test() {
+        let maze = Maze::new(10);
+
+        let path = find_path(&maze, (1, 1), (7, 4)).expect("no path");
+
+        assert_eq!(11, path.len() - 1);
+    }
+
+    #[test]
+    fn find_places_test() {
+        let maze = Maze::new(10);
+
+        let places = find_places(&maze, (1, 1), 1);
+
+        assert_eq!(3, places.len());
+        assert_eq!(Some(&1), places.get(&(0, 1)));
+        assert_eq!(Some(&0), places.get(&(1, 1)));
+
+        assert!(find_places(&maze, (1, 1), 4).contains_key(&(3, 3)));
+    }
+}
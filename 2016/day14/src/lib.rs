@@ -1,14 +1,12 @@
+extern crate aoc_common;
 extern crate crypto;
-extern crate pcre;
 
 use crypto::digest::Digest;
 use crypto::md5::Md5;
 
-use pcre::Pcre;
-
 use std::collections::VecDeque;
 
-use std::env;
+use aoc_common::{AocResult, Solver};
 
 fn hash_stretch(base: &str, num_iterations: usize) -> String {
     let mut md5 = Md5::new();
@@ -23,10 +21,23 @@ fn hash_stretch(base: &str, num_iterations: usize) -> String {
     out
 }
 
-fn generate_keys(salt: &str, num_keys: usize, secure_hashing: bool) -> Vec<(usize, String)> {
-    let mut hash_check_re = Pcre::compile(r"([0-9a-z])\1\1").expect("bad regex");
+/// Returns the character of the first run of three identical characters, if any.
+fn find_triple(hash: &str) -> Option<u8> {
+    hash.as_bytes()
+        .windows(3)
+        .find(|w| w[0] == w[1] && w[1] == w[2])
+        .map(|w| w[0])
+}
+
+/// Checks whether the hash contains a run of five of the given character.
+fn has_quintuple(hash: &str, ch: u8) -> bool {
+    hash.as_bytes()
+        .windows(5)
+        .any(|w| w.iter().all(|&c| c == ch))
+}
 
-    let mut candidate_list: VecDeque<(usize, String, Pcre)> = VecDeque::new();
+fn generate_keys(salt: &str, num_keys: usize, secure_hashing: bool) -> Vec<(usize, String)> {
+    let mut candidate_list: VecDeque<(usize, String, u8)> = VecDeque::new();
     let mut key_list = Vec::new();
 
     let mut md5 = Md5::new();
@@ -45,13 +56,7 @@ fn generate_keys(salt: &str, num_keys: usize, secure_hashing: bool) -> Vec<(usiz
         };
 
         // Purge candidates older than 1000 elements
-        loop {
-            let elem_idx = if let Some((elem_idx, _, _)) = candidate_list.front() {
-                *elem_idx
-            } else {
-                break;
-            };
-
+        while let Some(&(elem_idx, _, _)) = candidate_list.front() {
             if elem_idx + 1000 <= curr_idx {
                 candidate_list.pop_front();
             } else {
@@ -63,24 +68,19 @@ fn generate_keys(salt: &str, num_keys: usize, secure_hashing: bool) -> Vec<(usiz
         // Remove a candidate if it is a key
         let mut new_list = VecDeque::new();
 
-        for (candidate_idx, candidate_hash_str, mut candidate_hash_regex) in candidate_list {
-            if candidate_hash_regex.exec(&curr_hash).is_some() {
+        for (candidate_idx, candidate_hash_str, candidate_char) in candidate_list {
+            if has_quintuple(&curr_hash, candidate_char) {
                 key_list.push((candidate_idx, candidate_hash_str));
             } else {
-                new_list.push_back((candidate_idx, candidate_hash_str, candidate_hash_regex));
+                new_list.push_back((candidate_idx, candidate_hash_str, candidate_char));
             }
         }
 
         candidate_list = new_list;
 
         // See if the current hash is a candidate
-        if let Some(caps) = hash_check_re.exec(&curr_hash) {
-            let matched_char = caps.group(1).chars().next().expect("missing first char");
-
-            let check_re = Pcre::compile(&format!(r"({})\1\1\1\1", matched_char))
-                .expect("could not create regex");
-
-            candidate_list.push_back((curr_idx, curr_hash.clone(), check_re));
+        if let Some(matched_char) = find_triple(&curr_hash) {
+            candidate_list.push_back((curr_idx, curr_hash.clone(), matched_char));
         }
 
         curr_idx += 1;
@@ -91,25 +91,22 @@ fn generate_keys(salt: &str, num_keys: usize, secure_hashing: bool) -> Vec<(usiz
     key_list
 }
 
-fn main() {
-    let mut a = env::args();
-
-    a.next(); // The first argument is the binary name/path
+pub struct Solution;
 
-    let salt = a.next().expect("missing first CLI parameter (salt string)");
+impl Solver for Solution {
+    type Input = String;
 
-    let keys = generate_keys(&salt, 64, false);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
 
-    println!(
-        "The index that generates the 64th key is: {}",
-        keys.last().unwrap().0
-    );
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(generate_keys(input, 64, false).last().unwrap().0.to_string())
+    }
 
-    let secure_keys = generate_keys(&salt, 64, true);
-    println!(
-        "Hash stretching enabled. The index that generates the 64th key is: {}",
-        secure_keys.last().unwrap().0
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(generate_keys(input, 64, true).last().unwrap().0.to_string())
+    }
 }
 
 #[cfg(test)]
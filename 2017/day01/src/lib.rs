@@ -1,11 +1,11 @@
+extern crate aoc_common;
 extern crate itertools;
 
 use itertools::Itertools;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
-fn get_captcha(input: &Vec<u8>) -> usize {
+fn get_captcha(input: &[u8]) -> usize {
     if input.len() >= 2 {
         let mut sum = input
             .iter()
@@ -25,7 +25,7 @@ fn get_captcha(input: &Vec<u8>) -> usize {
     }
 }
 
-fn get_captcha_2(input: &Vec<u8>) -> usize {
+fn get_captcha_2(input: &[u8]) -> usize {
     let mut sum = 0;
     for (i, digit) in input.iter().enumerate() {
         let check_pos = (i + input.len() / 2) % input.len();
@@ -38,21 +38,26 @@ fn get_captcha_2(input: &Vec<u8>) -> usize {
     sum
 }
 
-fn main() {
+pub struct Solution;
 
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).expect(
-        "cannot read input",
-    );
+impl Solver for Solution {
+    type Input = Vec<u8>;
 
-    let input = input_str
-        .chars()
-        .filter_map(|ch| ch.to_digit(10))
-        .map(|d| d as u8)
-        .collect();
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input
+            .chars()
+            .filter_map(|ch| ch.to_digit(10))
+            .map(|d| d as u8)
+            .collect())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(get_captcha(input).to_string())
+    }
 
-    println!("Captcha 1: {}", get_captcha(&input));
-    println!("Captcha 2: {}", get_captcha_2(&input));
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(get_captcha_2(input).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,6 @@
+extern crate aoc_common;
 
-use std::io;
-use std::io::prelude::*;
-
+use aoc_common::{AocResult, Solver};
 
 fn sum_of_differences(input_str: &str) -> usize {
     input_str
@@ -48,16 +47,23 @@ fn sum_of_divisible(input_str: &str) -> usize {
         .sum()
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).expect(
-        "cannot read input",
-    );
+pub struct Solution;
 
-    println!("Sum of differences: {}", sum_of_differences(&input_str));
-    println!("Sum of divisible: {}", sum_of_divisible(&input_str));
-}
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(sum_of_differences(input).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(sum_of_divisible(input).to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -70,7 +76,7 @@ mod tests {
                           7 5 3\n\
                           2 4 6 8";
 
-        assert_eq!(18, sum_of_differences(&test_input));
+        assert_eq!(18, sum_of_differences(test_input));
     }
 
     #[test]
@@ -79,6 +85,6 @@ mod tests {
                           9 4 7 3\n\
                           3 8 6 5";
 
-        assert_eq!(9, sum_of_divisible(&test_input));
+        assert_eq!(9, sum_of_divisible(test_input));
     }
 }
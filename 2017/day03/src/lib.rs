@@ -1,5 +1,9 @@
+extern crate aoc_common;
+
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 fn get_distance(pos_val: usize) -> usize {
 
     let mut curr_pos: (isize, isize) = (0, 0);
@@ -117,17 +121,23 @@ fn get_first_larger(pos_val: usize) -> usize {
     unreachable!();
 }
 
-fn main() {
-    let input = 277678;
+pub struct Solution;
 
-    println!("The distance to {} is {}", input, get_distance(input));
-    println!(
-        "The first number larger than {} is {}",
-        input,
-        get_first_larger(input)
-    );
-}
+impl Solver for Solution {
+    type Input = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.trim().parse().map_err(AocError::from)
+    }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(get_distance(*input).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(get_first_larger(*input).to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1,10 +1,11 @@
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
 
 use std::collections::HashSet;
 use std::collections::BTreeSet;
 use std::collections::BTreeMap;
 
+use aoc_common::{AocResult, Solver};
+
 fn get_valid_passphrases(input_str: &str) -> Vec<String> {
     input_str
         .lines()
@@ -36,20 +37,22 @@ fn get_valid_passphrases_b(input_str: &str) -> Vec<String> {
         .collect()
 }
 
-fn main() {
+pub struct Solution;
 
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+impl Solver for Solution {
+    type Input = String;
 
-    let passphrases = get_valid_passphrases(&input_str);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
-    println!("Number of valid passphrases: {}", passphrases.len());
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(get_valid_passphrases(input).len().to_string())
+    }
 
-    let passphrases_b = get_valid_passphrases_b(&input_str);
-    println!(
-        "Number of valid anagram passphrases: {}",
-        passphrases_b.len()
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(get_valid_passphrases_b(input).len().to_string())
+    }
 }
 
 #[cfg(test)]
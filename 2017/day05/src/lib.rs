@@ -1,8 +1,9 @@
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
 
 use std::str::FromStr;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 impl FromStr for Computer {
     type Err = ();
 
@@ -17,7 +18,7 @@ impl FromStr for Computer {
 }
 
 #[derive(Debug, Clone)]
-struct Computer {
+pub struct Computer {
     instructions: Vec<isize>,
     pc: isize,
 }
@@ -48,21 +49,22 @@ impl Computer {
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
-
-    let mut computer: Computer = input_str.parse().unwrap();
-    let mut computer_b: Computer = computer.clone();
-
-    println!(
-        "The total step count for program A is {}.",
-        computer.run_program()
-    );
-    println!(
-        "The total step count for program B is {}.",
-        computer_b.run_program_b()
-    );
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Computer;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing jump offsets"))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.clone().run_program().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(input.clone().run_program_b().to_string())
+    }
 }
 
 #[cfg(test)]
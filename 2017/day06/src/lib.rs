@@ -1,5 +1,8 @@
+extern crate aoc_common;
+
 use std::collections::HashMap;
 
+use aoc_common::{AocError, AocResult, Solver};
 
 fn reallocate(mut banks: Vec<usize>) -> (usize, usize) {
     let mut states_seen: HashMap<Vec<usize>, usize> = HashMap::new();
@@ -32,8 +35,25 @@ fn reallocate(mut banks: Vec<usize>) -> (usize, usize) {
     unreachable!();
 }
 
-fn main() {
-    println!("Loop length, num reallocs: {:?}", reallocate(vec![0, 5, 10, 0, 11, 14, 13, 4, 11, 8, 8, 7, 1, 4, 12, 11]));
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input
+            .split_whitespace()
+            .map(|bank| bank.parse().map_err(AocError::from))
+            .collect()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(reallocate(input.clone()).1.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(reallocate(input.clone()).0.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -1,15 +1,13 @@
-#![feature(entry_and_modify)]
-
+extern crate aoc_common;
 extern crate regex;
 
 use regex::Regex;
 use std::collections::HashSet;
 use std::collections::HashMap;
 
-use std::io;
-use std::io::prelude::*;
 use std::str::FromStr;
 
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug)]
 enum WeightSearch<'a> {
@@ -24,7 +22,7 @@ enum Weight<'a> {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Program {
+pub struct Program {
     name: String,
     weight: usize,
     child_names: Vec<String>,
@@ -39,7 +37,7 @@ impl FromStr for Program {
         ).unwrap();
         let re_children = Regex::new(r"([a-z]+)").unwrap();
 
-        let caps = re.captures(&s).ok_or(())?;
+        let caps = re.captures(s).ok_or(())?;
 
         let name = caps.name("prg_name").ok_or(())?.as_str().to_string();
         let weight = caps.name("weight").ok_or(())?.as_str().parse().map_err(
@@ -66,7 +64,7 @@ impl FromStr for Program {
     }
 }
 
-fn get_root_name(programs: &Vec<Program>) -> Result<String, ()> {
+fn get_root_name(programs: &[Program]) -> Result<String, ()> {
     let (all_names, child_names) = programs.iter().fold(
         (HashSet::new(), HashSet::new()),
         |(mut all, mut children), curr_prog| {
@@ -92,7 +90,7 @@ fn get_root_name(programs: &Vec<Program>) -> Result<String, ()> {
 }
 
 fn find_imbalanced_node<'a>(
-    programs: &'a Vec<Program>,
+    programs: &'a [Program],
     root: &str,
 ) -> Result<(usize, usize, &'a Program), ()> {
 
@@ -190,33 +188,33 @@ fn get_weight_r<'a, 'b: 'a>(
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Program>;
 
-    let programs = input_str
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<Program>, ()>>()
-        .expect("parsing error");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.parse())
+            .collect::<Result<Vec<Program>, ()>>()
+            .map_err(|_| AocError::parse("error parsing programs"))
+    }
 
-    let root_name = get_root_name(&programs).expect("no root");
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        get_root_name(input).map_err(|_| AocError::runtime("no root program found"))
+    }
 
-    println!("The root node is '{}'.", root_name);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let root_name = get_root_name(input).map_err(|_| AocError::runtime("no root program found"))?;
 
-    let (desired_weight, subtree_weight, offending_prog) =
-        find_imbalanced_node(&programs, &root_name).expect("no imbalanced node");
+        let (desired_weight, subtree_weight, offending_prog) =
+            find_imbalanced_node(input, &root_name).map_err(|_| AocError::runtime("no imbalanced node"))?;
 
-    println!(
-        "Imbalanced node name '{}'. Weighs {}, and should weigh {} to bring the subtree balance to {}.",
-        offending_prog.name,
-        offending_prog.weight,
-        offending_prog.weight + desired_weight - subtree_weight,
-        desired_weight
-    );
+        Ok((offending_prog.weight + desired_weight - subtree_weight).to_string())
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,411 @@
+extern crate aoc_common;
+
+pub mod parser;
+pub mod repl;
+
+use std::fmt;
+
+use std::str::FromStr;
+
+use std::collections::HashMap;
+
+use std::cmp;
+
+use aoc_common::{parse_lines, AocError, AocResult, Solver};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Comparison {
+    Greater,
+    Less,
+    GreaterOrEqual,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    fn apply(&self, left: isize, right: isize) -> bool {
+        match *self {
+            Comparison::Greater => left > right,
+            Comparison::Less => left < right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match *self {
+            Comparison::Greater => ">",
+            Comparison::Less => "<",
+            Comparison::GreaterOrEqual => ">=",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinOp {
+    fn apply(&self, left: isize, right: isize) -> AocResult<isize> {
+        let result = match *self {
+            BinOp::Add => left.checked_add(right),
+            BinOp::Sub => left.checked_sub(right),
+            BinOp::Mul => left.checked_mul(right),
+            BinOp::Div | BinOp::Rem if right == 0 => return Err(AocError::runtime("division by zero")),
+            BinOp::Div => left.checked_div(right),
+            BinOp::Rem => left.checked_rem(right),
+        };
+
+        result.ok_or_else(|| AocError::runtime(format!("overflow in {} {} {}", left, self.symbol(), right)))
+    }
+
+    fn symbol(&self) -> &'static str {
+        match *self {
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+            BinOp::Rem => "%",
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match *self {
+            BinOp::Add | BinOp::Sub => 1,
+            BinOp::Mul | BinOp::Div | BinOp::Rem => 2,
+        }
+    }
+}
+
+/// An arithmetic expression over registers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Expr {
+    Num(isize),
+    Reg(String),
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn precedence(&self) -> u8 {
+        match *self {
+            Expr::Binary(op, ..) => op.precedence(),
+            _ => 3,
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn operand(f: &mut fmt::Formatter, expr: &Expr, parenthesize: bool) -> fmt::Result {
+            if parenthesize {
+                write!(f, "({})", expr)
+            } else {
+                write!(f, "{}", expr)
+            }
+        }
+
+        match *self {
+            Expr::Num(num) => write!(f, "{}", num),
+            Expr::Reg(ref name) => write!(f, "{}", name),
+            Expr::Neg(ref expr) => {
+                write!(f, "-")?;
+                operand(f, expr, expr.precedence() < 3)
+            }
+            Expr::Binary(op, ref left, ref right) => {
+                // all the operators are left associative
+                operand(f, left, left.precedence() < op.precedence())?;
+                write!(f, " {} ", op.symbol())?;
+                operand(f, right, right.precedence() <= op.precedence())
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Condition {
+    Compare(Comparison, Expr, Expr),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Condition::Compare(comparison, ref left, ref right) => {
+                write!(f, "{} {} {}", left, comparison.symbol(), right)
+            }
+            Condition::And(ref left, ref right) => {
+                for (i, condition) in [left, right].iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " && ")?;
+                    }
+
+                    match ***condition {
+                        Condition::Or(..) => write!(f, "({})", condition)?,
+                        _ => write!(f, "{}", condition)?,
+                    }
+                }
+
+                Ok(())
+            }
+            Condition::Or(ref left, ref right) => write!(f, "{} || {}", left, right),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    Inc,
+    Dec,
+}
+
+impl Operation {
+    fn exec(&self, left: isize, right: isize) -> AocResult<isize> {
+        match *self {
+            Operation::Inc => BinOp::Add.apply(left, right),
+            Operation::Dec => BinOp::Sub.apply(left, right),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Instruction {
+    pub register: String,
+    pub operation: Operation,
+    pub operand: Expr,
+    pub condition: Condition,
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse_instruction(s)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operation = match self.operation {
+            Operation::Inc => "inc",
+            Operation::Dec => "dec",
+        };
+
+        write!(f, "{} {} {} if {}", self.register, operation, self.operand, self.condition)
+    }
+}
+
+#[derive(Debug)]
+pub struct Program(Vec<Instruction>);
+
+impl Program {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.0
+    }
+}
+
+impl FromStr for Program {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lines(s).map(Program)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pc: usize,
+    registers: HashMap<String, isize>,
+    overall_max: Option<isize>,
+}
+
+impl Computer {
+    pub fn new() -> Computer {
+        Computer {
+            pc: 0,
+            registers: HashMap::new(),
+            overall_max: None,
+        }
+    }
+
+    pub fn get_register_mut(&mut self, name: &str) -> &mut isize {
+        self.registers.entry(name.to_string()).or_insert(0)
+    }
+
+    /// Every register an executed instruction has mentioned so far.
+    pub fn registers(&self) -> &HashMap<String, isize> {
+        &self.registers
+    }
+
+    /// The highest value held by any register after any instruction.
+    pub fn overall_max(&self) -> Option<isize> {
+        self.overall_max
+    }
+
+    fn eval(&mut self, expr: &Expr) -> AocResult<isize> {
+        match *expr {
+            Expr::Num(num) => Ok(num),
+            Expr::Reg(ref name) => Ok(*self.get_register_mut(name)),
+            Expr::Neg(ref expr) => {
+                let val = self.eval(expr)?;
+                val.checked_neg().ok_or_else(|| AocError::runtime(format!("overflow in -{}", val)))
+            }
+            Expr::Binary(op, ref left, ref right) => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+
+                op.apply(left, right)
+            }
+        }
+    }
+
+    /// `&&` and `||` short-circuit, so registers only mentioned on a side that is not evaluated
+    /// are not created.
+    fn test(&mut self, condition: &Condition) -> AocResult<bool> {
+        match *condition {
+            Condition::Compare(comparison, ref left, ref right) => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+
+                Ok(comparison.apply(left, right))
+            }
+            Condition::And(ref left, ref right) => Ok(self.test(left)? && self.test(right)?),
+            Condition::Or(ref left, ref right) => Ok(self.test(left)? || self.test(right)?),
+        }
+    }
+
+    /// Executes a single instruction, returning whether its condition held.
+    pub fn execute(&mut self, instr: &Instruction) -> AocResult<bool> {
+        let cond_eval = self.test(&instr.condition)?;
+
+        if cond_eval {
+            let operand = self.eval(&instr.operand)?;
+            let register = self.get_register_mut(&instr.register);
+
+            *register = instr.operation.exec(*register, operand)?;
+        }
+
+        if let Some(o_max) = self.overall_max {
+            if let Some(curr_max) = self.registers.values().max() {
+                self.overall_max = Some(cmp::max(o_max, *curr_max));
+            }
+        } else {
+            self.overall_max = self.registers.values().max().copied();
+        }
+
+        Ok(cond_eval)
+    }
+
+    pub fn run_program(&mut self, prog: &Program) -> AocResult<()> {
+
+        while let Some(instr) = prog.0.get(self.pc) {
+            self.execute(instr)?;
+
+            self.pc += 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Computer {
+    fn default() -> Computer {
+        Computer::new()
+    }
+}
+
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Program;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut computer = Computer::new();
+        computer.run_program(input)?;
+
+        computer
+            .registers
+            .values()
+            .max()
+            .map(|max| max.to_string())
+            .ok_or_else(|| AocError::unsolvable("no registers"))
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut computer = Computer::new();
+        computer.run_program(input)?;
+
+        computer
+            .overall_max
+            .map(|max| max.to_string())
+            .ok_or_else(|| AocError::unsolvable("no registers"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Computer, Program};
+
+    const TEST_INPUT: &str = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+
+    #[test]
+    fn end_register_max_value_test() {
+        let prog = TEST_INPUT.parse().unwrap();
+        let mut comp = Computer::new();
+
+        comp.run_program(&prog).unwrap();
+        assert_eq!(1, *comp.registers.values().max().expect("no max"));
+    }
+
+    #[test]
+    fn all_time_register_max_value_test() {
+        let prog = TEST_INPUT.parse().unwrap();
+        let mut comp = Computer::new();
+
+        comp.run_program(&prog).unwrap();
+        assert_eq!(10, comp.overall_max.expect("no max"));
+    }
+
+    #[test]
+    fn expression_program_test() {
+        let prog = "a inc 7 if a == 0
+b dec a * 2 - 1 if a > 5 && (b == 0 || c != 0)
+c inc a % 4 + -b / 3 if b < 0 || d > 100
+d inc 1 if (a + b) * 2 == -12"
+            .parse::<Program>()
+            .unwrap();
+        let mut comp = Computer::new();
+
+        comp.run_program(&prog).unwrap();
+        assert_eq!(7, comp.registers["a"]);
+        assert_eq!(-13, comp.registers["b"]);
+        assert_eq!(7, comp.registers["c"]);
+        assert_eq!(1, comp.registers["d"]);
+        assert_eq!(Some(7), comp.overall_max());
+
+        let prog = "a inc 1 if b / a > 0".parse::<Program>().unwrap();
+        assert!(Computer::new().run_program(&prog).is_err());
+
+        assert!("a inc 1 if b > 0\nb inc ( if a > 0".parse::<Program>().is_err());
+    }
+}
@@ -0,0 +1,416 @@
+//! Recursive descent parser for the register instructions.
+//!
+//! ```text
+//! instruction := register ("inc" | "dec") expr "if" condition
+//! condition   := conjunction ("||" conjunction)*
+//! conjunction := atom ("&&" atom)*
+//! atom        := "(" condition ")" | expr comparison expr
+//! expr        := term (("+" | "-") term)*
+//! term        := factor (("*" | "/" | "%") factor)*
+//! factor      := number | register | "-" factor | "(" expr ")"
+//! ```
+//!
+//! Register names are never reserved, `inc`, `dec` and `if` are only keywords where the grammar
+//! expects them. Errors carry the 1-based column of the offending token.
+
+use aoc_common::{AocError, AocResult};
+
+use {BinOp, Comparison, Condition, Expr, Instruction, Operation};
+
+const SYMBOLS: [&str; 15] = ["&&", "||", ">=", "<=", "==", "!=", ">", "<", "+", "-", "*", "/", "%", "(", ")"];
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Token {
+    Ident(String),
+    Num(isize),
+    Sym(&'static str),
+}
+
+fn error_at(column: usize, message: String) -> AocError {
+    AocError::Parse {
+        line: None,
+        column: Some(column),
+        message,
+    }
+}
+
+fn column_of(error: &AocError) -> usize {
+    match *error {
+        AocError::Parse {
+            column: Some(column), ..
+        } => column,
+        _ => 0,
+    }
+}
+
+/// Splits the line into tokens paired with their columns.
+fn tokenize(s: &str) -> AocResult<Vec<(Token, usize)>> {
+    let chars = s.char_indices().collect::<Vec<(usize, char)>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (offset, c) = chars[i];
+        let column = i + 1;
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() {
+            let len = chars[i..].iter().take_while(|&&(_, c)| c.is_ascii_digit()).count();
+            let end = chars.get(i + len).map_or(s.len(), |&(offset, _)| offset);
+
+            let num = s[offset..end]
+                .parse()
+                .map_err(|_| error_at(column, format!("number out of range: {}", &s[offset..end])))?;
+
+            tokens.push((Token::Num(num), column));
+            i += len;
+        } else if c.is_alphabetic() || c == '_' {
+            let len = chars[i..]
+                .iter()
+                .take_while(|&&(_, c)| c.is_alphanumeric() || c == '_')
+                .count();
+            let end = chars.get(i + len).map_or(s.len(), |&(offset, _)| offset);
+
+            tokens.push((Token::Ident(s[offset..end].to_string()), column));
+            i += len;
+        } else {
+            let sym = SYMBOLS
+                .iter()
+                .find(|sym| s[offset..].starts_with(*sym))
+                .ok_or_else(|| error_at(column, format!("unexpected character {:?}", c)))?;
+
+            tokens.push((Token::Sym(sym), column));
+            i += sym.len();
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end_column: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn is_sym(&self, sym: &str) -> bool {
+        matches!(self.peek(), Some(Token::Sym(s)) if *s == sym)
+    }
+
+    fn error(&self, expected: &str) -> AocError {
+        match self.tokens.get(self.pos) {
+            Some((token, column)) => {
+                let found = match *token {
+                    Token::Ident(ref name) => name.clone(),
+                    Token::Num(num) => num.to_string(),
+                    Token::Sym(sym) => sym.to_string(),
+                };
+
+                error_at(*column, format!("expected {}, found `{}`", expected, found))
+            }
+            None => error_at(self.end_column, format!("expected {}, found the end of the line", expected)),
+        }
+    }
+
+    fn expect_sym(&mut self, sym: &str) -> AocResult<()> {
+        if self.is_sym(sym) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}`", sym)))
+        }
+    }
+
+    fn ident(&mut self, expected: &str) -> AocResult<String> {
+        match self.peek() {
+            Some(Token::Ident(name)) => {
+                let name = name.clone();
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> AocResult<()> {
+        match self.peek() {
+            Some(Token::Ident(name)) if name == keyword => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => Err(self.error(&format!("`{}`", keyword))),
+        }
+    }
+
+    fn instruction(&mut self) -> AocResult<Instruction> {
+        let register = self.ident("a register")?;
+
+        let operation = match self.peek() {
+            Some(Token::Ident(name)) if name == "inc" => Operation::Inc,
+            Some(Token::Ident(name)) if name == "dec" => Operation::Dec,
+            _ => return Err(self.error("`inc` or `dec`")),
+        };
+        self.pos += 1;
+
+        let operand = self.expr()?;
+        self.keyword("if")?;
+        let condition = self.condition()?;
+
+        if self.peek().is_some() {
+            return Err(self.error("an operator or the end of the line"));
+        }
+
+        Ok(Instruction {
+            register,
+            operation,
+            operand,
+            condition,
+        })
+    }
+
+    fn condition(&mut self) -> AocResult<Condition> {
+        let mut condition = self.conjunction()?;
+
+        while self.is_sym("||") {
+            self.pos += 1;
+            condition = Condition::Or(Box::new(condition), Box::new(self.conjunction()?));
+        }
+
+        Ok(condition)
+    }
+
+    fn conjunction(&mut self) -> AocResult<Condition> {
+        let mut condition = self.atom()?;
+
+        while self.is_sym("&&") {
+            self.pos += 1;
+            condition = Condition::And(Box::new(condition), Box::new(self.atom()?));
+        }
+
+        Ok(condition)
+    }
+
+    fn atom(&mut self) -> AocResult<Condition> {
+        // `(` opens either a nested condition or an expression on the left of a comparison, try
+        // the condition first and fall back to the comparison.
+        if self.is_sym("(") {
+            let start = self.pos;
+            self.pos += 1;
+
+            let nested = self.condition().and_then(|condition| {
+                self.expect_sym(")")?;
+                Ok(condition)
+            });
+
+            match nested {
+                Ok(condition) => {
+                    if !self.continues_expr() {
+                        return Ok(condition);
+                    }
+
+                    self.pos = start;
+                    return self.comparison();
+                }
+                Err(nested_error) => {
+                    self.pos = start;
+
+                    // report whichever reading got further into the line
+                    return self.comparison().map_err(|error| {
+                        if column_of(&nested_error) > column_of(&error) {
+                            nested_error
+                        } else {
+                            error
+                        }
+                    });
+                }
+            }
+        }
+
+        self.comparison()
+    }
+
+    /// Whether the next token could follow an expression but not a condition.
+    fn continues_expr(&self) -> bool {
+        ["+", "-", "*", "/", "%", ">", "<", ">=", "<=", "==", "!="]
+            .iter()
+            .any(|sym| self.is_sym(sym))
+    }
+
+    fn comparison(&mut self) -> AocResult<Condition> {
+        let left = self.expr()?;
+
+        let comparison = match self.peek() {
+            Some(Token::Sym(">")) => Comparison::Greater,
+            Some(Token::Sym("<")) => Comparison::Less,
+            Some(Token::Sym(">=")) => Comparison::GreaterOrEqual,
+            Some(Token::Sym("<=")) => Comparison::LessOrEqual,
+            Some(Token::Sym("==")) => Comparison::Equal,
+            Some(Token::Sym("!=")) => Comparison::NotEqual,
+            _ => return Err(self.error("a comparison")),
+        };
+        self.pos += 1;
+
+        Ok(Condition::Compare(comparison, left, self.expr()?))
+    }
+
+    fn expr(&mut self) -> AocResult<Expr> {
+        let mut expr = self.term()?;
+
+        loop {
+            let op = match self.peek() {
+                Some(Token::Sym("+")) => BinOp::Add,
+                Some(Token::Sym("-")) => BinOp::Sub,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> AocResult<Expr> {
+        let mut expr = self.factor()?;
+
+        loop {
+            let op = match self.peek() {
+                Some(Token::Sym("*")) => BinOp::Mul,
+                Some(Token::Sym("/")) => BinOp::Div,
+                Some(Token::Sym("%")) => BinOp::Rem,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.factor()?));
+        }
+    }
+
+    fn factor(&mut self) -> AocResult<Expr> {
+        let token = self.peek().cloned();
+
+        match token {
+            Some(Token::Num(num)) => {
+                self.pos += 1;
+                Ok(Expr::Num(num))
+            }
+            Some(Token::Ident(name)) => {
+                self.pos += 1;
+                Ok(Expr::Reg(name))
+            }
+            Some(Token::Sym("-")) => {
+                self.pos += 1;
+
+                Ok(match self.factor()? {
+                    Expr::Num(num) => Expr::Num(-num),
+                    expr => Expr::Neg(Box::new(expr)),
+                })
+            }
+            Some(Token::Sym("(")) => {
+                self.pos += 1;
+                let expr = self.expr()?;
+                self.expect_sym(")")?;
+
+                Ok(expr)
+            }
+            _ => Err(self.error("a number, a register or `(`")),
+        }
+    }
+}
+
+/// Parses a single instruction.
+pub fn parse_instruction(s: &str) -> AocResult<Instruction> {
+    let mut parser = Parser {
+        tokens: tokenize(s)?,
+        pos: 0,
+        end_column: s.chars().count() + 1,
+    };
+
+    parser.instruction()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_instruction;
+    use aoc_common::AocError;
+    use {BinOp, Comparison, Condition, Expr, Operation};
+
+    fn reg(name: &str) -> Box<Expr> {
+        Box::new(Expr::Reg(name.to_string()))
+    }
+
+    #[test]
+    fn classic_test() {
+        let instr = parse_instruction("c dec -10 if a >= 1").unwrap();
+
+        assert_eq!("c", instr.register);
+        assert_eq!(Operation::Dec, instr.operation);
+        assert_eq!(Expr::Num(-10), instr.operand);
+        assert_eq!(
+            Condition::Compare(Comparison::GreaterOrEqual, Expr::Reg("a".to_string()), Expr::Num(1)),
+            instr.condition
+        );
+
+        // registers may be called like the keywords
+        let instr = parse_instruction("inc inc 1 if dec != 0").unwrap();
+        assert_eq!("inc", instr.register);
+        assert_eq!(
+            Condition::Compare(Comparison::NotEqual, Expr::Reg("dec".to_string()), Expr::Num(0)),
+            instr.condition
+        );
+    }
+
+    #[test]
+    fn expression_test() {
+        let instr = parse_instruction("a inc b * (c - 2) + -d % 3 if (b + 1) * 2 > c && (c < 0 || d == 1)").unwrap();
+
+        assert_eq!(
+            Expr::Binary(
+                BinOp::Add,
+                Box::new(Expr::Binary(
+                    BinOp::Mul,
+                    reg("b"),
+                    Box::new(Expr::Binary(BinOp::Sub, reg("c"), Box::new(Expr::Num(2))))
+                )),
+                Box::new(Expr::Binary(BinOp::Rem, Box::new(Expr::Neg(reg("d"))), Box::new(Expr::Num(3))))
+            ),
+            instr.operand
+        );
+
+        assert_eq!(
+            "a inc b * (c - 2) + -d % 3 if (b + 1) * 2 > c && (c < 0 || d == 1)",
+            instr.to_string()
+        );
+
+        let instr = parse_instruction("a inc 1 if a > 0 || b > 0 && c > 0").unwrap();
+        assert_eq!("a inc 1 if a > 0 || b > 0 && c > 0", instr.to_string());
+
+        match instr.condition {
+            Condition::Or(_, right) => match *right {
+                Condition::And(..) => {}
+                other => panic!("&& does not bind tighter than ||: {:?}", other),
+            },
+            other => panic!("|| is not at the top: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn error_test() {
+        let column = |s: &str| match parse_instruction(s) {
+            Err(AocError::Parse { column, .. }) => column,
+            other => panic!("{:?} parsed as {:?}", s, other),
+        };
+
+        assert_eq!(Some(3), column("a add 1 if b > 0"));
+        assert_eq!(Some(9), column("a inc 1 b > 0"));
+        assert_eq!(Some(16), column("a inc 1 if b > "));
+        assert_eq!(Some(10), column("a inc (1 if b > 0"));
+        assert_eq!(Some(23), column("a inc 1 if (b > 0 && c) "));
+        assert_eq!(Some(14), column("a inc 1 if b $ 0"));
+        assert_eq!(Some(18), column("a inc 1 if b > 0 c"));
+    }
+}
@@ -0,0 +1,241 @@
+//! An interactive session on a `Computer`: instructions typed at the prompt are executed right
+//! away, and can be undone one by one.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use aoc_common::{AocError, AocResult};
+
+use {Computer, Instruction, Program};
+
+const HELP: &str = "commands:
+  <reg> inc|dec <expr> if <condition>  execute an instruction
+  print [<reg>]  show a register, or all registers and the highest value seen
+  undo           revert the last executed instruction
+  load <path>    execute every instruction of a program file
+  reset          clear the registers and the history
+  quit";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    Execute(Instruction),
+    Print(Option<String>),
+    Undo,
+    Load(String),
+    Reset,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split_whitespace().collect::<Vec<&str>>();
+
+        match words.as_slice() {
+            ["p"] | ["print"] => Ok(Command::Print(None)),
+            ["p", reg] | ["print", reg] => Ok(Command::Print(Some(reg.to_string()))),
+            ["u"] | ["undo"] => Ok(Command::Undo),
+            ["load", path] => Ok(Command::Load(path.to_string())),
+            ["reset"] => Ok(Command::Reset),
+            ["h"] | ["help"] => Ok(Command::Help),
+            ["q"] | ["quit"] => Ok(Command::Quit),
+            // anything else has to be an instruction, which takes at least five words
+            _ => s.parse().map(Command::Execute),
+        }
+    }
+}
+
+pub struct Repl {
+    computer: Computer,
+    /// The executed instructions, each with the computer as it was before it.
+    history: Vec<(Instruction, Computer)>,
+}
+
+impl Repl {
+    pub fn new() -> Repl {
+        Repl {
+            computer: Computer::new(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    /// Executes an instruction, remembering how to undo it.
+    pub fn execute(&mut self, instr: Instruction) -> AocResult<bool> {
+        let before = self.computer.clone();
+        let executed = self.computer.execute(&instr)?;
+
+        self.history.push((instr, before));
+        Ok(executed)
+    }
+
+    /// Reverts the last executed instruction and returns it.
+    pub fn undo(&mut self) -> Option<Instruction> {
+        self.history.pop().map(|(instr, before)| {
+            self.computer = before;
+            instr
+        })
+    }
+
+    /// Executes all the instructions of the program, each of them undoable on its own.
+    ///
+    /// Stops at the first failing instruction, keeping the effect of the ones before it.
+    pub fn load(&mut self, program: &Program) -> AocResult<usize> {
+        for (executed, instr) in program.instructions().iter().enumerate() {
+            self.execute(instr.clone())
+                .map_err(|e| AocError::runtime(format!("{} (after {} instructions)", e, executed)))?;
+        }
+
+        Ok(program.instructions().len())
+    }
+
+    pub fn reset(&mut self) {
+        self.computer = Computer::new();
+        self.history.clear();
+    }
+
+    fn show_register(&self, name: &str) -> String {
+        format!("{} = {}", name, self.computer.registers().get(name).cloned().unwrap_or(0))
+    }
+
+    fn show_registers(&self) -> String {
+        let mut names = self.computer.registers().keys().collect::<Vec<&String>>();
+        names.sort();
+
+        let mut lines = names.iter().map(|name| self.show_register(name)).collect::<Vec<String>>();
+
+        lines.push(match self.computer.overall_max() {
+            Some(max) => format!("highest value seen: {}", max),
+            None => "no registers yet".to_string(),
+        });
+
+        lines.join("\n")
+    }
+
+    /// Executes a command and returns what should be shown to the user, or `None` on `quit`.
+    pub fn run_command(&mut self, cmd: Command) -> AocResult<Option<String>> {
+        let output = match cmd {
+            Command::Execute(instr) => {
+                let register = instr.register.clone();
+
+                if self.execute(instr)? {
+                    self.show_register(&register)
+                } else {
+                    "condition is false".to_string()
+                }
+            }
+            Command::Print(Some(name)) => self.show_register(&name),
+            Command::Print(None) => self.show_registers(),
+            Command::Undo => match self.undo() {
+                Some(instr) => format!("undid `{}`", instr),
+                None => "nothing to undo".to_string(),
+            },
+            Command::Load(path) => {
+                let source = fs::read_to_string(&path)
+                    .map_err(|e| AocError::runtime(format!("could not read {}: {}", path, e)))?;
+
+                let count = self.load(&source.parse()?)?;
+                format!("executed {} instructions", count)
+            }
+            Command::Reset => {
+                self.reset();
+                "registers cleared".to_string()
+            }
+            Command::Help => HELP.to_string(),
+            Command::Quit => return Ok(None),
+        };
+
+        Ok(Some(output))
+    }
+
+    /// Reads commands line by line until `quit` or the end of the input. Errors in commands are
+    /// reported to the user and do not end the session.
+    pub fn run_session<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        write!(output, "(day08) ")?;
+        output.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                match line.parse().and_then(|cmd| self.run_command(cmd)) {
+                    Ok(Some(text)) => writeln!(output, "{}", text)?,
+                    Ok(None) => return Ok(()),
+                    Err(e) => writeln!(output, "{}", e)?,
+                }
+            }
+
+            write!(output, "(day08) ")?;
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Repl {
+    fn default() -> Repl {
+        Repl::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, Repl};
+
+    #[test]
+    fn command_test() {
+        assert_eq!(Command::Print(Some("a".to_string())), "p a".parse().unwrap());
+        assert_eq!(Command::Undo, "undo".parse().unwrap());
+        assert_eq!(Command::Load("input.txt".to_string()), "load input.txt".parse().unwrap());
+
+        // a register may be called like a command
+        match "undo inc 1 if print > 0".parse().unwrap() {
+            Command::Execute(instr) => assert_eq!("undo", instr.register),
+            other => panic!("not an instruction: {:?}", other),
+        }
+
+        assert!("undo inc".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn undo_test() {
+        let mut repl = Repl::new();
+
+        repl.load(&"a inc 5 if b == 0\nb dec a * 2 if a > 1".parse().unwrap()).unwrap();
+        assert_eq!(-10, repl.computer().registers()["b"]);
+
+        assert_eq!("b dec a * 2 if a > 1", repl.undo().unwrap().to_string());
+        assert_eq!(0, repl.computer().registers()["b"]);
+        assert_eq!(Some(5), repl.computer().overall_max());
+
+        assert_eq!("a inc 5 if b == 0", repl.undo().unwrap().to_string());
+        assert!(repl.computer().registers().is_empty());
+        assert_eq!(None, repl.undo());
+    }
+
+    #[test]
+    fn session_test() {
+        let input = "a inc 3 if a == 0\nc dec 1 if a > 5\nfoo\nprint\nundo\nprint a\nquit\nprint\n";
+        let mut output = Vec::new();
+
+        Repl::new().run_session(input.as_bytes(), &mut output).unwrap();
+
+        let expected = "(day08) a = 3
+(day08) condition is false
+(day08) parse error at column 4: expected `inc` or `dec`, found the end of the line
+(day08) a = 3
+highest value seen: 3
+(day08) undid `c dec 1 if a > 5`
+(day08) a = 3
+(day08) ";
+
+        assert_eq!(expected, String::from_utf8(output).unwrap());
+    }
+}
@@ -1,6 +1,6 @@
+extern crate aoc_common;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
 fn score_groups(test_str: &str) -> (usize, usize) {
     let mut ignore_next = false;
@@ -38,19 +38,23 @@ fn score_groups(test_str: &str) -> (usize, usize) {
     (score, garbage_count)
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let (score, garbage_count) = score_groups(&input_str);
+impl Solver for Solution {
+    type Input = String;
 
-    println!(
-        "The score of the input data is {}. The garbage count is {}",
-        score,
-        garbage_count
-    );
-}
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(score_groups(input).0.to_string())
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(score_groups(input).1.to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
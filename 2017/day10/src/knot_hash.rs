@@ -0,0 +1,136 @@
+use std::hash::Hasher;
+
+/// Number of bytes in a full knot hash.
+pub const HASH_LEN: usize = 16;
+
+const LIST_LEN: usize = 256;
+const ROUNDS: usize = 64;
+const LENGTHS_SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+/// Ties `num_rounds` rounds of knots into a list of `list_len` marks and returns the resulting list.
+pub fn sparse_hash(list_len: usize, num_rounds: usize, skip_lengths: &[usize]) -> Vec<usize> {
+    let mut out: Vec<usize> = (0..list_len).collect();
+    let mut curr_pos = 0;
+    let mut skip_len = 0;
+
+    for _ in 0..num_rounds {
+        for in_skip_len in skip_lengths {
+
+            let end_pos = curr_pos + in_skip_len;
+
+            let tmp_vec = if end_pos <= out.len() {
+                out[curr_pos..end_pos].to_vec()
+            } else {
+                let mut tmp = out[curr_pos..].to_vec();
+
+                tmp.extend(out[0..(end_pos % out.len())].to_vec());
+
+                tmp
+            };
+
+            for (i, val) in tmp_vec.into_iter().rev().enumerate() {
+                let l = out.len();
+                out[(curr_pos + i) % l] = val;
+            }
+
+            curr_pos = (end_pos + skip_len) % out.len();
+            skip_len += 1;
+        }
+    }
+
+    out
+}
+
+/// Computes the full 64 round knot hash of the input bytes.
+pub fn knot_hash(input: &[u8]) -> [u8; HASH_LEN] {
+    let skip_lengths: Vec<usize> = input
+        .iter()
+        .map(|b| *b as usize)
+        .chain(LENGTHS_SUFFIX.iter().cloned())
+        .collect();
+
+    let sparse = sparse_hash(LIST_LEN, ROUNDS, &skip_lengths);
+
+    let mut dense = [0; HASH_LEN];
+
+    for (byte, block) in dense.iter_mut().zip(sparse.chunks(LIST_LEN / HASH_LEN)) {
+        *byte = block.iter().fold(0, |acc, val| acc ^ (*val as u8));
+    }
+
+    dense
+}
+
+/// Formats a hash as lowercase hexadecimal digits.
+pub fn to_hex(hash: &[u8]) -> String {
+    hash.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Computes the knot hash of a string, in the hexadecimal form used by the puzzles.
+pub fn knot_hash_hex(input: &str) -> String {
+    to_hex(&knot_hash(input.as_bytes()))
+}
+
+/// `Hasher` collecting all the written bytes and knot hashing them on `finish`.
+///
+/// Knot hashes are not streaming, so every call to `finish` hashes the whole input again.
+#[derive(Debug, Default, Clone)]
+pub struct KnotHasher {
+    input: Vec<u8>,
+}
+
+impl KnotHasher {
+    pub fn new() -> KnotHasher {
+        KnotHasher::default()
+    }
+
+    /// The full knot hash of the bytes written so far.
+    pub fn digest(&self) -> [u8; HASH_LEN] {
+        knot_hash(&self.input)
+    }
+}
+
+impl Hasher for KnotHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.input.extend_from_slice(bytes);
+    }
+
+    /// The first eight bytes of the knot hash, read as a big endian number.
+    fn finish(&self) -> u64 {
+        self.digest()[..8]
+            .iter()
+            .fold(0, |acc, byte| (acc << 8) | u64::from(*byte))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+
+    use super::{knot_hash, knot_hash_hex, sparse_hash, to_hex, KnotHasher};
+
+    #[test]
+    fn sparse_hash_test() {
+        assert_eq!(vec![3, 4, 2, 1, 0], sparse_hash(5, 1, &[3, 4, 1, 5]));
+    }
+
+    #[test]
+    fn knot_hash_test() {
+        assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", knot_hash_hex(""));
+        assert_eq!("33efeb34ea91902bb2f59c9920caa6cd", knot_hash_hex("AoC 2017"));
+        assert_eq!("3efbe78a8d82f29979031a4aa0b16a9d", knot_hash_hex("1,2,3"));
+        assert_eq!("63960835bcdc130f0b66d7ff4f6a5a8e", knot_hash_hex("1,2,4"));
+
+        assert_eq!(0xa2, knot_hash(b"")[0]);
+        assert_eq!("00ff10", to_hex(&[0x00, 0xff, 0x10]));
+    }
+
+    #[test]
+    fn hasher_test() {
+        let mut hasher = KnotHasher::new();
+        hasher.write(b"AoC ");
+        hasher.write(b"2017");
+
+        assert_eq!(knot_hash(b"AoC 2017"), hasher.digest());
+        assert_eq!(0x33ef_eb34_ea91_902b, hasher.finish());
+    }
+}
@@ -0,0 +1,28 @@
+extern crate aoc_common;
+
+pub mod knot_hash;
+
+use aoc_common::{parse_separated, AocResult, Solver};
+
+pub use knot_hash::{knot_hash, knot_hash_hex, KnotHasher};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let skip_lengths = parse_separated::<usize>(input, ',')?;
+
+        let hash = knot_hash::sparse_hash(256, 1, &skip_lengths);
+        Ok((hash[0] * hash[1]).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(knot_hash_hex(input))
+    }
+}
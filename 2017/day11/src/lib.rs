@@ -1,9 +1,9 @@
-
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
 
 use std::str::FromStr;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 #[derive(Debug, Copy, Clone)]
 enum Direction {
     North,
@@ -50,12 +50,12 @@ impl Position {
     }
 
     fn distance_to(&self, other_pos: &Position) -> usize {
-        ((self.0 - other_pos.0).abs() as usize + (self.1 - other_pos.1).abs() as usize +
-             (self.2 - other_pos.2).abs() as usize) / 2
+        ((self.0 - other_pos.0).unsigned_abs() + (self.1 - other_pos.1).unsigned_abs() +
+             (self.2 - other_pos.2).unsigned_abs()) / 2
     }
 }
 
-struct Path(Vec<Direction>);
+pub struct Path(Vec<Direction>);
 
 impl FromStr for Path {
     type Err = ();
@@ -86,24 +86,29 @@ impl Path {
 }
 
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let path: Path = input_str.trim().parse().expect("parse error");
-    let start_pos = Position::new();
-    let (end_pos, max_dist) = path.walk_and_remember(&start_pos);
+impl Solver for Solution {
+    type Input = Path;
 
-    println!(
-        "The distance from {:?} to {:?} is {}. The maximum distance from {:?} was {}.",
-        start_pos,
-        end_pos,
-        start_pos.distance_to(&end_pos),
-        start_pos,
-        max_dist
-    );
-}
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.trim().parse().map_err(|_| AocError::parse("error parsing path"))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let start_pos = Position::new();
+        let (end_pos, _) = input.walk_and_remember(&start_pos);
 
+        Ok(start_pos.distance_to(&end_pos).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let start_pos = Position::new();
+        let (_, max_dist) = input.walk_and_remember(&start_pos);
+
+        Ok(max_dist.to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1,16 +1,16 @@
+extern crate aoc_common;
 extern crate regex;
 
-use std::io;
-use std::io::prelude::*;
-
 use regex::Regex;
 
 use std::str::FromStr;
 
 use std::collections::HashSet;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 #[derive(Debug)]
-struct ProgramGroups {
+pub struct ProgramGroups {
     groups: Vec<HashSet<usize>>,
 }
 
@@ -23,12 +23,12 @@ impl FromStr for ProgramGroups {
             for i in 0..groups.len() {
                 if grp.intersection(&groups[i]).count() != 0 {
 
-                    grp.extend(groups.remove(i).into_iter());
+                    grp.extend(groups.remove(i));
                     return false;
                 }
             }
 
-            return true;
+            true
         }
 
 
@@ -83,17 +83,26 @@ impl ProgramGroups {
 }
 
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
+impl Solver for Solution {
+    type Input = ProgramGroups;
 
-    let prog_groups: ProgramGroups = input_str.parse().expect("parse error");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing program groups"))
+    }
 
-    println!("The group containing program 0 contains {} programs.", prog_groups.get_num_programs_in_group(0).expect("no such program"));
-    println!("There are {} groups in totat.", prog_groups.get_group_count());
-}
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        input
+            .get_num_programs_in_group(0)
+            .map(|count| count.to_string())
+            .ok_or_else(|| AocError::unsolvable("no program 0"))
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(input.get_group_count().to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
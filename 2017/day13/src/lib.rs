@@ -1,4 +1,4 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 use regex::Regex;
@@ -7,9 +7,7 @@ use std::collections::HashMap;
 
 use std::str::FromStr;
 
-use std::io;
-use std::io::prelude::*;
-
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug)]
 struct FwLayer {
@@ -17,17 +15,16 @@ struct FwLayer {
 }
 
 
-
 impl FwLayer {
     fn new(len: usize) -> FwLayer {
         FwLayer {
-            len: len,
+            len,
         }
     }
 
     fn get_pos_at_time(&self, time: usize) -> usize {
         let pos_rem = time % (self.len - 1);
-        let dir_down = (time / (self.len - 1)) % 2 == 0;
+        let dir_down = (time / (self.len - 1)).is_multiple_of(2);
 
 
         if dir_down {
@@ -44,7 +41,7 @@ impl FwLayer {
 
 
 #[derive(Debug)]
-struct Firewall {
+pub struct Firewall {
     layers: HashMap<usize, FwLayer>
 }
 
@@ -105,15 +102,23 @@ fn find_fw_entry_delay(fw: &Firewall) -> usize {
 }
 
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
-    let fw: Firewall = input_str.parse().expect("parse error");
+pub struct Solution;
 
-    println!("The severity of a packet run at time 0 is {}.", fw.run_packet(0).1);
-    println!("The first start time at which a packet is not caught is {}.", find_fw_entry_delay(&fw));
-}
+impl Solver for Solution {
+    type Input = Firewall;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing firewall"))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.run_packet(0).1.to_string())
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(find_fw_entry_delay(input).to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,111 @@
+extern crate aoc_common;
+extern crate aoc2017_day10;
+
+use std::fmt;
+
+use aoc2017_day10::knot_hash;
+
+use aoc_common::{AocResult, Grid, Solver};
+
+/// The used squares of the disk.
+struct Disk(Grid<bool>);
+
+impl fmt::Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0.render(|square| if *square { '#' } else { '.' }))
+    }
+}
+
+impl Disk {
+    fn new(init_hash: &str) -> Disk {
+        let hashes = (0..128)
+            .map(|row_idx| knot_hash(format!("{}-{}", init_hash, row_idx).as_bytes()))
+            .collect::<Vec<_>>();
+
+        Disk(Grid::from_fn(128, 128, |pos| {
+            let (row_idx, col_idx) = (pos.y as usize, pos.x as usize);
+            let byte_pos = col_idx / 8;
+            let offset = col_idx % 8;
+
+            (hashes[row_idx][byte_pos] & (1 << (7 - offset))) != 0
+        }))
+    }
+
+    fn get_used_square_count(&self) -> usize {
+        self.0.values().filter(|square| **square).count()
+    }
+
+    fn get_region_count(&self) -> usize {
+        let mut region_count = 0;
+        let mut squares_seen = Grid::new(128, 128, false);
+
+        for (square, used) in self.0.iter() {
+            if *used && !squares_seen[square] {
+                for region_square in self.0.flood_fill(square, |used| *used) {
+                    squares_seen[region_square] = true;
+                }
+
+                region_count += 1;
+            }
+        }
+
+        region_count
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(Disk::new(input).get_used_square_count().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(Disk::new(input).get_region_count().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disk;
+
+    #[test]
+    fn grid_creation_test() {
+        let expected_grid = "##.#.#..\n\
+                             .#.#.#.#\n\
+                             ....#.#.\n\
+                             #.#.##.#\n\
+                             .##.#...\n\
+                             ##..#..#\n\
+                             .#...#..\n\
+                             ##.#.##.";
+
+        let grid = Disk::new("flqrgnkx");
+        let grid_str = format!("{}", grid);
+        let grid_str_split = grid_str.lines().collect::<Vec<&str>>();
+
+        for (i, line) in expected_grid.lines().enumerate() {
+            assert_eq!(line, &grid_str_split[i][0..line.len()]);
+        }
+    }
+
+    #[test]
+    fn grid_count_test() {
+        let grid = Disk::new("flqrgnkx");
+
+        assert_eq!(8108, grid.get_used_square_count());
+    }
+
+    #[test]
+    fn grid_region_count_test() {
+        let grid = Disk::new("flqrgnkx");
+
+        assert_eq!(1242, grid.get_region_count());
+    }
+}
@@ -1,8 +1,8 @@
+extern crate aoc_common;
 
-use std::env;
+use aoc_common::{AocError, AocResult, Solver};
 
 struct Generator {
-    initial_val: usize,
     curr_val: usize,
     factor: usize,
     divisible_by: usize,
@@ -26,10 +26,9 @@ impl Generator {
         };
 
         Generator {
-            initial_val: initial_val,
             curr_val: initial_val,
-            factor: factor,
-            divisible_by: divisible_by,
+            factor,
+            divisible_by,
         }
     }
 
@@ -43,15 +42,11 @@ impl Generator {
         loop {
             let val = self.next_val();
 
-            if val % self.divisible_by == 0 {
+            if val.is_multiple_of(self.divisible_by) {
                 return val;
             }
         }
     }
-
-    fn reset(&mut self) {
-        self.curr_val = self.initial_val;
-    }
 }
 
 struct Judge {
@@ -93,41 +88,47 @@ impl Judge {
 
         match_count
     }
+}
 
-    fn reset(&mut self) {
-        self.gen_a.reset();
-        self.gen_b.reset();
-    }
+fn parse_generator(line: Option<&str>) -> AocResult<usize> {
+    line.and_then(|line| line.split_whitespace().last())
+        .ok_or_else(|| AocError::parse("missing generator"))?
+        .parse()
+        .map_err(AocError::from)
 }
 
-fn main() {
-    let mut args = env::args();
-    args.next();
+pub struct Solution;
 
-    let factor_a = args.next().expect("missing param").parse().expect(
-        "parse error",
-    );
-    let factor_b = args.next().expect("missing param").parse().expect(
-        "parse error",
-    );
+impl Solver for Solution {
+    type Input = (usize, usize);
 
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        let mut lines = input.lines();
 
-    let gen_a = Generator::new(factor_a, GeneratorType::A);
-    let gen_b = Generator::new(factor_b, GeneratorType::B);
+        let init_a = parse_generator(lines.next())?;
+        let init_b = parse_generator(lines.next())?;
 
-    let mut judge = Judge::new(gen_a, gen_b);
-    println!(
-        "The number of matches after 40 000 000 iterations is {}.",
-        judge.count_matches(40_000_000)
-    );
+        Ok((init_a, init_b))
+    }
 
-    judge.reset();
-    println!(
-        "The number of matches after 5 000 000 iterations while using the picky algorithm is {}.",
-        judge.count_matches_picky(5_000_000)
-    );
-}
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut judge = Judge::new(
+            Generator::new(input.0, GeneratorType::A),
+            Generator::new(input.1, GeneratorType::B),
+        );
 
+        Ok(judge.count_matches(40_000_000).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut judge = Judge::new(
+            Generator::new(input.0, GeneratorType::A),
+            Generator::new(input.1, GeneratorType::B),
+        );
+
+        Ok(judge.count_matches_picky(5_000_000).to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,4 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 #[macro_use]
@@ -7,13 +7,12 @@ extern crate lazy_static;
 use regex::Regex;
 
 use std::str::FromStr;
-use std::string::ToString;
-
-use std::io;
-use std::io::prelude::*;
+use std::fmt;
 
 use std::collections::BTreeMap;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 lazy_static! {
     static ref SPIN_RE: Regex = Regex::new(r"^s(?P<spin_by>[0-9]+)$").unwrap();
     static ref EXCHANGE_RE: Regex = Regex::new(r"^x(?P<exchange_a>[0-9]+)/(?P<exchange_b>[0-9]+)$").unwrap();
@@ -83,7 +82,7 @@ impl Programs {
         let mut programs = Vec::with_capacity(len as usize);
 
         for ch_offset in 0..len {
-            programs.push((('a' as u8) + ch_offset) as char);
+            programs.push((b'a' + ch_offset) as char);
         }
 
         Programs { programs }
@@ -116,9 +115,7 @@ impl Programs {
                         return Err(());
                     }
 
-                    let tmp = self.programs[a];
-                    self.programs[a] = self.programs[b];
-                    self.programs[b] = tmp;
+                    self.programs.swap(a, b);
                 }
 
                 DanceMove::Partner(a, b) => {
@@ -133,9 +130,7 @@ impl Programs {
                         .find(|&(_, ch)| *ch == b)
                         .ok_or(())?;
 
-                    let tmp = self.programs[a_pos];
-                    self.programs[a_pos] = self.programs[b_pos];
-                    self.programs[b_pos] = tmp;
+                    self.programs.swap(a_pos, b_pos);
                 }
             }
         }
@@ -149,7 +144,7 @@ impl Programs {
         let mut i = 0;
 
         while i < num_dances {
-            self.dance(&dance)?;
+            self.dance(dance)?;
 
 
             if !states_seen.contains_key(&self.programs) {
@@ -168,15 +163,15 @@ impl Programs {
     }
 }
 
-impl ToString for Programs {
-    fn to_string(&self) -> String {
-        self.programs.iter().collect()
+impl fmt::Display for Programs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.programs.iter().collect::<String>())
     }
 }
 
 
 #[derive(Debug, PartialEq, Eq)]
-struct Dance(Vec<DanceMove>);
+pub struct Dance(Vec<DanceMove>);
 
 impl FromStr for Dance {
     type Err = ();
@@ -188,27 +183,32 @@ impl FromStr for Dance {
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let dance = input_str.parse().expect("parse error");
+impl Solver for Solution {
+    type Input = Dance;
 
-    let mut progs = Programs::new(16);
-    progs.dance(&dance).expect("dance error");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.trim().parse().map_err(|_| AocError::parse("error parsing dance"))
+    }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut progs = Programs::new(16);
+        progs.dance(input).map_err(|_| AocError::runtime("dance error"))?;
 
-    println!("Program order after the first dance: {}", progs.to_string());
+        Ok(progs.to_string())
+    }
 
-    progs.perform_dances(&dance, 999_999_999).unwrap();
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut progs = Programs::new(16);
+        progs
+            .perform_dances(input, 1_000_000_000)
+            .map_err(|_| AocError::runtime("dance error"))?;
 
-    println!(
-        "Program order after 1 000 000 000 dances: {}",
-        progs.to_string()
-    );
+        Ok(progs.to_string())
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
 
@@ -1,5 +1,6 @@
+extern crate aoc_common;
 
-use std::env;
+use aoc_common::{AocError, AocResult, Solver};
 
 struct Spinlock {
     buffer: Vec<usize>,
@@ -32,7 +33,7 @@ impl Spinlock {
         {
             let pos = (idx + 1) % self.buffer.len();
 
-            self.buffer.iter().nth(pos).map(|val| *val)
+            self.buffer.get(pos).copied()
         } else {
             None
         }
@@ -42,47 +43,42 @@ impl Spinlock {
 fn get_spinlock_value_after_zero(step_size: usize, num_insertions: usize) -> Option<usize> {
     let mut val = None;
     let mut curr_pos = 0;
-    let mut lock_len = 1;
-
     for i in 1..(num_insertions + 1) {
-        curr_pos = (curr_pos + step_size) % lock_len + 1;
+        // The lock holds `i` values right before the i-th insertion
+        curr_pos = (curr_pos + step_size) % i + 1;
 
         if curr_pos == 1 {
             val = Some(i);
         }
-
-        lock_len += 1;
     }
 
     val
 }
 
 
+pub struct Solution;
 
-fn main() {
-    let mut args = env::args();
-    args.next();
+impl Solver for Solution {
+    type Input = usize;
 
-    let step_size = args.next().expect("missing argument").parse().expect(
-        "parse error",
-    );
-
-    let lock = Spinlock::new(step_size, 2017);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.trim().parse().map_err(AocError::from)
+    }
 
-    println!(
-        "Short lock: The value right after 2017 is {}.",
-        lock.get_value_after(2017).expect(
-            "2017 missing in spinlock",
-        )
-    );
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Spinlock::new(*input, 2017)
+            .get_value_after(2017)
+            .map(|val| val.to_string())
+            .ok_or_else(|| AocError::unsolvable("2017 missing in spinlock"))
+    }
 
-    println!(
-        "Long lock: The value right after 0 is {}.",
-        get_spinlock_value_after_zero(step_size, 50_000_000).unwrap()
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        get_spinlock_value_after_zero(*input, 50_000_000)
+            .map(|val| val.to_string())
+            .ok_or_else(|| AocError::unsolvable("0 missing in spinlock"))
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::Spinlock;
@@ -0,0 +1,41 @@
+extern crate aoc2017_day18;
+extern crate aoc_common;
+extern crate criterion;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc2017_day18::{Duet, DuetEvent, DuetState, Program, Sound, SoundState};
+use aoc_common::compiled::CompiledMachine;
+use aoc_common::vm::{Machine, Status};
+
+fn interpreter_vs_compiled(c: &mut Criterion) {
+    let prog = include_str!("../input.txt").parse::<Program>().unwrap();
+
+    let mut group = c.benchmark_group("2017/day18");
+
+    group.bench_function("sound interpreted", |b| {
+        b.iter(|| Machine::<Sound>::new(prog.instructions().to_vec(), SoundState::default()).run())
+    });
+    group.bench_function("sound compiled", |b| {
+        b.iter(|| CompiledMachine::<Sound>::new(prog.instructions().to_vec(), SoundState::default()).run())
+    });
+
+    // program 0 on its own, until it waits for a value
+    group.bench_function("duet interpreted", |b| {
+        b.iter(|| {
+            let mut machine = Machine::<Duet>::new(prog.instructions().to_vec(), DuetState::default());
+            while let Ok(Status::Paused(DuetEvent::Sent(_))) = machine.run() {}
+        })
+    });
+    group.bench_function("duet compiled", |b| {
+        b.iter(|| {
+            let mut machine = CompiledMachine::<Duet>::new(prog.instructions().to_vec(), DuetState::default());
+            while let Ok(Status::Paused(DuetEvent::Sent(_))) = machine.run() {}
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, interpreter_vs_compiled);
+criterion_main!(benches);
@@ -0,0 +1,462 @@
+extern crate aoc_common;
+
+pub mod network;
+
+use std::collections::VecDeque;
+
+use std::str::FromStr;
+
+use aoc_common::compiled::{Compile, CompiledMachine, Op, Slot};
+use aoc_common::vm::{self, Fault, InstructionSet, Operand, RegisterFile, Status, Step};
+use aoc_common::{parse_lines, AocError, AocResult, Solver};
+
+use network::{Network, Topology};
+
+pub type Value = Operand<char, isize>;
+
+pub type Registers = RegisterFile<char, isize>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Instruction {
+    Snd(Value),
+    Set(Value, Value),
+    Add(Value, Value),
+    Mul(Value, Value),
+    Mod(Value, Value),
+    Rcv(Value),
+    Jgz(Value, Value),
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mnemonic, args) = vm::split_instruction(s)?;
+
+        match mnemonic {
+            "snd" | "rcv" => {
+                let [arg] = vm::operands(mnemonic, &args)?;
+                let arg = arg.parse()?;
+
+                Ok(if mnemonic == "snd" {
+                    Instruction::Snd(arg)
+                } else {
+                    Instruction::Rcv(arg)
+                })
+            }
+            "set" | "add" | "mul" | "mod" | "jgz" => {
+                let [arg_1, arg_2] = vm::operands(mnemonic, &args)?;
+                let (arg_1, arg_2) = (arg_1.parse()?, arg_2.parse()?);
+
+                Ok(match mnemonic {
+                    "set" => Instruction::Set(arg_1, arg_2),
+                    "add" => Instruction::Add(arg_1, arg_2),
+                    "mul" => Instruction::Mul(arg_1, arg_2),
+                    "mod" => Instruction::Mod(arg_1, arg_2),
+                    _ => Instruction::Jgz(arg_1, arg_2),
+                })
+            }
+            _ => Err(AocError::parse(format!("unknown instruction: {:?}", s))),
+        }
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Program(Vec<Instruction>);
+
+impl Program {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.0
+    }
+}
+
+impl FromStr for Program {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lines(s).map(Program)
+    }
+}
+
+
+/// Executes the instructions that both interpretations of `snd` and `rcv` agree on.
+fn execute_arithmetic<E>(instr: &Instruction, regs: &mut Registers) -> Result<Step<E>, Fault> {
+    match *instr {
+        Instruction::Set(arg_1, arg_2) => {
+            regs[arg_1.register()?] = arg_2.value(regs);
+        }
+        Instruction::Add(arg_1, arg_2) => {
+            let reg = arg_1.register()?;
+            regs[reg] = regs[reg].checked_add(arg_2.value(regs)).ok_or(Fault::Overflow)?;
+        }
+        Instruction::Mul(arg_1, arg_2) => {
+            let reg = arg_1.register()?;
+            regs[reg] = regs[reg].checked_mul(arg_2.value(regs)).ok_or(Fault::Overflow)?;
+        }
+        Instruction::Mod(arg_1, arg_2) => {
+            let reg = arg_1.register()?;
+            regs[reg] = regs[reg].checked_rem(arg_2.value(regs)).ok_or(Fault::DivisionByZero)?;
+        }
+        Instruction::Jgz(arg_1, arg_2) => {
+            if arg_1.value(regs) > 0 {
+                return Ok(Step::Jump(arg_2.value(regs)));
+            }
+        }
+        Instruction::Snd(_) | Instruction::Rcv(_) => {
+            return Err(Fault::Invalid(format!("{:?} is not an arithmetic instruction", instr)));
+        }
+    }
+
+    Ok(Step::Next)
+}
+
+
+/// States of the interpretations, for the instructions they share.
+trait HasRegisters {
+    fn regs(&mut self) -> &mut Registers;
+}
+
+/// Compiles the instructions that both interpretations of `snd` and `rcv` agree on.
+fn compile_arithmetic<S>(instr: &Instruction) -> Op<S>
+where
+    S: InstructionSet,
+    S::State: HasRegisters,
+{
+    fn invalid<S: InstructionSet>(fault: Fault) -> Op<S> {
+        Box::new(move |_| Err(fault.clone()))
+    }
+
+    match *instr {
+        Instruction::Set(arg_1, arg_2)
+        | Instruction::Add(arg_1, arg_2)
+        | Instruction::Mul(arg_1, arg_2)
+        | Instruction::Mod(arg_1, arg_2) => {
+            let reg = match arg_1.register() {
+                Ok(reg) => vm::Register::index(reg),
+                Err(fault) => return invalid::<S>(fault),
+            };
+            let arg_2 = Slot::from(arg_2);
+
+            match *instr {
+                Instruction::Set(..) => Box::new(move |state| {
+                    let slots = state.regs().slots_mut();
+                    slots[reg] = arg_2.value(slots);
+                    Ok(Step::Next)
+                }),
+                Instruction::Add(..) => Box::new(move |state| {
+                    let slots = state.regs().slots_mut();
+                    slots[reg] = slots[reg].checked_add(arg_2.value(slots)).ok_or(Fault::Overflow)?;
+                    Ok(Step::Next)
+                }),
+                Instruction::Mul(..) => Box::new(move |state| {
+                    let slots = state.regs().slots_mut();
+                    slots[reg] = slots[reg].checked_mul(arg_2.value(slots)).ok_or(Fault::Overflow)?;
+                    Ok(Step::Next)
+                }),
+                _ => Box::new(move |state| {
+                    let slots = state.regs().slots_mut();
+                    slots[reg] = slots[reg].checked_rem(arg_2.value(slots)).ok_or(Fault::DivisionByZero)?;
+                    Ok(Step::Next)
+                }),
+            }
+        }
+        Instruction::Jgz(Operand::Imm(cond), Operand::Imm(offset)) => {
+            if cond > 0 {
+                Box::new(move |_| Ok(Step::Jump(offset)))
+            } else {
+                Box::new(|_| Ok(Step::Next))
+            }
+        }
+        Instruction::Jgz(arg_1, arg_2) => {
+            let (arg_1, arg_2) = (Slot::from(arg_1), Slot::from(arg_2));
+
+            Box::new(move |state| {
+                let slots = state.regs().slots();
+
+                Ok(if arg_1.value(slots) > 0 {
+                    Step::Jump(arg_2.value(slots))
+                } else {
+                    Step::Next
+                })
+            })
+        }
+        Instruction::Snd(_) | Instruction::Rcv(_) => invalid::<S>(Fault::Invalid(format!(
+            "{:?} is not an arithmetic instruction",
+            instr
+        ))),
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SoundState {
+    regs: Registers,
+    last_sound: Option<isize>,
+}
+
+impl HasRegisters for SoundState {
+    fn regs(&mut self) -> &mut Registers {
+        &mut self.regs
+    }
+}
+
+/// The first interpretation: `snd` plays a sound, `rcv` recovers the last one played.
+///
+/// The machine pauses on the first recovery, reporting the recovered frequency.
+pub struct Sound;
+
+impl InstructionSet for Sound {
+    type Instr = Instruction;
+    type State = SoundState;
+    type Event = Option<isize>;
+
+    fn execute(instr: &Instruction, state: &mut SoundState) -> Result<Step<Option<isize>>, Fault> {
+        match *instr {
+            Instruction::Snd(arg) => {
+                state.last_sound = Some(arg.value(&state.regs));
+
+                Ok(Step::Next)
+            }
+            Instruction::Rcv(arg) => {
+                if arg.value(&state.regs) != 0 {
+                    Ok(Step::Emit(state.last_sound))
+                } else {
+                    Ok(Step::Next)
+                }
+            }
+            _ => execute_arithmetic(instr, &mut state.regs),
+        }
+    }
+}
+
+
+impl Compile for Sound {
+    fn compile(instr: &Instruction) -> Op<Sound> {
+        match *instr {
+            Instruction::Snd(arg) => {
+                let arg = Slot::from(arg);
+
+                Box::new(move |state| {
+                    state.last_sound = Some(arg.value(state.regs.slots()));
+                    Ok(Step::Next)
+                })
+            }
+            Instruction::Rcv(arg) => {
+                let arg = Slot::from(arg);
+
+                Box::new(move |state| {
+                    Ok(if arg.value(state.regs.slots()) != 0 {
+                        Step::Emit(state.last_sound)
+                    } else {
+                        Step::Next
+                    })
+                })
+            }
+            _ => compile_arithmetic::<Sound>(instr),
+        }
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DuetEvent {
+    Sent(isize),
+    Blocked,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DuetState {
+    regs: Registers,
+    rx_queue: VecDeque<isize>,
+}
+
+impl HasRegisters for DuetState {
+    fn regs(&mut self) -> &mut Registers {
+        &mut self.regs
+    }
+}
+
+/// The second interpretation: `snd` sends a value to the other program, `rcv` waits for one.
+pub struct Duet;
+
+impl InstructionSet for Duet {
+    type Instr = Instruction;
+    type State = DuetState;
+    type Event = DuetEvent;
+
+    fn execute(instr: &Instruction, state: &mut DuetState) -> Result<Step<DuetEvent>, Fault> {
+        match *instr {
+            Instruction::Snd(arg) => Ok(Step::Emit(DuetEvent::Sent(arg.value(&state.regs)))),
+            Instruction::Rcv(arg) => {
+                let reg = arg.register()?;
+
+                if let Some(val) = state.rx_queue.pop_front() {
+                    state.regs[reg] = val;
+
+                    Ok(Step::Next)
+                } else {
+                    Ok(Step::Block(DuetEvent::Blocked))
+                }
+            }
+            _ => execute_arithmetic(instr, &mut state.regs),
+        }
+    }
+}
+
+
+impl Compile for Duet {
+    fn compile(instr: &Instruction) -> Op<Duet> {
+        match *instr {
+            Instruction::Snd(arg) => {
+                let arg = Slot::from(arg);
+
+                Box::new(move |state| Ok(Step::Emit(DuetEvent::Sent(arg.value(state.regs.slots())))))
+            }
+            Instruction::Rcv(Operand::Reg(reg)) => {
+                let reg = vm::Register::index(reg);
+
+                Box::new(move |state| {
+                    if let Some(val) = state.rx_queue.pop_front() {
+                        state.regs.slots_mut()[reg] = val;
+
+                        Ok(Step::Next)
+                    } else {
+                        Ok(Step::Block(DuetEvent::Blocked))
+                    }
+                })
+            }
+            Instruction::Rcv(Operand::Imm(_)) => {
+                Box::new(|_| Err(Fault::Invalid("immediate value used as a register".to_string())))
+            }
+            _ => compile_arithmetic::<Duet>(instr),
+        }
+    }
+}
+
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Program;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut computer = CompiledMachine::<Sound>::new(input.0.clone(), SoundState::default());
+
+        match computer.run()? {
+            Status::Paused(Some(freq)) => Ok(freq.to_string()),
+            _ => Err(AocError::unsolvable("no sound recovered")),
+        }
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut network = Network::new(input, 2, Topology::Pairwise)?;
+
+        Ok(network.run()?.sent[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Program;
+    use super::Value;
+    use super::Instruction;
+    use super::{Network, Topology};
+    use super::{Duet, DuetState, Sound, SoundState};
+    use aoc_common::compiled::CompiledMachine;
+    use aoc_common::vm::{Machine, Status};
+
+    #[test]
+    fn parse_test() {
+        let input_str = "set a 1\n\
+                         add a 2\n\
+                         mul a a\n\
+                         mod a 5\n\
+                         snd a\n\
+                         set a 0\n\
+                         rcv a\n\
+                         jgz a -1\n\
+                         set a 1\n\
+                         jgz a -2";
+
+        let expected_prog = Program(vec![
+            Instruction::Set(Value::Reg('a'), Value::Imm(1)),
+            Instruction::Add(Value::Reg('a'), Value::Imm(2)),
+            Instruction::Mul(Value::Reg('a'), Value::Reg('a')),
+            Instruction::Mod(Value::Reg('a'), Value::Imm(5)),
+            Instruction::Snd(Value::Reg('a')),
+            Instruction::Set(Value::Reg('a'), Value::Imm(0)),
+            Instruction::Rcv(Value::Reg('a')),
+            Instruction::Jgz(Value::Reg('a'), Value::Imm(-1)),
+            Instruction::Set(Value::Reg('a'), Value::Imm(1)),
+            Instruction::Jgz(Value::Reg('a'), Value::Imm(-2)),
+        ]);
+
+        assert_eq!(expected_prog, input_str.parse().expect("parse error"));
+    }
+
+    #[test]
+    fn eval_test() {
+        let prog = Program(vec![
+            Instruction::Set(Value::Reg('a'), Value::Imm(1)),
+            Instruction::Add(Value::Reg('a'), Value::Imm(2)),
+            Instruction::Mul(Value::Reg('a'), Value::Reg('a')),
+            Instruction::Mod(Value::Reg('a'), Value::Imm(5)),
+            Instruction::Snd(Value::Reg('a')),
+            Instruction::Set(Value::Reg('a'), Value::Imm(0)),
+            Instruction::Rcv(Value::Reg('a')),
+            Instruction::Jgz(Value::Reg('a'), Value::Imm(-1)),
+            Instruction::Set(Value::Reg('a'), Value::Imm(1)),
+            Instruction::Jgz(Value::Reg('a'), Value::Imm(-2)),
+        ]);
+
+        let mut computer = Machine::<Sound>::new(prog.0, SoundState::default());
+
+        assert_eq!(Ok(Status::Paused(Some(4))), computer.run());
+    }
+
+    #[test]
+    fn runner_test() {
+        let prog = Program(vec![
+            Instruction::Snd(Value::Imm(1)),
+            Instruction::Snd(Value::Imm(2)),
+            Instruction::Snd(Value::Reg('p')),
+            Instruction::Rcv(Value::Reg('a')),
+            Instruction::Rcv(Value::Reg('b')),
+            Instruction::Rcv(Value::Reg('c')),
+            Instruction::Rcv(Value::Reg('d')),
+        ]);
+
+        let mut network = Network::new(&prog, 2, Topology::Pairwise).unwrap();
+
+        assert_eq!(Ok(vec![3, 3]), network.run().map(|report| report.sent));
+    }
+
+    #[test]
+    fn compiled_test() {
+        let prog = include_str!("../input.txt").parse::<Program>().expect("parse error");
+
+        let mut computer = Machine::<Sound>::new(prog.0.clone(), SoundState::default());
+        let mut compiled = CompiledMachine::<Sound>::new(prog.0.clone(), SoundState::default());
+
+        assert_eq!(computer.run(), compiled.run());
+        assert_eq!(computer.steps(), compiled.steps());
+
+        let mut computer = Machine::<Duet>::new(prog.0.clone(), DuetState::default());
+        let mut compiled = CompiledMachine::<Duet>::new(prog.0, DuetState::default());
+
+        loop {
+            let status = computer.run();
+            assert_eq!(status, compiled.run());
+
+            if status == Ok(Status::Paused(super::DuetEvent::Blocked)) {
+                break;
+            }
+        }
+
+        assert_eq!(computer.state().regs, compiled.state().regs);
+    }
+}
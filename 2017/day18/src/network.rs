@@ -0,0 +1,473 @@
+//! Networks of duet programs exchanging values through queues.
+//!
+//! Every program has a single incoming queue, `rcv` takes values from it in the order they
+//! arrived. The topology decides which queues the values sent by a program go to.
+
+use std::fmt;
+use std::mem;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use aoc_common::compiled::CompiledMachine;
+use aoc_common::vm::{Status, VmError};
+use aoc_common::{AocError, AocResult};
+
+use super::{Duet, DuetEvent, DuetState, Instruction, Program};
+
+/// Where the values sent by a program go.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Topology {
+    /// To the next program, the last one sends to the first one.
+    Ring,
+    /// Programs 0 and 1, 2 and 3, ... send to each other, like in the puzzle.
+    Pairwise,
+    /// To all the other programs.
+    Broadcast,
+}
+
+impl Topology {
+    /// The programs receiving the values sent by `sender` in a network of `size` programs.
+    pub fn targets(self, sender: usize, size: usize) -> Vec<usize> {
+        match self {
+            Topology::Ring => vec![(sender + 1) % size],
+            Topology::Pairwise => vec![sender ^ 1],
+            Topology::Broadcast => (0..size).filter(|&target| target != sender).collect(),
+        }
+    }
+
+    /// The programs sending values to `target`.
+    pub fn feeders(self, target: usize, size: usize) -> Vec<usize> {
+        (0..size)
+            .filter(|&sender| self.targets(sender, size).contains(&target))
+            .collect()
+    }
+}
+
+/// Counters of the incoming queue of a program.
+#[derive(Debug, PartialEq, Eq, Default, Copy, Clone)]
+pub struct QueueStats {
+    /// Values sent to the queue.
+    pub pushed: usize,
+    /// Values taken from the queue by `rcv`.
+    pub popped: usize,
+    /// Most values waiting in the queue at the same time.
+    pub max_len: usize,
+}
+
+impl QueueStats {
+    fn push(&mut self) {
+        self.pushed += 1;
+        self.max_len = self.max_len.max(self.pushed - self.popped);
+    }
+}
+
+/// A program waiting for a value that never comes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Blocked {
+    pub program: usize,
+    pub pc: usize,
+    /// The register `rcv` would store the value to.
+    pub register: char,
+    /// The programs sending to its queue, all of them halted or blocked as well.
+    pub feeders: Vec<usize>,
+}
+
+impl fmt::Display for Blocked {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "program {} is blocked on `rcv {}` at pc {}, waiting on queue {} fed by {:?}",
+            self.program, self.register, self.pc, self.program, self.feeders
+        )
+    }
+}
+
+/// How a network run ended.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report {
+    /// Number of `snd` instructions executed by each program.
+    pub sent: Vec<usize>,
+    /// Statistics of each program's queue.
+    pub queues: Vec<QueueStats>,
+    /// Programs left waiting, empty if all of them halted.
+    pub blocked: Vec<Blocked>,
+}
+
+impl Report {
+    pub fn is_deadlock(&self) -> bool {
+        !self.blocked.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (program, (sent, queue)) in self.sent.iter().zip(&self.queues).enumerate() {
+            writeln!(
+                f,
+                "program {}: sent {}, queue pushed {} popped {} max length {}",
+                program, sent, queue.pushed, queue.popped, queue.max_len
+            )?;
+        }
+
+        for blocked in &self.blocked {
+            writeln!(f, "{}", blocked)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// What the programs of a threaded run are doing, shared by their threads.
+#[derive(Debug)]
+struct Switchboard {
+    waiting: Vec<bool>,
+    halted: Vec<bool>,
+    queues: Vec<QueueStats>,
+}
+
+impl Switchboard {
+    /// Whether every program is halted or waiting on an empty queue.
+    fn is_stuck(&self) -> bool {
+        (0..self.queues.len()).all(|program| {
+            let queue = &self.queues[program];
+
+            self.halted[program] || (self.waiting[program] && queue.pushed == queue.popped)
+        })
+    }
+
+    /// Wakes up the waiting programs if none of them can ever continue.
+    fn stop_if_stuck(&self, senders: &[Sender<Message>]) {
+        if self.is_stuck() {
+            for (sender, _) in senders.iter().zip(&self.waiting).filter(|&(_, &waiting)| waiting) {
+                let _ = sender.send(Message::Stop);
+            }
+        }
+    }
+}
+
+enum Message {
+    Value(isize),
+    Stop,
+}
+
+/// Everything a program needs to run on its own thread.
+struct Node {
+    id: usize,
+    machine: CompiledMachine<Duet>,
+    targets: Vec<usize>,
+    receiver: Receiver<Message>,
+    senders: Vec<Sender<Message>>,
+    board: Arc<Mutex<Switchboard>>,
+}
+
+impl Node {
+    /// Runs the program until it halts or the whole network is stuck, returns the number of
+    /// values it sent.
+    fn run(&mut self) -> Result<usize, VmError> {
+        let mut sent = 0;
+
+        let result = loop {
+            match self.machine.run() {
+                Ok(Status::Paused(DuetEvent::Sent(val))) => {
+                    sent += 1;
+
+                    // counted first, so a queue never looks empty while a value is on its way
+                    {
+                        let mut board = self.board.lock().unwrap();
+
+                        for &target in &self.targets {
+                            board.queues[target].push();
+                        }
+                    }
+
+                    for &target in &self.targets {
+                        let _ = self.senders[target].send(Message::Value(val));
+                    }
+                }
+                Ok(Status::Paused(DuetEvent::Blocked)) => {
+                    let message = match self.receiver.try_recv() {
+                        Ok(message) => message,
+                        Err(_) => {
+                            {
+                                let mut board = self.board.lock().unwrap();
+                                board.waiting[self.id] = true;
+                                board.stop_if_stuck(&self.senders);
+                            }
+
+                            self.receiver.recv().unwrap_or(Message::Stop)
+                        }
+                    };
+
+                    match message {
+                        Message::Value(val) => {
+                            let mut board = self.board.lock().unwrap();
+                            board.waiting[self.id] = false;
+                            board.queues[self.id].popped += 1;
+
+                            self.machine.state_mut().rx_queue.push_back(val);
+                        }
+                        Message::Stop => break Ok(sent),
+                    }
+                }
+                Ok(_) => break Ok(sent),
+                Err(e) => break Err(e),
+            }
+        };
+
+        let mut board = self.board.lock().unwrap();
+        board.halted[self.id] = true;
+        board.stop_if_stuck(&self.senders);
+
+        result
+    }
+}
+
+/// Duet programs connected by a topology, each of them started with its index in register `p`.
+pub struct Network {
+    topology: Topology,
+    programs: Vec<CompiledMachine<Duet>>,
+}
+
+impl Network {
+    pub fn new(prog: &Program, size: usize, topology: Topology) -> AocResult<Network> {
+        if size == 0 || (topology == Topology::Pairwise && !size.is_multiple_of(2)) {
+            return Err(AocError::runtime(format!(
+                "a {:?} network can not have {} programs",
+                topology, size
+            )));
+        }
+
+        let programs = (0..size)
+            .map(|id| {
+                let mut machine = CompiledMachine::<Duet>::new(prog.0.clone(), DuetState::default());
+                machine.state_mut().regs['p'] = id as isize;
+
+                machine
+            })
+            .collect();
+
+        Ok(Network { topology, programs })
+    }
+
+    pub fn programs(&self) -> &[CompiledMachine<Duet>] {
+        &self.programs
+    }
+
+    fn size(&self) -> usize {
+        self.programs.len()
+    }
+
+    /// Runs the programs one after another on the current thread, until none of them can
+    /// continue.
+    pub fn run(&mut self) -> Result<Report, VmError> {
+        let size = self.size();
+        let mut sent = vec![0; size];
+        let mut queues = vec![QueueStats::default(); size];
+
+        loop {
+            let mut stuck = true;
+
+            for (id, sent) in sent.iter_mut().enumerate() {
+                match self.programs[id].run()? {
+                    Status::Paused(DuetEvent::Sent(val)) => {
+                        *sent += 1;
+                        stuck = false;
+
+                        for target in self.topology.targets(id, size) {
+                            queues[target].push();
+                            self.programs[target].state_mut().rx_queue.push_back(val);
+                        }
+                    }
+                    Status::Running => stuck = false,
+                    Status::Paused(DuetEvent::Blocked) | Status::Halted => {}
+                }
+            }
+
+            if stuck {
+                break;
+            }
+        }
+
+        for (queue, program) in queues.iter_mut().zip(&self.programs) {
+            queue.popped = queue.pushed - program.state().rx_queue.len();
+        }
+
+        Ok(self.report(sent, queues))
+    }
+
+    /// Runs every program on its own thread, passing values through channels.
+    pub fn run_threaded(&mut self) -> Result<Report, VmError> {
+        let size = self.size();
+
+        let board = Arc::new(Mutex::new(Switchboard {
+            waiting: vec![false; size],
+            halted: vec![false; size],
+            queues: vec![QueueStats::default(); size],
+        }));
+
+        let (senders, receivers): (Vec<_>, Vec<_>) = (0..size).map(|_| mpsc::channel()).unzip();
+
+        let handles = mem::take(&mut self.programs)
+            .into_iter()
+            .zip(receivers)
+            .enumerate()
+            .map(|(id, (machine, receiver))| {
+                let mut node = Node {
+                    id,
+                    machine,
+                    targets: self.topology.targets(id, size),
+                    receiver,
+                    senders: senders.clone(),
+                    board: Arc::clone(&board),
+                };
+
+                thread::spawn(move || {
+                    let result = node.run();
+                    (node.machine, result)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut sent = Vec::with_capacity(size);
+        let mut error = None;
+
+        for handle in handles {
+            let (machine, result) = handle.join().expect("program thread panicked");
+
+            match result {
+                Ok(count) => sent.push(count),
+                Err(e) => {
+                    sent.push(0);
+                    error = error.or(Some(e));
+                }
+            }
+
+            self.programs.push(machine);
+        }
+
+        if let Some(e) = error {
+            return Err(e);
+        }
+
+        let queues = board.lock().unwrap().queues.clone();
+
+        Ok(self.report(sent, queues))
+    }
+
+    fn report(&self, sent: Vec<usize>, queues: Vec<QueueStats>) -> Report {
+        let size = self.size();
+
+        let blocked = self
+            .programs
+            .iter()
+            .enumerate()
+            .filter_map(|(program, machine)| match machine.current() {
+                Some(&Instruction::Rcv(arg)) => Some(Blocked {
+                    program,
+                    pc: machine.pc(),
+                    register: arg.register().ok()?,
+                    feeders: self.topology.feeders(program, size),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Report { sent, queues, blocked }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Network, QueueStats, Topology};
+    use Program;
+
+    #[test]
+    fn topology_test() {
+        assert_eq!(vec![0], Topology::Ring.targets(3, 4));
+        assert_eq!(vec![2], Topology::Pairwise.targets(3, 4));
+        assert_eq!(vec![0, 1, 3], Topology::Broadcast.targets(2, 4));
+        assert_eq!(vec![1], Topology::Ring.feeders(2, 4));
+
+        let prog = "snd p".parse::<Program>().unwrap();
+
+        assert!(Network::new(&prog, 3, Topology::Pairwise).is_err());
+        assert!(Network::new(&prog, 3, Topology::Ring).is_ok());
+    }
+
+    #[test]
+    fn ring_test() {
+        let prog = "snd p\nrcv a".parse::<Program>().unwrap();
+
+        for threaded in &[false, true] {
+            let mut network = Network::new(&prog, 3, Topology::Ring).unwrap();
+            let report = if *threaded { network.run_threaded() } else { network.run() }.unwrap();
+
+            assert!(!report.is_deadlock());
+            assert_eq!(vec![1, 1, 1], report.sent);
+
+            let received = network
+                .programs()
+                .iter()
+                .map(|machine| machine.state().regs['a'])
+                .collect::<Vec<isize>>();
+
+            assert_eq!(vec![2, 0, 1], received);
+        }
+    }
+
+    #[test]
+    fn broadcast_test() {
+        let prog = "snd p\nsnd p\nrcv a\nrcv b".parse::<Program>().unwrap();
+
+        for threaded in &[false, true] {
+            let mut network = Network::new(&prog, 3, Topology::Broadcast).unwrap();
+            let report = if *threaded { network.run_threaded() } else { network.run() }.unwrap();
+
+            assert!(!report.is_deadlock());
+
+            for queue in &report.queues {
+                assert_eq!((4, 2), (queue.pushed, queue.popped));
+            }
+
+            // how long the queues get depends on how the threads are scheduled
+            if !threaded {
+                let queue = QueueStats {
+                    pushed: 4,
+                    popped: 2,
+                    max_len: 4,
+                };
+
+                assert_eq!(vec![queue; 3], report.queues);
+            }
+        }
+    }
+
+    #[test]
+    fn deadlock_test() {
+        let prog = "snd p\nrcv a\nrcv b".parse::<Program>().unwrap();
+
+        for threaded in &[false, true] {
+            let mut network = Network::new(&prog, 4, Topology::Ring).unwrap();
+            let report = if *threaded { network.run_threaded() } else { network.run() }.unwrap();
+
+            assert!(report.is_deadlock());
+            assert_eq!(4, report.blocked.len());
+            assert_eq!(
+                "program 0 is blocked on `rcv b` at pc 2, waiting on queue 0 fed by [3]",
+                report.blocked[0].to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn threaded_input_test() {
+        let prog = include_str!("../input.txt").parse::<Program>().unwrap();
+
+        let mut network = Network::new(&prog, 2, Topology::Pairwise).unwrap();
+        let report = network.run_threaded().unwrap();
+
+        assert!(report.is_deadlock());
+        assert_eq!(7112, report.sent[1]);
+    }
+}
@@ -1,11 +1,13 @@
-use std::io;
-use std::io::prelude::*;
+extern crate aoc_common;
 
 use std::fmt;
 use std::fmt::Write;
 
 use std::str::FromStr;
 
+use aoc_common::{AocError, AocResult, Solver};
+
+#[derive(Clone)]
 enum Dir {
     Up,
     Right,
@@ -20,7 +22,8 @@ enum Search {
     Done,
 }
 
-struct Maze {
+#[derive(Clone)]
+pub struct Maze {
     maze: Vec<Vec<char>>,
     curr_pos: (usize, usize),
     curr_dir: Dir,
@@ -206,15 +209,30 @@ impl fmt::Display for Maze {
 }
 
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).expect(
-        "input error",
-    );
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Maze;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing maze"))
+    }
 
-    let mut maze: Maze = input_str.parse().expect("parse error");
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        input
+            .clone()
+            .walk_maze()
+            .map(|(_, letters)| letters)
+            .map_err(|_| AocError::runtime("invalid maze"))
+    }
 
-    println!("Hello, world! {:?}", maze.walk_maze());
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        input
+            .clone()
+            .walk_maze()
+            .map(|(step_cnt, _)| step_cnt.to_string())
+            .map_err(|_| AocError::runtime("invalid maze"))
+    }
 }
 
 #[cfg(test)]
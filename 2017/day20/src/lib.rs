@@ -1,17 +1,15 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 #[macro_use]
 extern crate lazy_static;
 
-use std::io;
-use std::io::prelude::*;
-
 use std::str::FromStr;
 
-
 use regex::Regex;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 lazy_static! {
     static ref PARTICLE_RE: Regex = Regex::new(r"^p=<(?P<pos_x>-?[0-9]+),(?P<pos_y>-?[0-9]+),(?P<pos_z>-?[0-9]+)>, v=<(?P<vel_x>-?[0-9]+),(?P<vel_y>-?[0-9]+),(?P<vel_z>-?[0-9]+)>, a=<(?P<acc_x>-?[0-9]+),(?P<acc_y>-?[0-9]+),(?P<acc_z>-?[0-9]+)>$").unwrap();
 }
@@ -67,8 +65,8 @@ impl FromStr for Particle {
     }
 }
 
-#[derive(Debug)]
-struct Simulation(Vec<Particle>);
+#[derive(Debug, Clone)]
+pub struct Simulation(Vec<Particle>);
 
 impl FromStr for Simulation {
     type Err = ();
@@ -143,39 +141,33 @@ fn dot_product(left: &(f64, f64, f64), right: &(f64, f64, f64)) -> f64 {
 }
 
 fn get_norm(vec: &(f64, f64, f64)) -> f64 {
-    dot_product(&vec, &vec).sqrt()
+    dot_product(vec, vec).sqrt()
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).expect(
-        "input error",
-    );
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Simulation;
 
-    let mut sim: Simulation = input_str.parse().expect("parse error");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing particles"))
+    }
 
-    println!(
-        "The longterm closest particle is: {:?}",
-        sim.get_longterm_closest_particle()
-    );
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.get_longterm_closest_particle().0.to_string())
+    }
 
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut sim = input.clone();
 
-    println!("Running 100000 steps in the simulation");
-    for i in 0..100_000 {
-        if i % 10_000 == 0 {
-            println!("*** {}", i);
+        for _ in 0..100_000 {
+            sim.do_step();
         }
 
-        sim.do_step();
+        Ok(sim.get_num_particles().to_string())
     }
-
-    println!(
-        "There are {} particles left after 100000 steps.",
-        sim.get_num_particles()
-    );
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::Simulation;
@@ -0,0 +1,204 @@
+extern crate aoc_common;
+
+use std::str::FromStr;
+
+use std::collections::HashSet;
+use std::collections::HashMap;
+
+use std::rc::Rc;
+
+use aoc_common::{AocError, AocResult, Grid, Point, Solver};
+
+/// A square of pixels, each of them on or off.
+type Pattern = Grid<bool>;
+
+/// Parses the rows of a pattern separated by `/`, e.g. `.#./..#/###`.
+fn parse_pattern(s: &str) -> Result<Pattern, ()> {
+    let pattern = Grid::parse(&s.replace('/', "\n"), |ch| match ch {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(AocError::parse(format!("Invalid pixel: {:?}", ch))),
+    })
+    .map_err(|_| ())?;
+
+    if pattern.width() != pattern.height() {
+        return Err(());
+    }
+
+    Ok(pattern)
+}
+
+
+struct InputLine(Pattern, Pattern);
+
+impl FromStr for InputLine {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pattern, output) = s.split_once(" => ").ok_or(())?;
+        let (pattern, output) = (parse_pattern(pattern)?, parse_pattern(output)?);
+
+        match (pattern.width(), output.width()) {
+            (2, 3) | (3, 4) => Ok(InputLine(pattern, output)),
+            _ => Err(()),
+        }
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rulebook(HashMap<Pattern, Rc<Pattern>>);
+
+impl FromStr for Rulebook {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed_lines = s.lines()
+            .map(|line| line.parse())
+            .collect::<Result<Vec<InputLine>, ()>>()?;
+
+        let mut out = HashMap::new();
+
+        for parsed_line in parsed_lines.into_iter() {
+
+            let output_pat = Rc::new(parsed_line.1);
+
+            let variants = parsed_line.0.symmetries().into_iter().collect::<HashSet<Pattern>>();
+
+            for pat in variants.into_iter() {
+                if out.insert(pat, output_pat.clone()).is_some() {
+                    return Err(());
+                }
+            }
+        }
+
+        Ok(Rulebook(out))
+    }
+}
+
+
+fn expand(grid: &Pattern, rulebook: &Rulebook) -> Result<Pattern, ()> {
+
+    let curr_size = grid.width();
+
+    let square_size = if curr_size.is_multiple_of(2) {
+        2
+    } else if curr_size.is_multiple_of(3) {
+        3
+    } else {
+        return Err(());
+    };
+
+    let square_count = curr_size / square_size;
+    let new_square_size = square_size + 1;
+    let mut new_grid = Grid::new(square_count * new_square_size, square_count * new_square_size, false);
+
+    for square_y in 0..square_count {
+        for square_x in 0..square_count {
+            let curr_pos = Point::new((square_x * square_size) as isize, (square_y * square_size) as isize);
+            let new_pos = Point::new((square_x * new_square_size) as isize, (square_y * new_square_size) as isize);
+
+            let curr_square = grid.view(curr_pos, square_size, square_size).to_grid();
+
+            let new_square = rulebook.0.get(&curr_square).ok_or(())?;
+
+            new_grid.view_mut(new_pos, new_square_size, new_square_size).paste(new_square);
+        }
+    }
+
+    Ok(new_grid)
+}
+
+fn count_enabled_pixels(grid: &Pattern) -> usize {
+    grid.values().filter(|pixel_state| **pixel_state).count()
+}
+
+fn count_pixels_after(rulebook: &Rulebook, num_iterations: usize) -> AocResult<usize> {
+    let mut grid = parse_pattern(".#./..#/###").map_err(|_| AocError::runtime("grid creation error"))?;
+
+    for _ in 0..num_iterations {
+        grid = expand(&grid, rulebook).map_err(|_| AocError::runtime("grid expansion error"))?;
+    }
+
+    Ok(count_enabled_pixels(&grid))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Rulebook;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing rulebook"))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        count_pixels_after(input, 5).map(|count| count.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        count_pixels_after(input, 18).map(|count| count.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_enabled_pixels, expand, parse_pattern};
+    use super::Rulebook;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    const TEST_STR: &str = "../.# => ##./#../...\n\
+                            .#./..#/### => #..#/..../..../#..#";
+
+    #[test]
+    fn parse_test() {
+        let rulebook: Rulebook = TEST_STR.parse().expect("parse error");
+
+        let mut test_book = Rulebook(HashMap::new());
+
+        let output_1 = Rc::new(parse_pattern("##./#../...").unwrap());
+
+        for pattern_1 in &["../.#", "../#.", "#./..", ".#/.."] {
+            test_book.0.insert(parse_pattern(pattern_1).unwrap(), output_1.clone());
+        }
+
+        let output_2 = Rc::new(parse_pattern("#..#/..../..../#..#").unwrap());
+
+        let patterns_2 = [
+            ".#./..#/###",
+            ".#./#../###",
+            "#../#.#/##.",
+            "..#/#.#/.##",
+            "###/#../.#.",
+            "###/..#/.#.",
+            ".##/#.#/..#",
+            "##./#.#/#..",
+        ];
+
+        for pattern_2 in &patterns_2 {
+            test_book.0.insert(parse_pattern(pattern_2).unwrap(), output_2.clone());
+        }
+
+        assert_eq!(test_book, rulebook);
+
+        assert!("../.# => ##./#../".parse::<Rulebook>().is_err());
+        assert!("../.# => #..#/..../..../#..#".parse::<Rulebook>().is_err());
+    }
+
+    #[test]
+    fn expansion_test() {
+        let rulebook: Rulebook = TEST_STR.parse().expect("parse error");
+
+        let grid = parse_pattern(".#./..#/###").expect("grid creation error");
+
+        let grid = expand(&grid, &rulebook).expect("grid expansion error");
+        assert_eq!(parse_pattern("#..#/..../..../#..#").unwrap(), grid);
+
+
+        let grid = expand(&grid, &rulebook).expect("grid expansion error");
+        assert_eq!(parse_pattern("##.##./#..#../....../##.##./#..#../......").unwrap(), grid);
+
+        assert_eq!(12, count_enabled_pixels(&grid));
+    }
+}
@@ -0,0 +1,226 @@
+extern crate aoc_common;
+extern crate colored;
+
+use colored::*;
+
+use std::str::FromStr;
+
+use std::fmt;
+
+use std::cmp;
+
+use aoc_common::{AocError, AocResult, Direction, Grid, Point, Solver, SparseGrid};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Node {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cluster {
+    nodes: SparseGrid<Node>,
+    curr_loc: Point,
+    curr_dir: Direction,
+    infection_ctr: usize,
+}
+
+impl FromStr for Cluster {
+    type Err = ();
+
+    fn from_str(input_str: &str) -> Result<Cluster, Self::Err> {
+        let grid = Grid::parse(input_str, |ch| match ch {
+            '#' => Ok(Node::Infected),
+            '.' => Ok(Node::Clean),
+            _ => Err(AocError::parse(format!("Invalid node: {:?}", ch))),
+        })
+        .map_err(|_| ())?;
+
+        let s = grid.width();
+
+        if s == 0 || grid.height() != s {
+            return Err(());
+        }
+
+        Ok(Cluster {
+            nodes: SparseGrid::from_grid(&grid, Node::Clean),
+            curr_loc: Point::new((s / 2) as isize, (s / 2) as isize),
+            curr_dir: Direction::Up,
+            infection_ctr: 0,
+        })
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        let (min, max) = self.nodes.bounds().unwrap_or((self.curr_loc, self.curr_loc));
+        let min = Point::new(cmp::min(min.x, self.curr_loc.x), cmp::min(min.y, self.curr_loc.y));
+        let max = Point::new(cmp::max(max.x, self.curr_loc.x), cmp::max(max.y, self.curr_loc.y));
+
+        let rendered = self.nodes.render(min, max, |node| match *node {
+            Node::Clean => '.',
+            Node::Weakened => 'W',
+            Node::Infected => '#',
+            Node::Flagged => 'F',
+        });
+
+        for (row_idx, line) in (min.y..).zip(rendered.lines()) {
+            for (col_idx, out) in (min.x..).zip(line.chars()) {
+                let out = out.to_string();
+
+                let out = if Point::new(col_idx, row_idx) == self.curr_loc {
+                    out.on_red()
+                } else {
+                    out.normal()
+                };
+
+                write!(w, "{}", out)?;
+            }
+
+            writeln!(w)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+impl Cluster {
+    fn do_step(&mut self) {
+        match *self.nodes.get(self.curr_loc) {
+            Node::Infected => {
+                self.curr_dir = self.curr_dir.turn_right();
+                self.nodes.set(self.curr_loc, Node::Clean);
+            }
+            Node::Clean => {
+                self.curr_dir = self.curr_dir.turn_left();
+                self.nodes.set(self.curr_loc, Node::Infected);
+                self.infection_ctr += 1;
+            }
+            state => {
+                panic!("invalid state: {:?}", state);
+            }
+        }
+
+        self.curr_loc += self.curr_dir.offset();
+    }
+
+    fn do_step_b(&mut self) {
+        let node = *self.nodes.get(self.curr_loc);
+
+        let new_node = match node {
+            Node::Clean => Node::Weakened,
+            Node::Weakened => {
+                self.infection_ctr += 1;
+
+                Node::Infected
+            }
+            Node::Infected => Node::Flagged,
+            Node::Flagged => Node::Clean,
+        };
+
+        match node {
+            Node::Clean => {
+                self.curr_dir = self.curr_dir.turn_left();
+            }
+            Node::Weakened => {}
+            Node::Infected => {
+                self.curr_dir = self.curr_dir.turn_right();
+            }
+            Node::Flagged => {
+                self.curr_dir = self.curr_dir.reverse();
+            }
+        }
+
+        self.nodes.set(self.curr_loc, new_node);
+
+        self.curr_loc += self.curr_dir.offset();
+    }
+
+
+    fn get_num_infection_events(&self) -> usize {
+        self.infection_ctr
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Cluster;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing grid"))
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut grid = input.clone();
+
+        for _ in 0..10_000 {
+            grid.do_step();
+        }
+
+        Ok(grid.get_num_infection_events().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut grid = input.clone();
+
+        for _ in 0..10_000_000 {
+            grid.do_step_b();
+        }
+
+        Ok(grid.get_num_infection_events().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cluster;
+    use super::Node;
+    use aoc_common::{Direction, Point, SparseGrid};
+
+    const TEST_INPUT: &str = "..#\n#..\n...\n";
+
+    #[test]
+    fn parse_test() {
+        let grid: Cluster = TEST_INPUT.parse().expect("parsing error");
+
+        let mut infected = SparseGrid::new(Node::Clean);
+        infected.set(Point::new(2, 0), Node::Infected);
+        infected.set(Point::new(0, 1), Node::Infected);
+
+        let expected = Cluster {
+            nodes: infected,
+            curr_loc: Point::new(1, 1),
+            curr_dir: Direction::Up,
+            infection_ctr: 0,
+        };
+
+        assert_eq!(expected, grid);
+    }
+
+
+    #[test]
+    fn infection_count_test() {
+        let mut grid: Cluster = TEST_INPUT.parse().expect("parsing error");
+
+        for _ in 0..10_000 {
+            grid.do_step();
+        }
+
+        assert_eq!(5587, grid.get_num_infection_events());
+    }
+
+    #[test]
+    fn infection_count_b_test() {
+        let mut grid: Cluster = TEST_INPUT.parse().expect("parsing error");
+
+        for _ in 0..10_000_000 {
+            grid.do_step_b();
+        }
+
+        assert_eq!(2511944, grid.get_num_infection_events());
+    }
+}
@@ -0,0 +1,27 @@
+extern crate aoc2017_day23;
+extern crate aoc_common;
+extern crate criterion;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc2017_day23::{Coprocessor, CoprocessorState, Program};
+use aoc_common::compiled::CompiledMachine;
+use aoc_common::vm::Machine;
+
+fn interpreter_vs_compiled(c: &mut Criterion) {
+    let prog = include_str!("../input.txt").parse::<Program>().unwrap();
+
+    let mut group = c.benchmark_group("2017/day23");
+
+    group.bench_function("interpreted", |b| {
+        b.iter(|| Machine::<Coprocessor>::new(prog.instructions().to_vec(), CoprocessorState::default()).run())
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| CompiledMachine::<Coprocessor>::new(prog.instructions().to_vec(), CoprocessorState::default()).run())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, interpreter_vs_compiled);
+criterion_main!(benches);
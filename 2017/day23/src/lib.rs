@@ -0,0 +1,409 @@
+extern crate aoc_common;
+
+pub mod optimizer;
+
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_common::compiled::{Compile, CompiledMachine, Op, Slot};
+use aoc_common::decompile::{Condition, Decompile, Flow};
+use aoc_common::vm::{self, Fault, InstructionSet, Operand, RegisterFile, Step};
+use aoc_common::{parse_lines, AocError, AocResult, Solver};
+
+use optimizer::{AddLoop, CompositeCheck, MulCheck};
+
+pub type Value = Operand<char, isize>;
+
+pub type Registers = RegisterFile<char, isize>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Instruction {
+    Set(Value, Value),
+    Sub(Value, Value),
+    Mul(Value, Value),
+    Jnz(Value, Value),
+    AddLoop(AddLoop),
+    MulCheck(MulCheck),
+    CompositeCheck(CompositeCheck),
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mnemonic, args) = vm::split_instruction(s)?;
+
+        match mnemonic {
+            "set" | "sub" | "mul" | "jnz" => {
+                let [arg_1, arg_2] = vm::operands(mnemonic, &args)?;
+                let (arg_1, arg_2) = (arg_1.parse()?, arg_2.parse()?);
+
+                Ok(match mnemonic {
+                    "set" => Instruction::Set(arg_1, arg_2),
+                    "sub" => Instruction::Sub(arg_1, arg_2),
+                    "mul" => Instruction::Mul(arg_1, arg_2),
+                    _ => Instruction::Jnz(arg_1, arg_2),
+                })
+            }
+            _ => Err(AocError::parse(format!("unknown instruction: {:?}", s))),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Instruction::Set(arg_1, arg_2) => write!(f, "set {} {}", arg_1, arg_2),
+            Instruction::Sub(arg_1, arg_2) => write!(f, "sub {} {}", arg_1, arg_2),
+            Instruction::Mul(arg_1, arg_2) => write!(f, "mul {} {}", arg_1, arg_2),
+            Instruction::Jnz(arg_1, arg_2) => write!(f, "jnz {} {}", arg_1, arg_2),
+            Instruction::AddLoop(ref add_loop) => add_loop.fmt(f),
+            Instruction::MulCheck(ref mul_check) => mul_check.fmt(f),
+            Instruction::CompositeCheck(ref composite_check) => composite_check.fmt(f),
+        }
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Program(Vec<Instruction>);
+
+impl Program {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.0
+    }
+}
+
+impl FromStr for Program {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lines(s).map(Program)
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for instr in &self.0 {
+            writeln!(f, "{}", instr)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CoprocessorState {
+    regs: Registers,
+    mul_count: isize,
+}
+
+/// The coprocessor instructions, plus the macro-instructions generated by the `optimizer`.
+pub struct Coprocessor;
+
+impl InstructionSet for Coprocessor {
+    type Instr = Instruction;
+    type State = CoprocessorState;
+    type Event = ();
+
+    fn execute(instr: &Instruction, state: &mut CoprocessorState) -> Result<Step<()>, Fault> {
+        let regs = &mut state.regs;
+
+        match *instr {
+            Instruction::Set(arg_1, arg_2) => {
+                regs[arg_1.register()?] = arg_2.value(regs);
+            }
+            Instruction::Sub(arg_1, arg_2) => {
+                let reg = arg_1.register()?;
+                regs[reg] = regs[reg].checked_sub(arg_2.value(regs)).ok_or(Fault::Overflow)?;
+            }
+            Instruction::Mul(arg_1, arg_2) => {
+                let reg = arg_1.register()?;
+                regs[reg] = regs[reg].checked_mul(arg_2.value(regs)).ok_or(Fault::Overflow)?;
+
+                state.mul_count += 1;
+            }
+            Instruction::Jnz(arg_1, arg_2) => {
+                if arg_1.value(regs) != 0 {
+                    return Ok(Step::Jump(arg_2.value(regs)));
+                }
+            }
+            Instruction::AddLoop(ref add_loop) => add_loop.execute(state)?,
+            Instruction::MulCheck(ref mul_check) => mul_check.execute(state)?,
+            Instruction::CompositeCheck(ref composite_check) => composite_check.execute(state)?,
+        }
+
+        Ok(Step::Next)
+    }
+}
+
+impl Compile for Coprocessor {
+    fn compile(instr: &Instruction) -> Op<Coprocessor> {
+        match *instr {
+            Instruction::Set(Operand::Reg(reg), arg_2)
+            | Instruction::Sub(Operand::Reg(reg), arg_2)
+            | Instruction::Mul(Operand::Reg(reg), arg_2) => {
+                let (reg, arg_2) = (vm::Register::index(reg), Slot::from(arg_2));
+
+                match *instr {
+                    Instruction::Set(..) => Box::new(move |state| {
+                        let slots = state.regs.slots_mut();
+                        slots[reg] = arg_2.value(slots);
+                        Ok(Step::Next)
+                    }),
+                    Instruction::Sub(..) => Box::new(move |state| {
+                        let slots = state.regs.slots_mut();
+                        slots[reg] = slots[reg].checked_sub(arg_2.value(slots)).ok_or(Fault::Overflow)?;
+                        Ok(Step::Next)
+                    }),
+                    _ => Box::new(move |state| {
+                        let slots = state.regs.slots_mut();
+                        slots[reg] = slots[reg].checked_mul(arg_2.value(slots)).ok_or(Fault::Overflow)?;
+
+                        state.mul_count += 1;
+                        Ok(Step::Next)
+                    }),
+                }
+            }
+            Instruction::Jnz(Operand::Imm(0), _) => Box::new(|_| Ok(Step::Next)),
+            Instruction::Jnz(Operand::Imm(_), Operand::Imm(offset)) => Box::new(move |_| Ok(Step::Jump(offset))),
+            Instruction::Jnz(arg_1, arg_2) => {
+                let (arg_1, arg_2) = (Slot::from(arg_1), Slot::from(arg_2));
+
+                Box::new(move |state| {
+                    let slots = state.regs.slots();
+
+                    Ok(if arg_1.value(slots) != 0 {
+                        Step::Jump(arg_2.value(slots))
+                    } else {
+                        Step::Next
+                    })
+                })
+            }
+            // writes to immediate values and the macro-instructions, nothing to resolve ahead
+            _ => {
+                let instr = instr.clone();
+
+                Box::new(move |state| Coprocessor::execute(&instr, state))
+            }
+        }
+    }
+}
+
+impl Decompile for Coprocessor {
+    fn flow(instr: &Instruction) -> Flow {
+        match *instr {
+            Instruction::Jnz(Operand::Imm(0), _) => Flow::Next,
+            Instruction::Jnz(Operand::Imm(_), Operand::Imm(offset)) => Flow::Jump(offset),
+            Instruction::Jnz(Operand::Reg(reg), Operand::Imm(offset)) => {
+                Flow::Branch(offset, Condition::new(format!("{} != 0", reg), format!("{} == 0", reg)))
+            }
+            Instruction::Jnz(Operand::Imm(_), arg_2) => Flow::Computed(format!("pc + {}", arg_2)),
+            Instruction::Jnz(arg_1, arg_2) => Flow::Computed(format!("pc + {} if {} != 0", arg_2, arg_1)),
+            _ => Flow::Next,
+        }
+    }
+
+    fn statement(instr: &Instruction) -> String {
+        match *instr {
+            Instruction::Jnz(..) => "nop".to_string(),
+            Instruction::Set(Operand::Reg(reg), arg_2) => format!("{} = {}", reg, arg_2),
+            Instruction::Sub(Operand::Reg(reg), Operand::Imm(val)) if val < 0 && val != isize::MIN => {
+                format!("{} += {}", reg, -val)
+            },
+            Instruction::Sub(Operand::Reg(reg), arg_2) => format!("{} -= {}", reg, arg_2),
+            Instruction::Mul(Operand::Reg(reg), arg_2) => format!("{} *= {}", reg, arg_2),
+            ref instr @ Instruction::Set(..) | ref instr @ Instruction::Sub(..) | ref instr @ Instruction::Mul(..) => {
+                format!("invalid {}", instr)
+            }
+            ref instr => instr.to_string(),
+        }
+    }
+}
+
+fn run_coprocessor(prog: &Program, reg_a: isize) -> AocResult<CompiledMachine<Coprocessor>> {
+    let mut computer = CompiledMachine::<Coprocessor>::new(prog.0.clone(), CoprocessorState::default());
+    computer.state_mut().regs['a'] = reg_a;
+
+    computer.run()?;
+
+    Ok(computer)
+}
+
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Program;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let computer = run_coprocessor(&optimizer::optimize(input), 0)?;
+
+        Ok(computer.state().mul_count.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let computer = run_coprocessor(&optimizer::optimize(input), 1)?;
+
+        Ok(computer.state().regs['h'].to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{optimizer, run_coprocessor, Coprocessor, CoprocessorState, Program};
+    use aoc_common::decompile::{self, Cfg, Target};
+    use aoc_common::vm::Machine;
+    use std::collections::HashMap;
+
+    #[test]
+    fn part_a_test() {
+        let input_str = include_str!("../input.txt");
+
+        let program = input_str.parse().expect("parse error");
+
+        let computer = run_coprocessor(&program, 0).expect("invalid program");
+
+        let num_mul = computer.state().mul_count;
+        let regs = computer
+            .state()
+            .regs
+            .iter()
+            .filter(|&(reg, _)| reg <= 'h')
+            .collect::<HashMap<char, isize>>();
+
+        let mut final_reg_state = HashMap::new();
+        final_reg_state.insert('a', 0);
+        final_reg_state.insert('b', 57);
+        final_reg_state.insert('c', 57);
+        final_reg_state.insert('d', 57);
+        final_reg_state.insert('e', 57);
+        final_reg_state.insert('f', 0);
+        final_reg_state.insert('g', 0);
+        final_reg_state.insert('h', 1);
+
+        assert_eq!(3025, num_mul);
+        assert_eq!(final_reg_state, regs);
+    }
+
+    #[test]
+    fn part_a_optimized_test() {
+        let input_str = include_str!("../input.txt");
+
+        let program = optimizer::optimize(&input_str.parse().expect("parse error"));
+
+        let computer = run_coprocessor(&program, 0).expect("invalid program");
+
+        let num_mul = computer.state().mul_count;
+        let regs = computer
+            .state()
+            .regs
+            .iter()
+            .filter(|&(reg, _)| reg <= 'h')
+            .collect::<HashMap<char, isize>>();
+
+        let mut final_reg_state = HashMap::new();
+        final_reg_state.insert('a', 0);
+        final_reg_state.insert('b', 57);
+        final_reg_state.insert('c', 57);
+        final_reg_state.insert('d', 57);
+        final_reg_state.insert('e', 57);
+        final_reg_state.insert('f', 0);
+        final_reg_state.insert('g', 0);
+        final_reg_state.insert('h', 1);
+
+        assert_eq!(3025, num_mul);
+        assert_eq!(final_reg_state, regs);
+    }
+
+    #[test]
+    fn part_b_optimized_test() {
+        let program = optimizer::optimize(&include_str!("../input.txt").parse().expect("parse error"));
+
+        let computer = run_coprocessor(&program, 1).expect("invalid program");
+
+        // every 17th number from 105700 to 122700 that is not a prime
+        let composites = (105_700..=122_700)
+            .step_by(17)
+            .filter(|&b: &isize| (2..).take_while(|d| d * d <= b).any(|d| b % d == 0))
+            .count();
+
+        assert_eq!(composites as isize, computer.state().regs['h']);
+        assert_eq!(122_700, computer.state().regs['b']);
+    }
+
+    #[test]
+    fn compiled_test() {
+        let program: Program = include_str!("../input.txt").parse().expect("parse error");
+
+        for program in &[optimizer::optimize(&program), program] {
+            let mut computer = Machine::<Coprocessor>::new(program.0.clone(), CoprocessorState::default());
+            computer.run().expect("invalid program");
+
+            let compiled = run_coprocessor(program, 0).expect("invalid program");
+
+            assert_eq!(computer.state(), compiled.state());
+            assert_eq!(computer.steps(), compiled.steps());
+        }
+    }
+
+    #[test]
+    fn decompile_test() {
+        let program: Program = include_str!("../input.txt").parse().expect("parse error");
+
+        let expected = "\
+b = 57
+c = b
+if a != 0 {
+    b *= 100
+    b += 100000
+    c = b
+    c += 17000
+}
+loop {
+    f = 1
+    d = 2
+    do {
+        e = 2
+        do {
+            g = d
+            g *= e
+            g -= b
+            if g == 0 {
+                f = 0
+            }
+            e += 1
+            g = e
+            g -= b
+        } while g != 0
+        d += 1
+        g = d
+        g -= b
+    } while g != 0
+    if f == 0 {
+        h += 1
+    }
+    g = b
+    g -= c
+    if g == 0 {
+        break
+    }
+    b += 17
+}
+";
+
+        assert_eq!(expected, decompile::render(&decompile::decompile::<Coprocessor>(program.instructions())));
+
+        let cfg = Cfg::new::<Coprocessor>(program.instructions());
+
+        assert_eq!(14, cfg.blocks.len());
+        assert_eq!((29, 30), (cfg.blocks[12].start, cfg.blocks[12].end));
+        assert_eq!(Target::Exit, cfg.blocks[12].edges[0].target);
+        assert_eq!(Target::Block(3), cfg.blocks[13].edges[0].target);
+    }
+}
@@ -0,0 +1,543 @@
+//! Loop and idiom optimizer for coprocessor programs.
+//!
+//! `optimize` looks for a few loop shapes and replaces each of them with a single
+//! macro-instruction computing the loop's effect in closed form:
+//!
+//! * `AddLoop`, a counting loop that only subtracts loop invariant values from registers
+//!   (multiplication by repeated addition),
+//! * `MulCheck`, the counting loop testing `d * e == b` for every `e` up to `b`,
+//! * `CompositeCheck`, a counting loop around a `MulCheck`, testing whether `b` has a pair of
+//!   factors in the given ranges.
+//!
+//! Every macro-instruction keeps the instructions it replaces and runs them as they are whenever
+//! its closed form does not apply (empty ranges, overflow, ...), so optimized programs behave
+//! exactly like the original ones, `mul` count included. `verify` checks that on actual inputs.
+
+use std::fmt;
+
+use aoc_common::vm::{Fault, InstructionSet, Machine, Operand, Step};
+use aoc_common::{AocError, AocResult};
+
+use super::{Coprocessor, CoprocessorState, Instruction, Program, Registers, Value};
+
+/// Runs a loop body on its own, until control falls off its end.
+fn run_body(body: &[Instruction], state: &mut CoprocessorState) -> Result<(), Fault> {
+    let mut pc = 0;
+
+    while pc < body.len() as isize {
+        if pc < 0 {
+            return Err(Fault::PcOutOfRange(pc));
+        }
+
+        match Coprocessor::execute(&body[pc as usize], state)? {
+            Step::Jump(offset) => pc += offset,
+            _ => pc += 1,
+        }
+    }
+
+    Ok(())
+}
+
+fn distinct(regs: &[char]) -> bool {
+    regs.iter()
+        .enumerate()
+        .all(|(i, reg)| !regs[i + 1..].contains(reg))
+}
+
+/// `do { x -= y; ...; counter += step } while counter != 0`, with `step` being 1 or -1 and every
+/// `y` unchanged by the loop.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AddLoop {
+    pub counter: char,
+    pub step: isize,
+    pub subs: Vec<(char, Value)>,
+    body: Vec<Instruction>,
+}
+
+impl AddLoop {
+    fn find(code: &[Instruction]) -> Option<(usize, Instruction)> {
+        let len = code.iter().take_while(|instr| matches!(instr, Instruction::Sub(..))).count() + 1;
+
+        let counter = match code.get(len - 1)? {
+            Instruction::Jnz(Operand::Reg(counter), Operand::Imm(offset)) if len > 1 && *offset == 1 - len as isize => {
+                *counter
+            }
+            _ => return None,
+        };
+
+        let mut step = None;
+        let mut subs = Vec::new();
+
+        for instr in &code[..len - 1] {
+            match *instr {
+                Instruction::Sub(Operand::Reg(reg), Operand::Imm(val)) if reg == counter && val.abs() == 1 => {
+                    if step.replace(-val).is_some() {
+                        return None;
+                    }
+                }
+                Instruction::Sub(Operand::Reg(reg), val) if reg != counter => subs.push((reg, val)),
+                _ => return None,
+            }
+        }
+
+        let targets = subs.iter().map(|&(reg, _)| reg).chain(Some(counter)).collect::<Vec<char>>();
+        let invariant = subs.iter().all(|&(_, val)| match val {
+            Operand::Reg(reg) => !targets.contains(&reg),
+            Operand::Imm(_) => true,
+        });
+
+        if !distinct(&targets) || !invariant {
+            return None;
+        }
+
+        let add_loop = AddLoop {
+            counter,
+            step: step?,
+            subs,
+            body: code[..len].to_vec(),
+        };
+
+        Some((len, Instruction::AddLoop(add_loop)))
+    }
+
+    fn closed_form(&self, state: &CoprocessorState) -> Option<CoprocessorState> {
+        let count = state.regs[self.counter];
+
+        if count == 0 || count.signum() == self.step.signum() {
+            return None;
+        }
+
+        let iterations = count.checked_abs()?;
+        let mut out = state.clone();
+
+        // Every register is changed by a single `sub`, so its values between the first and the
+        // last iteration can't overflow if the final one doesn't.
+        for &(reg, val) in &self.subs {
+            out.regs[reg] = out.regs[reg].checked_sub(val.value(&state.regs).checked_mul(iterations)?)?;
+        }
+
+        out.regs[self.counter] = 0;
+
+        Some(out)
+    }
+
+    pub(crate) fn execute(&self, state: &mut CoprocessorState) -> Result<(), Fault> {
+        match self.closed_form(state) {
+            Some(out) => {
+                *state = out;
+                Ok(())
+            }
+            None => run_body(&self.body, state),
+        }
+    }
+}
+
+impl fmt::Display for AddLoop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "addloop {} {:+} {{", self.counter, self.step)?;
+
+        for (i, &(reg, val)) in self.subs.iter().enumerate() {
+            write!(f, "{}sub {} {}", if i == 0 { " " } else { "; " }, reg, val)?;
+        }
+
+        write!(f, " }}")
+    }
+}
+
+/// `do { g = d * e - b; if g == 0 { f = 0 } e += 1; g = e - b } while g != 0`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MulCheck {
+    pub d: char,
+    pub e: char,
+    pub b: char,
+    pub f: char,
+    pub g: char,
+    body: Vec<Instruction>,
+}
+
+impl MulCheck {
+    fn find(code: &[Instruction]) -> Option<(usize, Instruction)> {
+        use aoc_common::vm::Operand::{Imm, Reg};
+        use Instruction::{Jnz, Mul, Set, Sub};
+
+        match code.get(..9)? {
+            [Set(Reg(g), Reg(d)), Mul(Reg(g_1), Reg(e)), Sub(Reg(g_2), Reg(b)), Jnz(Reg(g_3), Imm(2)), Set(Reg(f), Imm(0)), Sub(Reg(e_1), Imm(-1)), Set(Reg(g_4), Reg(e_2)), Sub(Reg(g_5), Reg(b_1)), Jnz(Reg(g_6), Imm(-8))]
+                if [g_1, g_2, g_3, g_4, g_5, g_6].iter().all(|reg| *reg == g)
+                    && e_1 == e
+                    && e_2 == e
+                    && b_1 == b
+                    && distinct(&[*d, *e, *b, *f, *g]) =>
+            {
+                let mul_check = MulCheck {
+                    d: *d,
+                    e: *e,
+                    b: *b,
+                    f: *f,
+                    g: *g,
+                    body: code[..9].to_vec(),
+                };
+
+                Some((9, Instruction::MulCheck(mul_check)))
+            }
+            _ => None,
+        }
+    }
+
+    fn closed_form(&self, state: &CoprocessorState) -> Option<CoprocessorState> {
+        let (d, e, b) = (state.regs[self.d], state.regs[self.e], state.regs[self.b]);
+
+        if e >= b {
+            return None;
+        }
+
+        // `d * e - b` is monotonic in `e`, so it fits if it does at both ends of the range.
+        for &e in &[e, b - 1] {
+            d.checked_mul(e)?.checked_sub(b)?;
+        }
+
+        let found = if d == 0 {
+            b == 0
+        } else {
+            b.checked_rem(d)? == 0 && (e..b).contains(&(b / d))
+        };
+
+        let mut out = state.clone();
+
+        if found {
+            out.regs[self.f] = 0;
+        }
+
+        out.regs[self.e] = b;
+        out.regs[self.g] = 0;
+        out.mul_count = out.mul_count.checked_add(b.checked_sub(e)?)?;
+
+        Some(out)
+    }
+
+    pub(crate) fn execute(&self, state: &mut CoprocessorState) -> Result<(), Fault> {
+        match self.closed_form(state) {
+            Some(out) => {
+                *state = out;
+                Ok(())
+            }
+            None => run_body(&self.body, state),
+        }
+    }
+}
+
+impl fmt::Display for MulCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "mulcheck {} {} {} {} {}", self.d, self.e, self.b, self.f, self.g)
+    }
+}
+
+/// `do { e = start; <MulCheck>; d += 1; g = d - b } while g != 0`, i.e. `f = 0` if `b` is a
+/// product of a `d` and an `e` from their ranges.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CompositeCheck {
+    pub d: char,
+    pub e: char,
+    pub start: isize,
+    pub b: char,
+    pub f: char,
+    pub g: char,
+    body: Vec<Instruction>,
+}
+
+impl CompositeCheck {
+    fn find(code: &[Instruction]) -> Option<(usize, Instruction)> {
+        use aoc_common::vm::Operand::{Imm, Reg};
+        use Instruction::{Jnz, Set, Sub};
+
+        match code.get(..6)? {
+            [Set(Reg(e), Imm(start)), Instruction::MulCheck(inner), Sub(Reg(d), Imm(-1)), Set(Reg(g), Reg(d_1)), Sub(Reg(g_1), Reg(b)), Jnz(Reg(g_2), Imm(-5))]
+                if inner.e == *e
+                    && inner.d == *d
+                    && inner.b == *b
+                    && inner.g == *g
+                    && d_1 == d
+                    && g_1 == g
+                    && g_2 == g =>
+            {
+                let composite_check = CompositeCheck {
+                    d: *d,
+                    e: *e,
+                    start: *start,
+                    b: *b,
+                    f: inner.f,
+                    g: *g,
+                    body: code[..6].to_vec(),
+                };
+
+                Some((6, Instruction::CompositeCheck(composite_check)))
+            }
+            _ => None,
+        }
+    }
+
+    fn closed_form(&self, state: &CoprocessorState) -> Option<CoprocessorState> {
+        let (d, e, b) = (state.regs[self.d], self.start, state.regs[self.b]);
+
+        // Positive ranges only, so the largest product is the one of both upper ends.
+        if d < 1 || e < 1 || d >= b || e >= b {
+            return None;
+        }
+
+        (b - 1).checked_mul(b - 1)?;
+
+        let found = (1..)
+            .take_while(|i| i * i <= b)
+            .filter(|i| b % i == 0)
+            .any(|i| {
+                let j = b / i;
+
+                // the loops stop before `b`, so `1 * b` is not a product of them
+                j < b && ((i >= d && j >= e) || (j >= d && i >= e))
+            });
+
+        let mut out = state.clone();
+
+        if found {
+            out.regs[self.f] = 0;
+        }
+
+        out.regs[self.d] = b;
+        out.regs[self.e] = b;
+        out.regs[self.g] = 0;
+        out.mul_count = out.mul_count.checked_add((b - d).checked_mul(b - e)?)?;
+
+        Some(out)
+    }
+
+    pub(crate) fn execute(&self, state: &mut CoprocessorState) -> Result<(), Fault> {
+        match self.closed_form(state) {
+            Some(out) => {
+                *state = out;
+                Ok(())
+            }
+            None => run_body(&self.body, state),
+        }
+    }
+}
+
+impl fmt::Display for CompositeCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "compositecheck {} {} {} {} {} {}",
+            self.d, self.e, self.start, self.b, self.f, self.g
+        )
+    }
+}
+
+type Finder = fn(&[Instruction]) -> Option<(usize, Instruction)>;
+
+/// Replaces `code[start..start + len]` with a single instruction, relocating the jumps around it.
+///
+/// Fails if an instruction outside of the span jumps into the middle of it.
+fn replace(code: &[Instruction], start: usize, len: usize, instr: Instruction) -> Option<Vec<Instruction>> {
+    let (start, end) = (start as isize, (start + len) as isize);
+    let relocate = |pc: isize| if pc >= end { pc - (len as isize - 1) } else { pc };
+
+    let mut instr = Some(instr);
+    let mut out = Vec::with_capacity(code.len() + 1 - len);
+
+    for (pc, old) in (0..).zip(code) {
+        if pc == start {
+            out.extend(instr.take());
+        }
+
+        if pc >= start && pc < end {
+            continue;
+        }
+
+        match *old {
+            Instruction::Jnz(cond, Operand::Imm(offset)) => {
+                let target = pc + offset;
+
+                if target > start && target < end {
+                    return None;
+                }
+
+                out.push(Instruction::Jnz(cond, Operand::Imm(relocate(target) - relocate(pc))));
+            }
+            _ => out.push(old.clone()),
+        }
+    }
+
+    Some(out)
+}
+
+/// Replaces the recognized loops of a program with macro-instructions.
+///
+/// Programs with jumps to computed offsets are returned unchanged, as they can't be relocated.
+pub fn optimize(program: &Program) -> Program {
+    let mut code = program.0.clone();
+
+    if code.iter().any(|instr| matches!(instr, Instruction::Jnz(_, Operand::Reg(_)))) {
+        return Program(code);
+    }
+
+    // Inner loops go first, the composite check is matched around an already replaced `MulCheck`.
+    let finders: [Finder; 3] = [AddLoop::find, MulCheck::find, CompositeCheck::find];
+
+    for find in &finders {
+        let mut pc = 0;
+
+        while pc < code.len() {
+            if let Some((len, instr)) = find(&code[pc..]) {
+                if let Some(replaced) = replace(&code, pc, len, instr) {
+                    code = replaced;
+                }
+            }
+
+            pc += 1;
+        }
+    }
+
+    Program(code)
+}
+
+/// Number of steps taken by a program and by its optimized version.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Verification {
+    pub original_steps: u64,
+    pub optimized_steps: u64,
+}
+
+/// Runs a program and its optimized version side by side, from the same registers, and checks
+/// they end up with identical registers and `mul` counts (or fail the same way).
+///
+/// Meant for small inputs, as the original program has to halt within `step_limit` steps. A
+/// macro-instruction is a single step however long its fallback loop runs, so the optimized
+/// program is not guaranteed to stop where the original one hits the limit.
+pub fn verify(program: &Program, regs: &Registers, step_limit: u64) -> AocResult<Verification> {
+    let start = |code: Vec<Instruction>| {
+        let state = CoprocessorState {
+            regs: regs.clone(),
+            mul_count: 0,
+        };
+
+        Machine::<Coprocessor>::new(code, state).with_step_limit(step_limit)
+    };
+
+    let mut original = start(program.0.clone());
+    let mut optimized = start(optimize(program).0);
+
+    let original_result = original.run().map_err(|e| e.fault);
+    let optimized_result = optimized.run().map_err(|e| e.fault);
+
+    if let Err(Fault::StepLimit(limit)) = original_result {
+        return Err(AocError::runtime(format!(
+            "the original program did not halt within {} steps",
+            limit
+        )));
+    }
+
+    if original_result != optimized_result {
+        return Err(AocError::runtime(format!(
+            "the original program ended with {:?}, the optimized one with {:?}",
+            original_result, optimized_result
+        )));
+    }
+
+    if original.state() != optimized.state() {
+        return Err(AocError::runtime(format!(
+            "the original program ended in {:?}, the optimized one in {:?}",
+            original.state(),
+            optimized.state()
+        )));
+    }
+
+    Ok(Verification {
+        original_steps: original.steps(),
+        optimized_steps: optimized.steps(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{optimize, verify};
+    use {Instruction, Program, Registers};
+
+    const STEP_LIMIT: u64 = 1_000_000;
+
+    #[test]
+    fn optimize_input_test() {
+        let program = include_str!("../input.txt").parse::<Program>().expect("parse error");
+
+        let optimized = optimize(&program);
+
+        assert_eq!(19, optimized.0.len());
+        assert_eq!("compositecheck d e 2 b f g", optimized.0[10].to_string());
+        assert_eq!("jnz 1 -10", optimized.0[18].to_string());
+    }
+
+    #[test]
+    fn verify_input_test() {
+        let source = include_str!("../input.txt");
+
+        for b in (3..40).chain(Some(57)) {
+            let program = source
+                .replacen("set b 57", &format!("set b {}", b), 1)
+                .parse::<Program>()
+                .expect("parse error");
+
+            let verification = verify(&program, &Registers::new(), STEP_LIMIT).expect("verification failed");
+
+            assert!(verification.optimized_steps < verification.original_steps);
+        }
+    }
+
+    #[test]
+    fn composite_check_one_test() {
+        // `1 * 7` is not a product of the loops, which stop before `b`, so 7 stays prime.
+        let program = "set b 7\nset f 1\nset d 1\nset e 1\nset g d\nmul g e\nsub g b\njnz g 2\nset f 0\nsub e -1\nset g e\nsub g b\njnz g -8\nsub d -1\nset g d\nsub g b\njnz g -13"
+            .parse::<Program>()
+            .expect("parse error");
+
+        assert_eq!("compositecheck d e 1 b f g", optimize(&program).0[3].to_string());
+
+        verify(&program, &Registers::new(), STEP_LIMIT).expect("verification failed");
+    }
+
+    #[test]
+    fn add_loop_test() {
+        let program = "set c 5\nset k 4\nsub x -3\nsub y k\nsub c 1\njnz c -3\nset z x"
+            .parse::<Program>()
+            .expect("parse error");
+
+        let optimized = optimize(&program);
+
+        assert_eq!(4, optimized.0.len());
+        assert_eq!("addloop c -1 { sub x -3; sub y k }", optimized.0[2].to_string());
+
+        let verification = verify(&program, &Registers::new(), STEP_LIMIT).expect("verification failed");
+        assert_eq!(4, verification.optimized_steps);
+
+        // `k` changes inside the loop
+        let program = "sub x k\nsub k 1\njnz k -2".parse::<Program>().expect("parse error");
+        assert_eq!(program, optimize(&program));
+    }
+
+    #[test]
+    fn fallback_test() {
+        // The closed forms give up on overflows, the original loops then fail at the same point.
+        let programs = [
+            "set c 5\nset x 9223372036854775800\nsub x -3\nsub c 1\njnz c -2",
+            "set b 3\nset d 4611686018427387904\nset e 1\nset g d\nmul g e\nsub g b\njnz g 2\nset f 0\nsub e -1\nset g e\nsub g b\njnz g -8",
+            "set b 10\nset d 4611686018427387904\nset e 10\nset g d\nmul g e\nsub g b\njnz g 2\nset f 0\nsub e -1\nset g e\nsub g b\njnz g -8",
+        ];
+
+        for source in &programs {
+            let program = source.parse::<Program>().expect("parse error");
+
+            assert!(matches!(
+                optimize(&program).0.last(),
+                Some(Instruction::AddLoop(_)) | Some(Instruction::MulCheck(_))
+            ));
+
+            verify(&program, &Registers::new(), STEP_LIMIT).expect("verification failed");
+        }
+    }
+}
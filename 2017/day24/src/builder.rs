@@ -0,0 +1,277 @@
+//! Finds the best bridges for any scoring that adds up a value per component.
+//!
+//! How well a bridge can still be extended depends only on its open port and on the components it
+//! has used, so the best extensions of each such state are computed once. That needs the score of a
+//! bridge to be the sum of the scores of its components, which every `Scoring` is.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::ops::Add;
+use std::rc::Rc;
+
+use aoc_common::{AocError, AocResult};
+
+use {Bridge, Component, Components};
+
+/// How good a bridge is, as the sum of a score for each of its components. Higher is better.
+pub trait Scoring {
+    type Score: Copy + Ord + Default + Add<Output = Self::Score>;
+
+    fn score(&self, component: &Component) -> Self::Score;
+}
+
+/// Any function scoring a single component is a scoring.
+impl<F, T> Scoring for F
+where
+    F: Fn(&Component) -> T,
+    T: Copy + Ord + Default + Add<Output = T>,
+{
+    type Score = T;
+
+    fn score(&self, component: &Component) -> T {
+        self(component)
+    }
+}
+
+/// Two scores, compared by the first one and then by the second one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Then<A, B>(pub A, pub B);
+
+impl<A: Add<Output = A>, B: Add<Output = B>> Add for Then<A, B> {
+    type Output = Then<A, B>;
+
+    fn add(self, other: Then<A, B>) -> Then<A, B> {
+        Then(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+/// The sum of the ports of all the components.
+pub struct Strength;
+
+impl Scoring for Strength {
+    type Score = usize;
+
+    fn score(&self, component: &Component) -> usize {
+        component.get_strength()
+    }
+}
+
+/// The number of components.
+pub struct Count;
+
+impl Scoring for Count {
+    type Score = usize;
+
+    fn score(&self, _component: &Component) -> usize {
+        1
+    }
+}
+
+/// The longest bridges first, the strongest of them first.
+pub struct LengthThenStrength;
+
+impl Scoring for LengthThenStrength {
+    type Score = Then<usize, usize>;
+
+    fn score(&self, component: &Component) -> Then<usize, usize> {
+        Then(1, component.get_strength())
+    }
+}
+
+/// The rest of a bridge, as indices of the components, shared between the bridges continuing it.
+struct Chain {
+    component: usize,
+    rest: Option<Rc<Chain>>,
+}
+
+/// The best ways to continue a bridge, with their scores, best first.
+type Ranking<T> = Rc<Vec<(T, Option<Rc<Chain>>)>>;
+
+pub struct BridgeBuilder<S: Scoring> {
+    /// Every component once for each time it is available, equal ones next to each other.
+    components: Vec<Component>,
+    scoring: S,
+    keep: usize,
+    memo: HashMap<(usize, u64), Ranking<S::Score>>,
+}
+
+impl<S: Scoring> BridgeBuilder<S> {
+    /// Fails with more than 64 components, the bits of the memo keys.
+    pub fn new(components: &Components, scoring: S) -> AocResult<BridgeBuilder<S>> {
+        let mut all = components
+            .0
+            .iter()
+            .flat_map(|(&component, &count)| (0..count).map(move |_| component))
+            .collect::<Vec<Component>>();
+
+        if all.len() > 64 {
+            return Err(AocError::runtime(format!("{} components are too many, 64 at most", all.len())));
+        }
+
+        all.sort_by_key(|component| (component.0, component.1));
+
+        Ok(BridgeBuilder {
+            components: all,
+            scoring,
+            keep: 1,
+            memo: HashMap::new(),
+        })
+    }
+
+    /// The best ways to continue a bridge ending in the port, without the used components. The
+    /// bridge can also stay as it is.
+    fn rank(&mut self, port: usize, used: u64) -> Ranking<S::Score> {
+        if let Some(ranking) = self.memo.get(&(port, used)) {
+            return ranking.clone();
+        }
+
+        let mut ranking = vec![(S::Score::default(), None)];
+
+        for idx in 0..self.components.len() {
+            let component = self.components[idx];
+
+            // of equal components, the first unused one is taken
+            let taken_before = idx > 0 && self.components[idx - 1] == component && used & 1 << (idx - 1) == 0;
+
+            if used & 1 << idx != 0 || taken_before {
+                continue;
+            }
+
+            if let Some(other_port) = component.is_compatible(port) {
+                let score = self.scoring.score(&component);
+
+                for (rest_score, rest) in self.rank(other_port, used | 1 << idx).iter() {
+                    let chain = Chain {
+                        component: idx,
+                        rest: rest.clone(),
+                    };
+
+                    ranking.push((score + *rest_score, Some(Rc::new(chain))));
+                }
+            }
+        }
+
+        ranking.sort_by_key(|&(score, _)| Reverse(score));
+        ranking.truncate(self.keep);
+
+        let ranking = Rc::new(ranking);
+        self.memo.insert((port, used), ranking.clone());
+
+        ranking
+    }
+
+    fn bridge(&self, chain: &Chain) -> Bridge {
+        let mut bridge = Bridge::new();
+        let mut link = Some(chain);
+
+        while let Some(chain) = link {
+            let component = self.components[chain.component];
+            let other_port = component.is_compatible(bridge.last_port).unwrap();
+
+            bridge = bridge.extend(component, other_port);
+            link = chain.rest.as_deref();
+        }
+
+        bridge
+    }
+
+    /// The `k` best bridges starting at the port 0, best first, with their scores. Bridges with
+    /// equal scores come in no particular order.
+    pub fn top(&mut self, k: usize) -> Vec<(S::Score, Bridge)> {
+        // the empty bridge can take one of the places
+        if self.keep != k + 1 {
+            self.keep = k + 1;
+            self.memo.clear();
+        }
+
+        let ranking = self.rank(0, 0);
+
+        ranking
+            .iter()
+            .filter_map(|(score, chain)| chain.as_ref().map(|chain| (*score, self.bridge(chain))))
+            .take(k)
+            .collect()
+    }
+
+    pub fn best(&mut self) -> Option<(S::Score, Bridge)> {
+        self.top(1).pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BridgeBuilder, Count, LengthThenStrength, Strength, Then};
+    use {Component, Components};
+
+    const TEST_STR: &str = "0/2\n2/2\n2/3\n3/4\n3/5\n0/1\n10/1\n9/10\n";
+
+    fn rendered<T>(bridges: &[(T, ::Bridge)]) -> Vec<String> {
+        bridges.iter().map(|(_, bridge)| bridge.to_string()).collect()
+    }
+
+    #[test]
+    fn top_test() {
+        let components: Components = TEST_STR.parse().unwrap();
+
+        let mut strongest = BridgeBuilder::new(&components, Strength).unwrap();
+        let top = strongest.top(3);
+        assert_eq!(vec![31, 19, 18], top.iter().map(|&(score, _)| score).collect::<Vec<usize>>());
+        assert_eq!(vec!["0/1--10/1--9/10", "0/2--2/2--2/3--3/5"], &rendered(&top)[..2]);
+
+        let mut longest = BridgeBuilder::new(&components, LengthThenStrength).unwrap();
+        let (score, bridge) = longest.best().unwrap();
+        assert_eq!(Then(4, 19), score);
+        assert_eq!("0/2--2/2--2/3--3/5", bridge.to_string());
+
+        // all eleven bridges, the longest of them first
+        let mut counted = BridgeBuilder::new(&components, Count).unwrap();
+        let all = counted.top(20);
+        assert_eq!(11, all.len());
+        assert_eq!(vec![4, 4], all.iter().take(2).map(|&(score, _)| score).collect::<Vec<usize>>());
+        assert_eq!(1, all[10].0);
+    }
+
+    #[test]
+    fn custom_scoring_test() {
+        let components: Components = "0/1\n1/1\n1/1\n1/5\n0/5\n".parse().unwrap();
+
+        // the weakest bridge, a single component
+        let mut weakest =
+            BridgeBuilder::new(&components, |component: &Component| -(component.get_strength() as isize)).unwrap();
+        assert_eq!((-1, "0/1".to_string()), weakest.best().map(|(score, bridge)| (score, bridge.to_string())).unwrap());
+
+        // swapping the two equal components does not make another bridge
+        let mut longest = BridgeBuilder::new(&components, Count).unwrap();
+        let mut full = longest
+            .top(10)
+            .into_iter()
+            .filter(|&(count, _)| count == 5)
+            .map(|(_, bridge)| bridge.to_string())
+            .collect::<Vec<String>>();
+        full.sort();
+        assert_eq!(vec!["0/1--1/1--1/1--1/5--0/5", "0/5--1/5--1/1--1/1--0/1"], full);
+    }
+
+    #[test]
+    fn memo_test() {
+        // 0/1--1/2--2/1 and 0/1--2/1--1/2 both end in the port 1 with the same components used, so
+        // the way on to 1/3 is ranked once for the two of them
+        let components: Components = "0/1\n1/2\n2/1\n1/3\n".parse().unwrap();
+
+        let mut builder = BridgeBuilder::new(&components, Count).unwrap();
+        let all = builder.top(20);
+        assert_eq!(8, all.len());
+
+        // without the memo, the empty bridge and each of the eight would be ranked
+        assert_eq!(7, builder.memo.len());
+    }
+
+    #[test]
+    fn too_many_test() {
+        let components: Components = "0/1\n".repeat(65).parse().unwrap();
+        assert!(BridgeBuilder::new(&components, Count).is_err());
+
+        let components: Components = "0/1\n".repeat(64).parse().unwrap();
+        assert!(BridgeBuilder::new(&components, Count).is_ok());
+    }
+}
@@ -1,13 +1,9 @@
-
+extern crate aoc_common;
 extern crate regex;
 
 #[macro_use]
 extern crate lazy_static;
 
-
-use std::io;
-use std::io::prelude::*;
-
 use std::collections::HashMap;
 
 use std::str::FromStr;
@@ -16,13 +12,18 @@ use std::fmt;
 
 use regex::Regex;
 
+use aoc_common::{AocError, AocResult, Solver};
+
+use builder::{BridgeBuilder, LengthThenStrength, Strength};
+
+pub mod builder;
 
 lazy_static! {
     static ref COMPONENT_RE: Regex = Regex::new(r"^([0-9]+)/([0-9]+)$").unwrap();
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-struct Component(usize, usize);
+pub struct Component(usize, usize);
 
 
 impl FromStr for Component {
@@ -39,7 +40,8 @@ impl FromStr for Component {
 }
 
 impl Component {
-    fn is_compatible(&self, port_val: usize) -> Option<usize> {
+    /// The other port of the component, if it connects to the port.
+    pub fn is_compatible(&self, port_val: usize) -> Option<usize> {
         if self.0 == port_val {
             Some(self.1)
         } else if self.1 == port_val {
@@ -49,7 +51,7 @@ impl Component {
         }
     }
 
-    fn get_strength(&self) -> usize {
+    pub fn get_strength(&self) -> usize {
         self.0 + self.1
     }
 }
@@ -61,10 +63,8 @@ impl fmt::Display for Component {
 }
 
 
-
-
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Components(HashMap<Component, usize>);
+pub struct Components(HashMap<Component, usize>);
 
 impl Components {
     fn new() -> Components {
@@ -74,18 +74,6 @@ impl Components {
     fn insert(&mut self, component: Component) {
         *self.0.entry(component).or_insert(0) += 1;
     }
-
-    fn remove(&mut self, component: &Component) {
-        if let Some(comp_cnt) = self.0.remove(component) {
-            if comp_cnt > 1 {
-                self.0.insert(*component, comp_cnt - 1);
-            }
-        }
-    }
-
-    fn iter(&self) -> std::collections::hash_map::Keys<Component, usize> {
-        self.0.keys()
-    }
 }
 
 impl FromStr for Components {
@@ -105,7 +93,7 @@ impl FromStr for Components {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-struct Bridge {
+pub struct Bridge {
     components: Vec<Component>,
     last_port: usize,
 }
@@ -118,44 +106,12 @@ impl Bridge {
         }
     }
 
-    fn expand_bridge(&self, available_comps: &Components) -> Vec<Bridge> {
-        let mut out = Vec::new();
-
-        for (valid_comp, other_port) in
-            available_comps.iter().filter_map(
-                |comp| if let Some(other_port) =
-                    comp.is_compatible(self.last_port)
-                {
-                    Some((comp, other_port))
-                } else {
-                    None
-                },
-            )
-        {
-            let mut new_comps = available_comps.clone();
-            new_comps.remove(valid_comp);
-
-            let mut new_bridge = self.clone();
-            new_bridge.components.push(*valid_comp);
-            new_bridge.last_port = other_port;
-
-            out.extend(new_bridge.expand_bridge(&new_comps));
-            out.push(new_bridge);
-        }
-
-        out
-    }
+    fn extend(&self, component: Component, other_port: usize) -> Bridge {
+        let mut new_bridge = self.clone();
+        new_bridge.components.push(component);
+        new_bridge.last_port = other_port;
 
-    fn get_strength(&self) -> usize {
-        self.components.iter().map(|comp| comp.get_strength()).sum()
-    }
-
-    fn len(&self) -> usize {
-        self.components.len()
-    }
-
-    fn get_valid_bridges(components: &Components) -> Vec<Bridge> {
-        Bridge::new().expand_bridge(components)
+        new_bridge
     }
 }
 
@@ -176,58 +132,36 @@ impl fmt::Display for Bridge {
 }
 
 
-fn get_strongest_bridge(bridges: &Vec<Bridge>) -> Option<(&Bridge, usize)> {
-    bridges.iter().map(|b| (b, b.get_strength())).max_by(
-        |&(_, s_1),
-         &(_, s_2)| {
-            s_1.cmp(&s_2)
-        },
-    )
-}
-
-fn get_strongest_longest_bridge(bridges: &Vec<Bridge>) -> Option<(&Bridge, usize)> {
-    bridges
-        .iter()
-        .map(|b| (b, b.len(), b.get_strength()))
-        .max_by(|&(_, l_1, s_1), &(_, l_2, s_2)| {
-            l_1.cmp(&l_2).then(s_1.cmp(&s_2))
-        })
-        .map(|(b, _len, strength)| (b, strength))
-}
-
-
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let components = input_str.parse().expect("parse error");
+impl Solver for Solution {
+    type Input = Components;
 
-    let bridges = Bridge::get_valid_bridges(&components);
-
-    let (strongest_bridge, max_strength) = get_strongest_bridge(&bridges).expect("no bridges");
-
-    println!("The bridge with the maximum score is:");
-    println!("{}", strongest_bridge);
-    println!("Bridge score: {}", max_strength);
-
-    println!();
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse().map_err(|_| AocError::parse("error parsing components"))
+    }
 
-    let (longest_bridge, longest_str) = get_strongest_longest_bridge(&bridges).expect("no bridges");
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        BridgeBuilder::new(input, Strength)?
+            .best()
+            .map(|(strength, _)| strength.to_string())
+            .ok_or_else(|| AocError::unsolvable("no bridges"))
+    }
 
-    println!("The longest possible bridge with the maximum score is:");
-    println!("{}", longest_bridge);
-    println!("Bridge score: {}", longest_str);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        BridgeBuilder::new(input, LengthThenStrength)?
+            .best()
+            .map(|(score, _)| score.1.to_string())
+            .ok_or_else(|| AocError::unsolvable("no bridges"))
+    }
 }
 
-
-
 #[cfg(test)]
 mod tests {
     use super::Component;
     use super::Components;
     use super::Bridge;
-    use super::get_strongest_bridge;
-    use super::get_strongest_longest_bridge;
+    use super::builder::{BridgeBuilder, Count, LengthThenStrength, Strength};
 
     use std::collections::HashSet;
 
@@ -336,8 +270,8 @@ mod tests {
         expected_bridges.insert(b11);
 
 
-        let bridge_vec = Bridge::get_valid_bridges(&components);
-        let bridge_hash = bridge_vec.into_iter().collect::<HashSet<Bridge>>();
+        let mut builder = BridgeBuilder::new(&components, Count).unwrap();
+        let bridge_hash = builder.top(20).into_iter().map(|(_, bridge)| bridge).collect::<HashSet<Bridge>>();
 
         assert_eq!(expected_bridges, bridge_hash);
     }
@@ -345,7 +279,6 @@ mod tests {
     #[test]
     fn strongest_bridge_test() {
         let components: Components = TEST_STR.parse().expect("parse error");
-        let bridges = Bridge::get_valid_bridges(&components);
 
         let mut expected_max_bridge = Bridge::new();
         expected_max_bridge.components.push(Component(0, 1));
@@ -354,16 +287,15 @@ mod tests {
         expected_max_bridge.last_port = 9;
 
 
-        let (max_bridge, strength) = get_strongest_bridge(&bridges).expect("no bridges");
+        let (strength, max_bridge) = BridgeBuilder::new(&components, Strength).unwrap().best().expect("no bridges");
 
-        assert_eq!(&expected_max_bridge, max_bridge);
+        assert_eq!(expected_max_bridge, max_bridge);
         assert_eq!(31, strength);
     }
 
     #[test]
     fn longest_strongest_bridge_test() {
         let components: Components = TEST_STR.parse().expect("parse error");
-        let bridges = Bridge::get_valid_bridges(&components);
 
         let mut expected_max_bridge = Bridge::new();
         expected_max_bridge.components.push(Component(0, 2));
@@ -373,9 +305,11 @@ mod tests {
         expected_max_bridge.last_port = 5;
 
 
-        let (max_bridge, strength) = get_strongest_longest_bridge(&bridges).expect("no bridges");
+        let (score, max_bridge) =
+            BridgeBuilder::new(&components, LengthThenStrength).unwrap().best().expect("no bridges");
+        let strength = score.1;
 
-        assert_eq!(&expected_max_bridge, max_bridge);
+        assert_eq!(expected_max_bridge, max_bridge);
         assert_eq!(19, strength);
     }
 }
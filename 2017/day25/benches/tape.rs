@@ -0,0 +1,53 @@
+extern crate aoc2017_day25;
+extern crate criterion;
+
+use std::collections::HashSet;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc2017_day25::blueprint::Blueprint;
+use aoc2017_day25::macro_step::MacroMachine;
+use aoc2017_day25::tape::BitTape;
+
+const STEPS: u64 = 1_000_000;
+
+fn hash_set_vs_bit_tape(c: &mut Criterion) {
+    let blueprint = include_str!("../input.txt").parse::<Blueprint>().unwrap();
+
+    let mut group = c.benchmark_group("2017/day25");
+
+    group.bench_function("hash set tape", |b| {
+        b.iter(|| {
+            let mut machine = blueprint.machine_on::<HashSet<isize>>();
+
+            for _ in 0..STEPS {
+                machine.do_step();
+            }
+
+            machine.get_checksum()
+        })
+    });
+    group.bench_function("bit tape", |b| {
+        b.iter(|| {
+            let mut machine = blueprint.machine_on::<BitTape>();
+
+            for _ in 0..STEPS {
+                machine.do_step();
+            }
+
+            machine.get_checksum()
+        })
+    });
+    group.bench_function("macro steps", |b| {
+        b.iter(|| {
+            let mut machine = MacroMachine::new(&blueprint);
+            machine.run(STEPS);
+            machine.get_checksum()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, hash_set_vs_bit_tape);
+criterion_main!(benches);
@@ -0,0 +1,356 @@
+//! Turing machine blueprints, written like the puzzle input:
+//!
+//! ```text
+//! Begin in state A.
+//! Perform a diagnostic checksum after 6 steps.
+//!
+//! In state A:
+//!   If the current value is 0:
+//!     - Write the value 1.
+//!     - Move one slot to the right.
+//!     - Continue with state B.
+//!   If the current value is 1:
+//!     ...
+//! ```
+//!
+//! Every state needs a rule for both values, and every state a rule continues with has to be
+//! described somewhere in the blueprint. Indentation does not matter.
+
+use std::str::FromStr;
+
+use aoc_common::AocError;
+
+use std::collections::HashSet;
+
+use tape::Tape;
+use {Machine, MachineRegs, State};
+
+/// What a state does for one value under the cursor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rule {
+    pub write: bool,
+    /// -1 moves the cursor to the left, 1 to the right.
+    pub shift: isize,
+    pub next: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Blueprint {
+    names: Vec<String>,
+    /// The rules of each state, for the values 0 and 1.
+    rules: Vec<[Rule; 2]>,
+    start: usize,
+    steps: usize,
+}
+
+impl Blueprint {
+    /// The number of steps after which the checksum is taken.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn name(&self, state: usize) -> &str {
+        &self.names[state]
+    }
+
+    pub fn rule(&self, state: usize, value: bool) -> Rule {
+        self.rules[state][value as usize]
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// A machine with an empty tape, in the starting state of the blueprint.
+    pub fn machine(&self) -> Machine<TableState<'_>> {
+        self.machine_on::<HashSet<isize>>()
+    }
+
+    /// A machine in the starting state of the blueprint, with an empty tape of the given kind.
+    pub fn machine_on<T: Tape>(&self) -> Machine<TableState<'_>, T> {
+        Machine::new(TableState {
+            blueprint: self,
+            state: self.start,
+        })
+    }
+}
+
+/// A state of a machine built from a blueprint, which looks its rules up in the blueprint's table.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TableState<'a> {
+    blueprint: &'a Blueprint,
+    state: usize,
+}
+
+impl<'a> TableState<'a> {
+    pub fn index(&self) -> usize {
+        self.state
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.blueprint.name(self.state)
+    }
+}
+
+impl<'a, T: Tape> State<T> for TableState<'a> {
+    fn run_state_change(&self, regs: &mut MachineRegs<T>) -> TableState<'a> {
+        let rule = self.blueprint.rule(self.state, regs.tape.get(regs.cursor));
+
+        regs.tape.set(regs.cursor, rule.write);
+        regs.cursor += rule.shift;
+
+        TableState {
+            blueprint: self.blueprint,
+            state: rule.next,
+        }
+    }
+}
+
+/// A rule as it is being read, with the line it starts on.
+#[derive(Default)]
+struct PartialRule {
+    line: usize,
+    write: Option<bool>,
+    shift: Option<isize>,
+    /// The name of the next state, with the line it is on.
+    next: Option<(String, usize)>,
+}
+
+/// The middle of `line`, if it starts with `prefix` and ends with `suffix`.
+fn sentence<'l>(line: &'l str, prefix: &str, suffix: &str) -> Option<&'l str> {
+    line.strip_prefix(prefix)?.strip_suffix(suffix)
+}
+
+fn parse_value(value: &str) -> Option<bool> {
+    match value {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut start = None;
+        let mut steps = None;
+
+        let mut names: Vec<String> = Vec::new();
+        let mut header_lines = Vec::new();
+        let mut partial_rules: Vec<[Option<PartialRule>; 2]> = Vec::new();
+        // the value of the rule being read, in the last state
+        let mut value = None;
+
+        for (idx, full_line) in s.lines().enumerate() {
+            let line = full_line.trim();
+            let error = |message: String| {
+                AocError::parse_at(idx + 1, full_line.len() - full_line.trim_start().len() + 1, message)
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = sentence(line, "Begin in state ", ".") {
+                start = Some((name.to_string(), idx + 1));
+            } else if let Some(count) = sentence(line, "Perform a diagnostic checksum after ", " steps.") {
+                steps = Some(
+                    count
+                        .parse::<usize>()
+                        .map_err(|e| error(format!("Invalid step count {:?}: {}", count, e)))?,
+                );
+            } else if let Some(name) = sentence(line, "In state ", ":") {
+                if names.iter().any(|known| known == name) {
+                    return Err(error(format!("State {} is described twice", name)));
+                }
+
+                names.push(name.to_string());
+                header_lines.push(idx + 1);
+                partial_rules.push([None, None]);
+                value = None;
+            } else if let Some(current) = sentence(line, "If the current value is ", ":") {
+                let current = parse_value(current).ok_or_else(|| error(format!("Invalid value: {:?}", current)))?;
+                let rules = partial_rules
+                    .last_mut()
+                    .ok_or_else(|| error("A rule outside of any state".to_string()))?;
+
+                if rules[current as usize].is_some() {
+                    return Err(error(format!(
+                        "State {} has two rules for the value {}",
+                        names.last().unwrap(),
+                        current as usize
+                    )));
+                }
+
+                rules[current as usize] = Some(PartialRule {
+                    line: idx + 1,
+                    ..PartialRule::default()
+                });
+                value = Some(current as usize);
+            } else if let Some(action) = line.strip_prefix("- ") {
+                let rule = match (partial_rules.last_mut(), value) {
+                    (Some(rules), Some(value)) => rules[value].as_mut().unwrap(),
+                    _ => return Err(error("An action outside of any rule".to_string())),
+                };
+
+                if let Some(written) = sentence(action, "Write the value ", ".") {
+                    rule.write = Some(parse_value(written).ok_or_else(|| error(format!("Invalid value: {:?}", written)))?);
+                } else if let Some(direction) = sentence(action, "Move one slot to the ", ".") {
+                    rule.shift = Some(match direction {
+                        "left" => -1,
+                        "right" => 1,
+                        _ => return Err(error(format!("Invalid direction: {:?}", direction))),
+                    });
+                } else if let Some(next) = sentence(action, "Continue with state ", ".") {
+                    rule.next = Some((next.to_string(), idx + 1));
+                } else {
+                    return Err(error(format!("Invalid action: {:?}", action)));
+                }
+            } else {
+                return Err(error(format!("Invalid line: {:?}", line)));
+            }
+        }
+
+        let find_state = |name: &str, line: usize| {
+            names
+                .iter()
+                .position(|known| known == name)
+                .ok_or_else(|| AocError::parse_at(line, 1, format!("State {} is not described", name)))
+        };
+
+        let (start_name, start_line) = start.ok_or_else(|| AocError::parse("Missing the state to begin in"))?;
+        let start = find_state(&start_name, start_line)?;
+        let steps = steps.ok_or_else(|| AocError::parse("Missing the number of steps"))?;
+
+        let mut rules = Vec::with_capacity(names.len());
+
+        for (state, partial) in partial_rules.iter().enumerate() {
+            let mut complete = [Rule {
+                write: false,
+                shift: 0,
+                next: 0,
+            }; 2];
+
+            for current in 0..2 {
+                let partial = partial[current].as_ref().ok_or_else(|| {
+                    AocError::parse_at(
+                        header_lines[state],
+                        1,
+                        format!("State {} has no rule for the value {}", names[state], current),
+                    )
+                })?;
+                let incomplete = |missing: &str| {
+                    AocError::parse_at(
+                        partial.line,
+                        1,
+                        format!("The rule of state {} for the value {} does not say {}", names[state], current, missing),
+                    )
+                };
+
+                let (next_name, next_line) = partial.next.as_ref().ok_or_else(|| incomplete("which state is next"))?;
+
+                complete[current] = Rule {
+                    write: partial.write.ok_or_else(|| incomplete("what to write"))?,
+                    shift: partial.shift.ok_or_else(|| incomplete("where to move"))?,
+                    next: find_state(next_name, *next_line)?,
+                };
+            }
+
+            rules.push(complete);
+        }
+
+        Ok(Blueprint {
+            names,
+            rules,
+            start,
+            steps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Blueprint;
+    use aoc_common::AocError;
+
+    const TEST_INPUT: &str = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.";
+
+    #[test]
+    fn run_test() {
+        let blueprint = TEST_INPUT.parse::<Blueprint>().unwrap();
+
+        assert_eq!(6, blueprint.steps());
+        assert_eq!(2, blueprint.state_count());
+        assert_eq!(-1, blueprint.rule(0, true).shift);
+
+        let mut machine = blueprint.machine();
+        let mut cursors = Vec::new();
+        let mut states = Vec::new();
+
+        for _ in 0..blueprint.steps() {
+            machine.do_step();
+            cursors.push(machine.regs.cursor);
+            states.push(machine.state.name());
+        }
+
+        assert_eq!(vec![1, 0, -1, -2, -1, 0], cursors);
+        assert_eq!(vec!["B", "A", "B", "A", "B", "A"], states);
+        assert_eq!(3, machine.get_checksum());
+    }
+
+    #[test]
+    fn error_test() {
+        let error = |input: &str| input.parse::<Blueprint>().unwrap_err();
+
+        assert_eq!(
+            AocError::parse_at(8, 5, "Invalid action: \"Continue with state C\""),
+            error(&TEST_INPUT.replacen("state B.", "state C", 1))
+        );
+        assert_eq!(
+            AocError::parse_at(8, 1, "State C is not described"),
+            error(&TEST_INPUT.replacen("state B.", "state C.", 1))
+        );
+        assert_eq!(
+            AocError::parse_at(14, 1, "State B has no rule for the value 1"),
+            error(&TEST_INPUT.lines().take(18).collect::<Vec<&str>>().join("\n"))
+        );
+        assert_eq!(
+            AocError::parse_at(9, 1, "The rule of state A for the value 1 does not say where to move"),
+            error(&TEST_INPUT.replacen("    - Move one slot to the left.\n", "", 1))
+        );
+        assert_eq!(
+            AocError::parse_at(9, 3, "State A has two rules for the value 1"),
+            error(&TEST_INPUT.replacen("value is 0", "value is 1", 1))
+        );
+        assert_eq!(
+            AocError::parse("Missing the number of steps"),
+            error(&TEST_INPUT.replacen("Perform a diagnostic checksum after 6 steps.", "", 1))
+        );
+    }
+}
@@ -0,0 +1,215 @@
+extern crate aoc_common;
+
+pub mod blueprint;
+pub mod macro_step;
+pub mod tape;
+
+use std::collections::HashSet;
+
+use aoc_common::{AocResult, Solver};
+
+use blueprint::Blueprint;
+use macro_step::MacroMachine;
+use tape::Tape;
+
+pub trait State<T: Tape = HashSet<isize>> {
+    fn run_state_change(&self, machine: &mut MachineRegs<T>) -> Self;
+}
+
+#[derive(Debug)]
+pub struct MachineRegs<T: Tape = HashSet<isize>> {
+    tape: T,
+    cursor: isize,
+}
+
+impl<T: Tape> MachineRegs<T> {
+    fn new() -> MachineRegs<T> {
+        MachineRegs {
+            tape: T::default(),
+            cursor: 0,
+        }
+    }
+
+    pub fn tape(&self) -> &T {
+        &self.tape
+    }
+
+    pub fn cursor(&self) -> isize {
+        self.cursor
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine<S: State<T>, T: Tape = HashSet<isize>> {
+    regs: MachineRegs<T>,
+    state: S,
+}
+
+impl<S, T> Machine<S, T>
+where
+    S: State<T>,
+    T: Tape,
+{
+    pub fn new(state: S) -> Machine<S, T> {
+        Machine {
+            regs: MachineRegs::new(),
+            state,
+        }
+    }
+
+    pub fn regs(&self) -> &MachineRegs<T> {
+        &self.regs
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn do_step(&mut self) {
+        self.state = self.state.run_state_change(&mut self.regs);
+    }
+
+    pub fn get_checksum(&self) -> usize {
+        self.regs.tape.count_ones()
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Blueprint;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut machine = MacroMachine::new(input);
+        machine.run(input.steps() as u64);
+
+        Ok(machine.get_checksum().to_string())
+    }
+
+    fn part_b(_input: &Self::Input) -> AocResult<String> {
+        Ok("Merry Christmas!".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Machine;
+    use super::MachineRegs;
+    use super::State;
+    use super::HashSet;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum SimpleStates {
+        A,
+        B,
+    }
+
+    impl State for SimpleStates {
+        fn run_state_change(&self, regs: &mut MachineRegs) -> SimpleStates {
+            match *self {
+                // In state A:
+                //   If the current value is 0:
+                //     - Write the value 1.
+                //     - Move one slot to the right.
+                //     - Continue with state B.
+                //   If the current value is 1:
+                //     - Write the value 0.
+                //     - Move one slot to the left.
+                //     - Continue with state B.
+                SimpleStates::A => {
+                    if regs.tape.remove(&regs.cursor) {
+                        regs.cursor -= 1;
+                    } else {
+                        regs.tape.insert(regs.cursor);
+                        regs.cursor += 1;
+                    }
+                    SimpleStates::B
+                }
+                // In state B:
+                //   If the current value is 0:
+                //     - Write the value 1.
+                //     - Move one slot to the left.
+                //     - Continue with state A.
+                //   If the current value is 1:
+                //     - Write the value 1.
+                //     - Move one slot to the right.
+                //     - Continue with state A.
+                SimpleStates::B => {
+                    if regs.tape.contains(&regs.cursor) {
+                        regs.cursor += 1;
+                    } else {
+                        regs.tape.insert(regs.cursor);
+                        regs.cursor -= 1;
+                    }
+                    SimpleStates::A
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn simple_state_test() {
+        // Begin in state A.
+        let mut machine = Machine::new(SimpleStates::A);
+        let mut test_tape = HashSet::new();
+
+        // ... 0  0  0 [0] 0  0 ... (before any steps; about to run state A)
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, 0);
+        assert_eq!(machine.state, SimpleStates::A);
+
+        machine.do_step();
+
+        // ... 0  0  0  1 [0] 0 ... (after 1 step;     about to run state B)
+        test_tape.insert(0);
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, 1);
+        assert_eq!(machine.state, SimpleStates::B);
+
+        machine.do_step();
+
+        // ... 0  0  0 [1] 1  0 ... (after 2 steps;    about to run state A)
+        test_tape.insert(1);
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, 0);
+        assert_eq!(machine.state, SimpleStates::A);
+
+        machine.do_step();
+
+        // ... 0  0 [0] 0  1  0 ... (after 3 steps;    about to run state B)
+        test_tape.remove(&0);
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, -1);
+        assert_eq!(machine.state, SimpleStates::B);
+
+        machine.do_step();
+
+        // ... 0 [0] 1  0  1  0 ... (after 4 steps;    about to run state A)
+        test_tape.insert(-1);
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, -2);
+        assert_eq!(machine.state, SimpleStates::A);
+
+        machine.do_step();
+
+        // ... 0  1 [1] 0  1  0 ... (after 5 steps;    about to run state B)
+        test_tape.insert(-2);
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, -1);
+        assert_eq!(machine.state, SimpleStates::B);
+
+        machine.do_step();
+
+        // ... 0  1  1 [0] 1  0 ... (after 6 steps;    about to run state A)
+        assert_eq!(machine.regs.tape, test_tape);
+        assert_eq!(machine.regs.cursor, 0);
+        assert_eq!(machine.state, SimpleStates::A);
+
+        assert_eq!(machine.get_checksum(), 3);
+    }
+
+}
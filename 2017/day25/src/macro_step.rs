@@ -0,0 +1,234 @@
+//! Macro-stepping, the way busy beaver simulators speed machines up.
+//!
+//! The tape is cut into blocks of 16 slots. Whenever the machine enters a block at one of its
+//! edges, what it does until it leaves the block depends only on its state and the 16 bits of
+//! the block, so it is computed once and cached. Later visits replace all the steps spent
+//! inside the block with a single lookup.
+
+use blueprint::Blueprint;
+use tape::{BitTape, Tape};
+
+const BLOCK_SLOTS: isize = 16;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Transition {
+    Unknown,
+    /// The machine leaves the block after `steps` steps, with the block holding `bits`.
+    Exit {
+        bits: u16,
+        state: u32,
+        right: bool,
+        steps: u64,
+    },
+    /// The machine never leaves the block.
+    Trapped,
+}
+
+pub struct MacroMachine<'a> {
+    blueprint: &'a Blueprint,
+    tape: BitTape,
+    cursor: isize,
+    state: usize,
+    steps: u64,
+    /// Indexed by the state, the bits of the block and the edge the block is entered at.
+    cache: Vec<Transition>,
+}
+
+impl<'a> MacroMachine<'a> {
+    /// A machine with an empty tape, in the starting state of the blueprint.
+    pub fn new(blueprint: &'a Blueprint) -> MacroMachine<'a> {
+        assert!(blueprint.state_count() <= u32::MAX as usize, "too many states");
+
+        MacroMachine {
+            blueprint,
+            tape: BitTape::default(),
+            cursor: 0,
+            state: blueprint.start(),
+            steps: 0,
+            cache: vec![Transition::Unknown; blueprint.state_count() << 17],
+        }
+    }
+
+    pub fn tape(&self) -> &BitTape {
+        &self.tape
+    }
+
+    pub fn cursor(&self) -> isize {
+        self.cursor
+    }
+
+    pub fn state(&self) -> usize {
+        self.state
+    }
+
+    /// The steps done so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn get_checksum(&self) -> usize {
+        self.tape.count_ones()
+    }
+
+    /// Does a single step, without the cache.
+    pub fn step(&mut self) {
+        let rule = self.blueprint.rule(self.state, self.tape.get(self.cursor));
+
+        self.tape.set(self.cursor, rule.write);
+        self.cursor += rule.shift;
+        self.state = rule.next;
+        self.steps += 1;
+    }
+
+    /// Runs the machine inside a block, starting at `offset`, until it leaves the block or has done
+    /// `limit` steps. Returns the new bits of the block, the offset and state the machine ends up
+    /// with, and the number of steps done.
+    fn run_block(&self, mut bits: u16, mut offset: isize, mut state: usize, limit: u64) -> (u16, isize, usize, u64) {
+        let mut steps = 0;
+
+        while steps < limit && (0..BLOCK_SLOTS).contains(&offset) {
+            let rule = self.blueprint.rule(state, bits >> offset & 1 == 1);
+
+            if rule.write {
+                bits |= 1 << offset;
+            } else {
+                bits &= !(1 << offset);
+            }
+
+            offset += rule.shift;
+            state = rule.next;
+            steps += 1;
+        }
+
+        (bits, offset, state, steps)
+    }
+
+    fn transition(&mut self, bits: u16, right_edge: bool) -> Transition {
+        let index = self.state << 17 | (bits as usize) << 1 | right_edge as usize;
+
+        if self.cache[index] == Transition::Unknown {
+            // A block has 16 * 2^16 configurations in every state, running longer repeats one
+            let limit = (BLOCK_SLOTS as u64 * self.blueprint.state_count() as u64) << 16;
+            let start = if right_edge { BLOCK_SLOTS - 1 } else { 0 };
+            let (bits, offset, state, steps) = self.run_block(bits, start, self.state, limit + 1);
+
+            self.cache[index] = if (0..BLOCK_SLOTS).contains(&offset) {
+                Transition::Trapped
+            } else {
+                Transition::Exit {
+                    bits,
+                    state: state as u32,
+                    right: offset >= BLOCK_SLOTS,
+                    steps,
+                }
+            };
+        }
+
+        self.cache[index]
+    }
+
+    /// Does the given number of steps more, leaving the machine exactly where single steps would.
+    pub fn run(&mut self, steps: u64) {
+        let mut remaining = steps;
+
+        while remaining > 0 {
+            let block = self.cursor.div_euclid(BLOCK_SLOTS);
+            let offset = self.cursor.rem_euclid(BLOCK_SLOTS);
+            let bits = self.tape.get_block(block);
+
+            let transition = if offset == 0 || offset == BLOCK_SLOTS - 1 {
+                self.transition(bits, offset != 0)
+            } else {
+                Transition::Unknown
+            };
+
+            let (bits, offset, state, taken) = match transition {
+                Transition::Exit {
+                    bits,
+                    state,
+                    right,
+                    steps,
+                } if steps <= remaining => {
+                    let offset = if right { BLOCK_SLOTS } else { -1 };
+                    (bits, offset, state as usize, steps)
+                }
+                // In the middle of a block, trapped in it, or with too few steps left to leave it
+                _ => self.run_block(bits, offset, self.state, remaining),
+            };
+
+            self.tape.set_block(block, bits);
+            self.cursor = block * BLOCK_SLOTS + offset;
+            self.state = state;
+            self.steps += taken;
+            remaining -= taken;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MacroMachine;
+    use blueprint::Blueprint;
+    use tape::{BitTape, Tape};
+
+    #[test]
+    fn macro_step_test() {
+        let blueprint = include_str!("../input.txt").parse::<Blueprint>().unwrap();
+        let mut single = blueprint.machine_on::<BitTape>();
+        let mut fast = MacroMachine::new(&blueprint);
+
+        for &steps in &[1, 15, 16, 17, 100, 1000, 12345, 100_000] {
+            for _ in 0..steps {
+                single.do_step();
+            }
+
+            fast.run(steps);
+
+            assert_eq!(single.regs().cursor(), fast.cursor(), "after {} steps", fast.steps());
+            assert_eq!(single.state().index(), fast.state());
+            assert_eq!(single.get_checksum(), fast.get_checksum());
+
+            let (low, high) = fast.tape().span();
+            assert!((low..high).all(|pos| single.regs().tape().get(pos) == fast.tape().get(pos)));
+        }
+
+        // single steps and macro steps mix
+        fast.step();
+        fast.run(3);
+        assert_eq!(113_498, fast.steps());
+    }
+
+    #[test]
+    fn trapped_test() {
+        // bounces between two slots forever
+        let blueprint = "Begin in state A.
+Perform a diagnostic checksum after 1000 steps.
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the right.
+    - Continue with state B.
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state A."
+            .parse::<Blueprint>()
+            .unwrap();
+
+        let mut machine = MacroMachine::new(&blueprint);
+        machine.run(1_000_001);
+
+        assert_eq!(1_000_001, machine.steps());
+        assert_eq!(1, machine.cursor());
+        assert_eq!(1, machine.get_checksum());
+    }
+}
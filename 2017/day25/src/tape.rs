@@ -0,0 +1,169 @@
+//! The tapes a machine can run on.
+
+use std::collections::HashSet;
+
+/// An infinite tape of bits in both directions, all of them 0 at the start.
+pub trait Tape: Default {
+    fn get(&self, pos: isize) -> bool;
+    fn set(&mut self, pos: isize, value: bool);
+    /// The number of 1s on the tape, which is the diagnostic checksum.
+    fn count_ones(&self) -> usize;
+}
+
+/// The positions of the 1s. Every access hashes, but it is the simplest tape there is.
+impl Tape for HashSet<isize> {
+    fn get(&self, pos: isize) -> bool {
+        self.contains(&pos)
+    }
+
+    fn set(&mut self, pos: isize, value: bool) {
+        if value {
+            self.insert(pos);
+        } else {
+            self.remove(&pos);
+        }
+    }
+
+    fn count_ones(&self) -> usize {
+        self.len()
+    }
+}
+
+const WORD_BITS: isize = 64;
+
+/// A dense tape, stored as one bit per slot in a vector of words that grows in both directions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitTape {
+    words: Vec<u64>,
+    /// The position of the lowest bit of the first word, always a multiple of the word size.
+    start: isize,
+}
+
+impl BitTape {
+    /// The positions covered by the words, so far.
+    pub fn span(&self) -> (isize, isize) {
+        (self.start, self.start + self.words.len() as isize * WORD_BITS)
+    }
+
+    /// Index of the word holding the position, and of the bit in that word.
+    fn locate(&self, pos: isize) -> Option<(usize, u32)> {
+        let offset = pos - self.start;
+
+        if offset >= 0 && offset < self.words.len() as isize * WORD_BITS {
+            Some(((offset / WORD_BITS) as usize, (offset % WORD_BITS) as u32))
+        } else {
+            None
+        }
+    }
+
+    /// Makes room for the position, at least doubling the words when it has to grow.
+    fn reserve(&mut self, pos: isize) -> (usize, u32) {
+        if let Some(location) = self.locate(pos) {
+            return location;
+        }
+
+        let (low, high) = self.span();
+
+        if self.words.is_empty() {
+            // the first word starts where the first position is written
+            self.start = pos.div_euclid(WORD_BITS) * WORD_BITS;
+            self.words.push(0);
+        } else if pos < low {
+            let needed = ((low - pos + WORD_BITS - 1) / WORD_BITS) as usize;
+            let added = needed.max(self.words.len());
+
+            let mut words = vec![0; added];
+            words.extend_from_slice(&self.words);
+
+            self.words = words;
+            self.start -= added as isize * WORD_BITS;
+        } else {
+            let needed = ((pos - high) / WORD_BITS + 1) as usize;
+            let added = needed.max(self.words.len());
+
+            self.words.resize(self.words.len() + added, 0);
+        }
+
+        self.locate(pos).unwrap()
+    }
+
+    /// The 16 slots starting at `block * 16`, the lowest position in the lowest bit.
+    pub fn get_block(&self, block: isize) -> u16 {
+        match self.locate(block * 16) {
+            Some((word, bit)) => (self.words[word] >> bit) as u16,
+            None => 0,
+        }
+    }
+
+    pub fn set_block(&mut self, block: isize, bits: u16) {
+        if bits == 0 && self.locate(block * 16).is_none() {
+            return;
+        }
+
+        let (word, bit) = self.reserve(block * 16);
+
+        self.words[word] = self.words[word] & !(0xffff << bit) | u64::from(bits) << bit;
+    }
+}
+
+impl Tape for BitTape {
+    fn get(&self, pos: isize) -> bool {
+        match self.locate(pos) {
+            Some((word, bit)) => self.words[word] >> bit & 1 == 1,
+            None => false,
+        }
+    }
+
+    fn set(&mut self, pos: isize, value: bool) {
+        if value {
+            let (word, bit) = self.reserve(pos);
+            self.words[word] |= 1 << bit;
+        } else if let Some((word, bit)) = self.locate(pos) {
+            self.words[word] &= !(1 << bit);
+        }
+    }
+
+    fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitTape, Tape};
+
+    #[test]
+    fn bit_tape_test() {
+        let mut tape = BitTape::default();
+
+        assert!(!tape.get(-1000));
+        tape.set(5, false);
+        assert_eq!((0, 0), tape.span());
+
+        for &pos in &[0, 63, 64, -1, -64, -65, 1000, -1000] {
+            tape.set(pos, true);
+            assert!(tape.get(pos), "position {}", pos);
+        }
+
+        assert_eq!(8, tape.count_ones());
+        assert!(!tape.get(1) && !tape.get(-2) && !tape.get(999));
+
+        tape.set(-65, false);
+        assert!(!tape.get(-65));
+        assert_eq!(7, tape.count_ones());
+
+        let (low, high) = tape.span();
+        assert!(low <= -1000 && high > 1000 && low % 64 == 0);
+
+        assert_eq!(0b1000_0000_0000_0001, tape.get_block(3) | tape.get_block(0));
+        assert_eq!(1 << 15, tape.get_block(-1));
+
+        tape.set_block(-1, 0b101);
+        assert!(tape.get(-16) && !tape.get(-15) && tape.get(-14) && !tape.get(-1));
+        assert!(tape.get(0) && tape.get(-64));
+
+        tape.set_block(100, 0xffff);
+        assert_eq!(0xffff, tape.get_block(100));
+        assert!(tape.get(1615) && !tape.get(1616));
+    }
+}
@@ -1,16 +1,11 @@
-use std::num::ParseIntError;
 use std::str::FromStr;
 
 use std::collections::HashSet;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug)]
-struct AocError(String);
-
-#[derive(Debug)]
-struct Frequencies(Vec<isize>);
+pub struct Frequencies(Vec<isize>);
 
 impl FromStr for Frequencies {
     type Err = AocError;
@@ -20,7 +15,7 @@ impl FromStr for Frequencies {
             s.lines()
                 .map(|line| {
                     line.parse()
-                        .map_err(|e: ParseIntError| AocError(format!("Parsing error: {}", e)))
+                        .map_err(AocError::from)
                 })
                 .collect::<Result<Vec<isize>, AocError>>()?,
         ))
@@ -50,29 +45,36 @@ impl Frequencies {
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let frequencies: Frequencies = input_str.parse().expect("parse error");
+impl Solver for Solution {
+    type Input = Frequencies;
 
-    println!("The sum of all frequencies is: {}", frequencies.get_sum());
-    println!("The first frequency seen twice is: {}", frequencies.get_first_seen_twice());
-}
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
 
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(input.get_sum().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(input.get_first_seen_twice().to_string())
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::Frequencies;
 
-    const TEST_PAIRS_A: [(&'static str, isize); 4] = [
+    const TEST_PAIRS_A: [(&str, isize); 4] = [
         ("+1\n-2\n+3\n+1", 3),
         ("+1\n+1\n+1", 3),
         ("+1\n+1\n-2", 0),
         ("-1\n-2\n-3", -6)
     ];
 
-    const TEST_PAIRS_B: [(&'static str, isize); 5] = [
+    const TEST_PAIRS_B: [(&str, isize); 5] = [
         ("+1\n-2\n+3\n+1", 2),
         ("+1\n-1", 0),
         ("+3\n+3\n+4\n-2\n-4", 10),
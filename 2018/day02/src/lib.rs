@@ -1,10 +1,9 @@
-use std::io;
-use std::io::prelude::*;
-
 use std::collections::HashMap;
 
 use std::cmp;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 fn get_histograms(input: &str) -> Vec<HashMap<char, usize>> {
     input
         .lines()
@@ -79,29 +78,27 @@ fn reduce_similar(string_a: &str, string_b: &str) -> String {
     out
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let histos = get_histograms(&input_str);
+impl Solver for Solution {
+    type Input = String;
 
-    let (twice, thrice) = count_ids(&histos);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
-    println!(
-        "The ID counts are {} and {}. Checksum {}.",
-        twice,
-        thrice,
-        twice * thrice
-    );
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let (twice, thrice) = count_ids(&get_histograms(input));
 
-    let (string_a, string_b) = find_first_similar(&input_str).expect("no similar ID strings");
+        Ok((twice * thrice).to_string())
+    }
 
-    let reduced = reduce_similar(&string_a, &string_b);
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let (string_a, string_b) =
+            find_first_similar(input).ok_or_else(|| AocError::unsolvable("no similar ID strings"))?;
 
-    println!(
-        "The similar ID strings are '{}' and '{}'. The common letters are '{}'.",
-        string_a, string_b, reduced
-    );
+        Ok(reduce_similar(&string_a, &string_b))
+    }
 }
 
 #[cfg(test)]
@@ -1,30 +1,24 @@
 use std::collections::HashMap;
-use std::collections::HashSet;
 
 use std::str::FromStr;
 
 use std::ops::{Deref, DerefMut};
 
-use std::io;
-use std::io::prelude::*;
-
 use regex::Regex;
 
-use std::num::ParseIntError;
 
 use lazy_static::lazy_static;
 
-#[derive(Debug)]
-struct AocError(String);
+use aoc_common::{AocError, AocResult, Grid, Point, Solver};
 
 #[derive(Debug, PartialEq, Eq)]
-struct Patch {
+pub struct Patch {
     loc: (usize, usize),
     size: (usize, usize),
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Claims(HashMap<usize, Patch>);
+pub struct Claims(HashMap<usize, Patch>);
 
 lazy_static! {
     static ref CLAIMS_RE: Regex = Regex::new("^#(?P<id>[0-9]+) @ (?P<loc_x>[0-9]+),(?P<loc_y>[0-9]+): (?P<size_x>[0-9]+)x(?P<size_y>[0-9]+)$").unwrap();
@@ -39,39 +33,39 @@ impl FromStr for Claims {
         for line in input.lines() {
             let caps = CLAIMS_RE
                 .captures(line)
-                .ok_or_else(|| AocError(format!("Invalid input: {:?}", line)))?;
+                .ok_or_else(|| AocError::parse(format!("Invalid input: {:?}", line)))?;
 
             let id = caps
                 .name("id")
-                .ok_or_else(|| AocError(format!("Missing claim ID in input: {:?}", line)))?
+                .ok_or_else(|| AocError::parse(format!("Missing claim ID in input: {:?}", line)))?
                 .as_str()
                 .parse()
-                .map_err(|e: ParseIntError| AocError(e.to_string()))?;
+                .map_err(AocError::from)?;
 
             let loc = (
                 caps.name("loc_x")
-                    .ok_or_else(|| AocError(format!("Missing location X in input: {:?}", line)))?
+                    .ok_or_else(|| AocError::parse(format!("Missing location X in input: {:?}", line)))?
                     .as_str()
                     .parse()
-                    .map_err(|e: ParseIntError| AocError(e.to_string()))?,
+                    .map_err(AocError::from)?,
                 caps.name("loc_y")
-                    .ok_or_else(|| AocError(format!("Missing location Y in input: {:?}", line)))?
+                    .ok_or_else(|| AocError::parse(format!("Missing location Y in input: {:?}", line)))?
                     .as_str()
                     .parse()
-                    .map_err(|e: ParseIntError| AocError(e.to_string()))?,
+                    .map_err(AocError::from)?,
             );
 
             let size = (
                 caps.name("size_x")
-                    .ok_or_else(|| AocError(format!("Missing size X in input: {:?}", line)))?
+                    .ok_or_else(|| AocError::parse(format!("Missing size X in input: {:?}", line)))?
                     .as_str()
                     .parse()
-                    .map_err(|e: ParseIntError| AocError(e.to_string()))?,
+                    .map_err(AocError::from)?,
                 caps.name("size_y")
-                    .ok_or_else(|| AocError(format!("Missing size Y in input: {:?}", line)))?
+                    .ok_or_else(|| AocError::parse(format!("Missing size Y in input: {:?}", line)))?
                     .as_str()
                     .parse()
-                    .map_err(|e: ParseIntError| AocError(e.to_string()))?,
+                    .map_err(AocError::from)?,
             );
 
             claims.insert(id, Patch { loc, size });
@@ -95,89 +89,76 @@ impl DerefMut for Claims {
     }
 }
 
+/// How many claims cover each square inch of the fabric.
 #[derive(Debug, PartialEq, Eq)]
-struct Fabric(HashMap<(usize, usize), HashSet<usize>>);
+struct Fabric(Grid<usize>);
+
+impl Patch {
+    fn origin(&self) -> Point {
+        Point::new(self.loc.0 as isize, self.loc.1 as isize)
+    }
+}
 
 impl Fabric {
-    fn new() -> Fabric {
-        Fabric(HashMap::new())
+    /// Fabric just large enough for all the claims, none of them added yet.
+    fn new(claims: &Claims) -> Fabric {
+        let (width, height) = claims.values().fold((0, 0), |(width, height), patch| {
+            (width.max(patch.loc.0 + patch.size.0), height.max(patch.loc.1 + patch.size.1))
+        });
+
+        Fabric(Grid::new(width, height, 0))
     }
 
     fn add_patches(&mut self, claims: &Claims) {
-        for (id, patch) in claims.iter() {
-            for x in (patch.loc.0)..(patch.loc.0 + patch.size.0) {
-                for y in (patch.loc.1)..(patch.loc.1 + patch.size.1) {
-                    self.entry((x, y)).or_insert_with(HashSet::new).insert(*id);
-                }
+        for patch in claims.values() {
+            for (_, count) in self.0.view_mut(patch.origin(), patch.size.0, patch.size.1).iter_mut() {
+                *count += 1;
             }
         }
     }
 
     fn multiple_claim_count(&self) -> usize {
-        self.values()
-            .map(|set| set.len())
-            .filter(|count| *count >= 2)
-            .count()
+        self.0.values().filter(|count| **count >= 2).count()
     }
 
     fn first_intact_claim_id(&self, claims: &Claims) -> Option<usize> {
-        for (id, patch) in claims.iter() {
-            let mut patch_intact = true;
-
-            'patch_loop: for x in (patch.loc.0)..(patch.loc.0 + patch.size.0) {
-                for y in (patch.loc.1)..(patch.loc.1 + patch.size.1) {
-                    if let Some(set) = self.get(&(x, y)) {
-                        if set.len() != 1 {
-                            patch_intact = false;
-                            break 'patch_loop;
-                        }
-                    }
-                }
-            }
-
-            if patch_intact {
-                return Some(*id);
-            }
-        }
-
-        None
+        claims
+            .iter()
+            .find(|(_, patch)| {
+                self.0
+                    .view(patch.origin(), patch.size.0, patch.size.1)
+                    .iter()
+                    .all(|(_, count)| *count == 1)
+            })
+            .map(|(id, _)| *id)
     }
 }
 
-impl Deref for Fabric {
-    type Target = HashMap<(usize, usize), HashSet<usize>>;
+pub struct Solution;
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
+impl Solver for Solution {
+    type Input = Claims;
 
-impl DerefMut for Fabric {
-    fn deref_mut(&mut self) -> &mut HashMap<(usize, usize), HashSet<usize>> {
-        &mut self.0
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
     }
-}
-
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
 
-    let claims = input_str.parse().expect("could not parse input");
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut fabric = Fabric::new(input);
+        fabric.add_patches(input);
 
-    let mut fabric = Fabric::new();
-    fabric.add_patches(&claims);
+        Ok(fabric.multiple_claim_count().to_string())
+    }
 
-    println!(
-        "The number of locations claimed by multiple patches is: {}",
-        fabric.multiple_claim_count()
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut fabric = Fabric::new(input);
+        fabric.add_patches(input);
 
-    println!(
-        "The first claim that is intact is: {}",
         fabric
-            .first_intact_claim_id(&claims)
-            .expect("no intact claims")
-    );
+            .first_intact_claim_id(input)
+            .map(|id| id.to_string())
+            .ok_or_else(|| AocError::unsolvable("no intact claims"))
+    }
 }
 
 #[cfg(test)]
@@ -229,7 +210,7 @@ mod tests {
 
         assert_eq!(*EXPECTED_CLAIMS, claims);
 
-        let mut fabric = Fabric::new();
+        let mut fabric = Fabric::new(&claims);
         fabric.add_patches(&claims);
 
         assert_eq!(4, fabric.multiple_claim_count());
@@ -4,20 +4,15 @@ use std::str::FromStr;
 
 use std::ops::{Deref, DerefMut};
 
-use std::io;
-use std::io::prelude::*;
-
 use regex::Regex;
 
-use std::num::ParseIntError;
 
 use lazy_static::lazy_static;
 
-#[derive(Debug)]
-struct AocError(String);
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug, PartialEq, Eq)]
-struct Schedule(HashMap<usize, HashMap<u8, usize>>);
+pub struct Schedule(HashMap<usize, HashMap<u8, usize>>);
 
 lazy_static! {
     static ref EVENTS_RE: Regex = Regex::new(r"^(?P<datetime>\[[0-9]{4}-[0-9]{2}-[0-9]{2} (?P<hour>[0-9]{2}):(?P<minute>[0-9]{2})\]) (?P<action>Guard #(?P<guard_id>[0-9]+) begins shift|falls asleep|wakes up)$").unwrap();
@@ -27,7 +22,9 @@ impl FromStr for Schedule {
     type Err = AocError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // The datetime strings are only read through `Debug`, to make error messages useful.
         #[derive(Debug)]
+        #[allow(dead_code)]
         enum Event<'a> {
             GuardStarts(&'a str, usize),
             FallsAsleep(&'a str, u8),
@@ -44,33 +41,33 @@ impl FromStr for Schedule {
         for event_str in &event_strings {
             let caps = EVENTS_RE
                 .captures(event_str)
-                .ok_or_else(|| AocError(format!("Invalid input: {:?}", event_str)))?;
+                .ok_or_else(|| AocError::parse(format!("Invalid input: {:?}", event_str)))?;
 
             let datetime_str = caps
                 .name("datetime")
                 .ok_or_else(|| {
-                    AocError(format!("Missing date and time in input: {:?}", event_str))
+                    AocError::parse(format!("Missing date and time in input: {:?}", event_str))
                 })?
                 .as_str();
 
             let hour: u8 = caps
                 .name("hour")
-                .ok_or_else(|| AocError(format!("Missing hour in input: {:?}", event_str)))?
+                .ok_or_else(|| AocError::parse(format!("Missing hour in input: {:?}", event_str)))?
                 .as_str()
                 .parse()
-                .map_err(|e: ParseIntError| AocError(e.to_string()))?;
+                .map_err(AocError::from)?;
 
             let minute = caps
                 .name("minute")
-                .ok_or_else(|| AocError(format!("Missing minute in input: {:?}", event_str)))?
+                .ok_or_else(|| AocError::parse(format!("Missing minute in input: {:?}", event_str)))?
                 .as_str()
                 .parse()
-                .map_err(|e: ParseIntError| AocError(e.to_string()))?;
+                .map_err(AocError::from)?;
 
             let event = match caps
                 .name("action")
                 .ok_or_else(|| {
-                    AocError(format!("Missing action string in input: {:?}", event_str))
+                    AocError::parse(format!("Missing action string in input: {:?}", event_str))
                 })?
                 .as_str()
             {
@@ -78,25 +75,25 @@ impl FromStr for Schedule {
                     if hour == 0 {
                         Event::FallsAsleep(datetime_str, minute)
                     } else {
-                        return Err(AocError(format!("The fall asleep event should only happen during the midnight hour: {:?}", event_str)));
+                        return Err(AocError::parse(format!("The fall asleep event should only happen during the midnight hour: {:?}", event_str)));
                     }
                 }
                 "wakes up" => {
                     if hour == 0 {
                         Event::WakesUp(datetime_str, minute)
                     } else {
-                        return Err(AocError(format!("The fall asleep event should only happen during the midnight hour: {:?}", event_str)));
+                        return Err(AocError::parse(format!("The fall asleep event should only happen during the midnight hour: {:?}", event_str)));
                     }
                 }
                 _ => {
                     let guard_id = caps
                         .name("guard_id")
                         .ok_or_else(|| {
-                            AocError(format!("Missing guard ID in input: {:?}", event_str))
+                            AocError::parse(format!("Missing guard ID in input: {:?}", event_str))
                         })?
                         .as_str()
                         .parse()
-                        .map_err(|e: ParseIntError| AocError(e.to_string()))?;
+                        .map_err(AocError::from)?;
 
                     Event::GuardStarts(datetime_str, guard_id)
                 }
@@ -120,7 +117,7 @@ impl FromStr for Schedule {
                     }
                     Event::FallsAsleep(_, minute) => {
                         if let Some(ev @ Event::FallsAsleep(_, _)) = prev_event {
-                            return Err(AocError(format!(
+                            return Err(AocError::parse(format!(
                                 "Cannot have event '{:?}' after event '{:?}.",
                                 event, ev
                             )));
@@ -131,7 +128,7 @@ impl FromStr for Schedule {
                     Event::WakesUp(_, minute) => {
                         match prev_event {
                             Some(Event::GuardStarts(_, _)) | Some(Event::WakesUp(_, _)) | None => {
-                                return Err(AocError(format!(
+                                return Err(AocError::parse(format!(
                                     "Cannot have event '{:?}' after event '{:?}.",
                                     event, prev_event
                                 )));
@@ -141,7 +138,7 @@ impl FromStr for Schedule {
 
                         if let Some(start_time) = fell_asleep_at {
                             if start_time >= minute {
-                                return Err(AocError(format!(
+                                return Err(AocError::parse(format!(
                                     "Wakeup time before fall asleep time: '{:?}' -> '{:?}'",
                                     prev_event, event
                                 )));
@@ -150,12 +147,12 @@ impl FromStr for Schedule {
                             for min in start_time..minute {
                                 *schedule
                                     .entry(curr)
-                                    .or_insert_with(HashMap::new)
+                                    .or_default()
                                     .entry(min)
                                     .or_insert(0) += 1
                             }
                         } else {
-                            return Err(AocError(format!(
+                            return Err(AocError::parse(format!(
                                 "Wakeup without falling asleep at '{:?}'.",
                                 event
                             )));
@@ -169,7 +166,7 @@ impl FromStr for Schedule {
                         fell_asleep_at = None;
                     }
                     ev => {
-                        return Err(AocError(format!("Invalid event at this time: {:?}", ev)));
+                        return Err(AocError::parse(format!("Invalid event at this time: {:?}", ev)));
                     }
                 }
             }
@@ -205,13 +202,13 @@ impl Schedule {
                     .sum::<usize>()
                     .cmp(&guard_b_sched.values().sum::<usize>())
             })
-            .ok_or_else(|| AocError("No schedule stored!".into()))?;
+            .ok_or_else(|| AocError::runtime("No schedule stored!"))?;
 
         let max_minute = max_guard
             .1
             .iter()
             .max_by(|(_, minute_a_cnt), (_, minute_b_cnt)| minute_a_cnt.cmp(minute_b_cnt))
-            .ok_or_else(|| AocError(format!("Guard #{} never fell asleep!", max_guard.0)))?
+            .ok_or_else(|| AocError::runtime(format!("Guard #{} never fell asleep!", max_guard.0)))?
             .0;
 
         Ok((*max_guard.0, *max_minute))
@@ -226,47 +223,41 @@ impl Schedule {
                     .max()
                     .cmp(&guard_b_sched.values().max())
             })
-            .ok_or_else(|| AocError("No schedule stored!".into()))?;
+            .ok_or_else(|| AocError::runtime("No schedule stored!"))?;
 
         let max_minute = max_guard
             .1
             .iter()
             .max_by(|(_, minute_a_cnt), (_, minute_b_cnt)| minute_a_cnt.cmp(minute_b_cnt))
-            .ok_or_else(|| AocError(format!("Guard #{} never fell asleep!", max_guard.0)))?
+            .ok_or_else(|| AocError::runtime(format!("Guard #{} never fell asleep!", max_guard.0)))?
             .0;
 
         Ok((*max_guard.0, *max_minute))
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let schedule: Schedule = input_str.parse().expect("parsing error");
+impl Solver for Solution {
+    type Input = Schedule;
 
-    let (max_guard, max_minute) = schedule
-        .find_sleepiest_guard_and_minute()
-        .expect("schedule search error");
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
 
-    println!(
-        "Guard #{} is the sleepiest, and has most often slept on minute {}. Multiplied {}.",
-        max_guard,
-        max_minute,
-        max_guard * max_minute as usize
-    );
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let (max_guard, max_minute) = input
+            .find_sleepiest_guard_and_minute()?;
 
+        Ok((max_guard * max_minute as usize).to_string())
+    }
 
-    let (max_guard_2, max_minute_2) = schedule
-        .find_most_often_asleep_guard_and_minute()
-        .expect("schedule search error");
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let (max_guard, max_minute) = input
+            .find_most_often_asleep_guard_and_minute()?;
 
-    println!(
-        "Guard #{} is most frequently asleep, on minute {}. Multiplied {}.",
-        max_guard_2,
-        max_minute_2,
-        max_guard_2 * max_minute_2 as usize
-    );
+        Ok((max_guard * max_minute as usize).to_string())
+    }
 }
 
 #[cfg(test)]
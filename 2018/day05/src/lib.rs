@@ -1,11 +1,10 @@
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocResult, Solver};
 
 fn find_shortest_polymer(input: &str) -> String {
-    let mut min = std::usize::MAX;
+    let mut min = usize::MAX;
     let mut min_str = input.to_string();
 
-    for remove_ch_byte in b'a'..b'z' {
+    for remove_ch_byte in b'a'..=b'z' {
         let test_polymer = input
             .chars()
             .filter(|ch| {
@@ -65,20 +64,22 @@ fn reduce_polymer(input: &str) -> String {
     out
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let reduced = reduce_polymer(&input_str);
+impl Solver for Solution {
+    type Input = String;
 
-    println!("The length of the reduced polymer is {}.", reduced.len());
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(input.to_string())
+    }
 
-    let shortest = find_shortest_polymer(&input_str);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(reduce_polymer(input).len().to_string())
+    }
 
-    println!(
-        "The length of the shortest possible polymer is {}.",
-        shortest.len()
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(find_shortest_polymer(input).len().to_string())
+    }
 }
 
 #[cfg(test)]
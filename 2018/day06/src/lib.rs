@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use std::collections::HashSet;
+
+use lazy_static::lazy_static;
+
+use regex::Regex;
+
+use itertools::{Itertools, MinMaxResult};
+
+use aoc_common::{AocError, AocResult, Solver};
+
+use crate::regions::{Metric, RegionMap};
+
+pub mod regions;
+pub mod render;
+
+pub type Location = (isize, isize);
+
+lazy_static! {
+    static ref SITE_RE: Regex = Regex::new("^(?P<x>-?[0-9]+), (?P<y>-?[0-9]+)$").unwrap();
+}
+
+fn distance(loc_a: Location, loc_b: Location) -> usize {
+    ((loc_b.0 - loc_a.0).abs() + (loc_b.1 - loc_a.1).abs()) as usize
+}
+
+fn parse_location(s: &str) -> AocResult<Location> {
+    let caps = SITE_RE
+        .captures(s.trim())
+        .ok_or_else(|| AocError::parse(format!("Invalid input: {:?}", s.trim())))?;
+
+    let x = caps
+        .name("x")
+        .ok_or_else(|| AocError::parse(format!("Missing X coordinate: {:?}", s.trim())))?
+        .as_str()
+        .parse()
+        .map_err(AocError::from)?;
+
+    let y = caps
+        .name("y")
+        .ok_or_else(|| AocError::parse(format!("Missing Y coordinate: {:?}", s.trim())))?
+        .as_str()
+        .parse()
+        .map_err(AocError::from)?;
+
+    Ok((x, y))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Sites(Vec<Location>);
+
+impl FromStr for Sites {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines().map(parse_location).collect::<AocResult<_>>().map(Sites)
+    }
+}
+
+impl Sites {
+    pub fn locations(&self) -> &[Location] {
+        &self.0
+    }
+
+    fn find_acceptable_region(&self, max_distance: usize) -> HashSet<(isize, isize)> {
+        let starting_locs = &self.0;
+
+        let imax_distance = max_distance as isize;
+
+        if let MinMaxResult::MinMax(min, max) = starting_locs.iter().minmax() {
+            let mut out = HashSet::new();
+
+            for x in (max.0 - imax_distance)..=(min.0 + imax_distance) {
+                for y in (max.1 - imax_distance)..=(min.1 + imax_distance) {
+                    if starting_locs
+                        .iter()
+                        .map(|loc| distance((x, y), *loc))
+                        .sum::<usize>()
+                        < max_distance
+                    {
+                        out.insert((x, y));
+                    }
+                }
+            }
+
+            out
+        } else {
+            panic!("");
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Sites;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        RegionMap::new(input.locations(), Metric::Manhattan)
+            .largest_finite_area()
+            .map(|size| size.to_string())
+            .ok_or_else(|| AocError::unsolvable("no finite region"))
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(input.find_acceptable_region(10000).len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sites;
+
+    const INPUT_STR: &str = "1, 1\n\
+                             1, 6\n\
+                             8, 3\n\
+                             3, 4\n\
+                             5, 5\n\
+                             8, 9";
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Sites(vec![(1, 1), (1, 6), (8, 3), (3, 4), (5, 5), (8, 9)]), INPUT_STR.parse().unwrap());
+    }
+
+    #[test]
+    fn acceptable_region_test() {
+        let sites: Sites = INPUT_STR.parse().unwrap();
+
+        assert_eq!(16, sites.find_acceptable_region(32).len());
+    }
+}
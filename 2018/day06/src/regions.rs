@@ -0,0 +1,344 @@
+//! Which site is the nearest one to each location, and which sites are the nearest one to
+//! infinitely many locations.
+//!
+//! Only a box around the sites is mapped, chosen so that it holds every finite region whole and at
+//! least one location of every infinite one:
+//!
+//! - Manhattan: stepping away from the box along an axis takes every site one step further, so a
+//!   location outside the box has the same nearest site as the location on the border of the box
+//!   it is stepped back to. A site is nearest to infinitely many locations if and only if it is
+//!   nearest to a location on the border.
+//! - Chebyshev: the same holds for the diagonal steps and the box bounding `x + y` and `x - y`,
+//!   which the diagonal steps change by two, so the border is two locations thick.
+//! - Euclidean: the locations nearer to a site than to the others form a convex polygon, which is
+//!   unbounded if and only if the site is on the boundary of the convex hull of the sites. A point
+//!   far out along the outer normal of a hull edge through the site is nearer to it than to any
+//!   other site. The corners of the bounded polygons are centers of circles through three sites
+//!   with no site inside, so the box holds them all.
+
+use std::str::FromStr;
+
+use aoc_common::AocError;
+
+use crate::{distance, Location};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Manhattan,
+    Chebyshev,
+    Euclidean,
+}
+
+impl Metric {
+    /// How far apart the locations are. The Euclidean distance is squared to stay exact, which
+    /// orders the locations the same way.
+    pub fn distance(self, loc_a: Location, loc_b: Location) -> usize {
+        let (dx, dy) = ((loc_b.0 - loc_a.0).abs(), (loc_b.1 - loc_a.1).abs());
+
+        match self {
+            Metric::Manhattan => distance(loc_a, loc_b),
+            Metric::Chebyshev => dx.max(dy) as usize,
+            Metric::Euclidean => (dx * dx + dy * dy) as usize,
+        }
+    }
+
+    /// The coordinates in which a step away from all the sites takes every one of them equally
+    /// further.
+    fn axes(self, loc: Location) -> (isize, isize) {
+        match self {
+            Metric::Chebyshev => (loc.0 + loc.1, loc.0 - loc.1),
+            _ => loc,
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manhattan" => Ok(Metric::Manhattan),
+            "chebyshev" => Ok(Metric::Chebyshev),
+            "euclidean" => Ok(Metric::Euclidean),
+            _ => Err(AocError::parse(format!("Unknown metric: {:?}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionMap {
+    metric: Metric,
+    sites: Vec<Location>,
+    /// The corners of the mapped box, both included.
+    min: Location,
+    max: Location,
+    /// The nearest site of each location in the box, row by row. Locations equally near to more
+    /// sites have none.
+    owners: Vec<Option<usize>>,
+    infinite: Vec<bool>,
+}
+
+fn bounding_box(locs: impl Iterator<Item = Location>) -> (Location, Location) {
+    locs.fold(
+        ((isize::MAX, isize::MAX), (isize::MIN, isize::MIN)),
+        |((min_x, min_y), (max_x, max_y)), (x, y)| ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))),
+    )
+}
+
+fn cross(origin: Location, loc_a: Location, loc_b: Location) -> isize {
+    (loc_a.0 - origin.0) * (loc_b.1 - origin.1) - (loc_a.1 - origin.1) * (loc_b.0 - origin.0)
+}
+
+/// Whether all the sites are on one side of some line through the site and another one.
+fn on_hull(sites: &[Location], site: Location) -> bool {
+    let mut others = sites.iter().filter(|&&other| other != site).peekable();
+
+    others.peek().is_none()
+        || others.any(|&other| {
+            let sides = sites.iter().map(|&loc| cross(site, other, loc).signum());
+
+            sides.clone().all(|side| side >= 0) || sides.clone().all(|side| side <= 0)
+        })
+}
+
+/// The center of the circle through the three sites, unless they are on a line or another site is
+/// inside the circle.
+fn empty_circle_center(sites: &[Location], site: Location, loc_a: Location, loc_b: Location) -> Option<(f64, f64)> {
+    let (ax, ay) = ((loc_a.0 - site.0) as i128, (loc_a.1 - site.1) as i128);
+    let (bx, by) = ((loc_b.0 - site.0) as i128, (loc_b.1 - site.1) as i128);
+
+    // the center relative to the site is (cx / d, cy / d)
+    let d = 2 * (ax * by - ay * bx);
+
+    if d == 0 {
+        return None;
+    }
+
+    let (a_sq, b_sq) = (ax * ax + ay * ay, bx * bx + by * by);
+    let (cx, cy) = (by * a_sq - ay * b_sq, ax * b_sq - bx * a_sq);
+
+    let inside = sites.iter().any(|&loc| {
+        let (dx, dy) = (cx - d * (loc.0 - site.0) as i128, cy - d * (loc.1 - site.1) as i128);
+
+        dx * dx + dy * dy < cx * cx + cy * cy
+    });
+
+    if inside {
+        None
+    } else {
+        Some((site.0 as f64 + cx as f64 / d as f64, site.1 as f64 + cy as f64 / d as f64))
+    }
+}
+
+impl RegionMap {
+    pub fn new(sites: &[Location], metric: Metric) -> RegionMap {
+        let (min, max) = match metric {
+            Metric::Manhattan => bounding_box(sites.iter().cloned()),
+            Metric::Chebyshev => {
+                let ((min_u, min_v), (max_u, max_v)) = bounding_box(sites.iter().map(|&loc| metric.axes(loc)));
+                let ((min_u, min_v), (max_u, max_v)) = ((min_u - 1, min_v - 1), (max_u + 1, max_v + 1));
+
+                // x is (u + v) / 2 and y is (u - v) / 2
+                let floor_half = |value: isize| value.div_euclid(2);
+                let ceil_half = |value: isize| -(-value).div_euclid(2);
+
+                (
+                    (floor_half(min_u + min_v), floor_half(min_u - max_v)),
+                    (ceil_half(max_u + max_v), ceil_half(max_u - min_v)),
+                )
+            }
+            Metric::Euclidean => {
+                let mut corners = Vec::new();
+
+                for &site in sites.iter().filter(|&&site| !on_hull(sites, site)) {
+                    for (idx, &loc_a) in sites.iter().enumerate() {
+                        for &loc_b in &sites[idx + 1..] {
+                            if let Some((x, y)) = empty_circle_center(sites, site, loc_a, loc_b) {
+                                corners.push((x.floor() as isize, y.floor() as isize));
+                                corners.push((x.ceil() as isize, y.ceil() as isize));
+                            }
+                        }
+                    }
+                }
+
+                bounding_box(sites.iter().cloned().chain(corners))
+            }
+        };
+
+        let mut map = RegionMap {
+            metric,
+            sites: sites.to_vec(),
+            min,
+            max,
+            owners: Vec::new(),
+            infinite: vec![false; sites.len()],
+        };
+
+        map.owners = (min.1..=max.1)
+            .flat_map(|y| (min.0..=max.0).map(move |x| (x, y)))
+            .map(|loc| map.nearest(loc))
+            .collect();
+
+        match metric {
+            Metric::Euclidean => {
+                for (idx, &site) in sites.iter().enumerate() {
+                    map.infinite[idx] = on_hull(sites, site);
+                }
+            }
+            _ => {
+                let ((min_u, min_v), (max_u, max_v)) = bounding_box(sites.iter().map(|&loc| metric.axes(loc)));
+
+                for (idx, owner) in map.owners.iter().enumerate() {
+                    let (u, v) = metric.axes(map.location(idx));
+
+                    if let Some(site) = *owner {
+                        if u <= min_u || u >= max_u || v <= min_v || v >= max_v {
+                            map.infinite[site] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        // a site sharing its location with another one is not the nearest one anywhere
+        for (idx, &site) in sites.iter().enumerate() {
+            if sites.iter().filter(|&&loc| loc == site).count() > 1 {
+                map.infinite[idx] = false;
+            }
+        }
+
+        map
+    }
+
+    fn nearest(&self, loc: Location) -> Option<usize> {
+        let mut nearest = None;
+        let mut best = usize::MAX;
+
+        for (idx, &site) in self.sites.iter().enumerate() {
+            let dist = self.metric.distance(loc, site);
+
+            if dist < best {
+                best = dist;
+                nearest = Some(idx);
+            } else if dist == best {
+                nearest = None;
+            }
+        }
+
+        nearest
+    }
+
+    fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1).max(0) as usize
+    }
+
+    fn location(&self, idx: usize) -> Location {
+        let width = self.width();
+
+        (self.min.0 + (idx % width) as isize, self.min.1 + (idx / width) as isize)
+    }
+
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    pub fn sites(&self) -> &[Location] {
+        &self.sites
+    }
+
+    /// The corners of the mapped box, both included.
+    pub fn bounds(&self) -> (Location, Location) {
+        (self.min, self.max)
+    }
+
+    /// The nearest site to a location in the box, unless more sites are equally near.
+    pub fn owner(&self, loc: Location) -> Option<usize> {
+        if loc.0 < self.min.0 || loc.0 > self.max.0 || loc.1 < self.min.1 || loc.1 > self.max.1 {
+            return self.nearest(loc);
+        }
+
+        let idx = (loc.1 - self.min.1) as usize * self.width() + (loc.0 - self.min.0) as usize;
+
+        self.owners[idx]
+    }
+
+    pub fn is_infinite(&self, site: usize) -> bool {
+        self.infinite[site]
+    }
+
+    /// The number of locations nearest to the site, unless there are infinitely many.
+    pub fn area(&self, site: usize) -> Option<usize> {
+        if self.infinite[site] {
+            None
+        } else {
+            Some(self.owners.iter().filter(|&&owner| owner == Some(site)).count())
+        }
+    }
+
+    pub fn largest_finite_area(&self) -> Option<usize> {
+        (0..self.sites.len()).filter_map(|site| self.area(site)).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Metric, RegionMap};
+    use crate::Location;
+
+    const SITES: [Location; 6] = [(1, 1), (1, 6), (8, 3), (3, 4), (5, 5), (8, 9)];
+
+    /// Counts the nearest locations in a box far around the sites. The count of a region grows with
+    /// the box exactly when the region is infinite.
+    fn brute_force(sites: &[Location], metric: Metric, margin: isize) -> Vec<usize> {
+        let map = RegionMap::new(sites, metric);
+        let ((min_x, min_y), (max_x, max_y)) = map.bounds();
+        let mut counts = vec![0; sites.len()];
+
+        for y in min_y - margin..=max_y + margin {
+            for x in min_x - margin..=max_x + margin {
+                if let Some(site) = map.nearest((x, y)) {
+                    counts[site] += 1;
+                }
+            }
+        }
+
+        counts
+    }
+
+    #[test]
+    fn manhattan_test() {
+        let map = RegionMap::new(&SITES, Metric::Manhattan);
+
+        assert_eq!(vec![None, None, None, Some(9), Some(17), None], (0..6).map(|site| map.area(site)).collect::<Vec<_>>());
+        assert_eq!(Some(17), map.largest_finite_area());
+        assert_eq!(None, map.owner((5, 0)));
+        assert_eq!(Some(0), map.owner((0, 0)));
+    }
+
+    #[test]
+    fn infinity_test() {
+        let scattered = [(0, 0), (10, 0), (5, 1), (5, 10), (4, 4), (17, 3), (9, 12), (12, 7), (2, 9), (6, 6)];
+
+        for metric in [Metric::Manhattan, Metric::Chebyshev, Metric::Euclidean] {
+            for sites in [&SITES[..], &scattered[..]] {
+                let map = RegionMap::new(sites, metric);
+                let (near, far) = (brute_force(sites, metric, 30), brute_force(sites, metric, 60));
+
+                for site in 0..sites.len() {
+                    assert_eq!(near[site] != far[site], map.is_infinite(site), "{:?} {:?}", metric, sites[site]);
+                    assert_eq!(map.area(site).unwrap_or(far[site]), far[site], "{:?} {:?}", metric, sites[site]);
+                }
+            }
+        }
+
+        // the site inside reaches far below the others
+        let map = RegionMap::new(&[(0, 0), (10, 0), (5, 1), (5, 10)], Metric::Euclidean);
+        assert_eq!(vec![true, true, false, true], (0..4).map(|site| map.is_infinite(site)).collect::<Vec<_>>());
+        assert_eq!(Some(2), map.owner((5, -11)));
+
+        // sites on a hull edge and sites on top of each other
+        let map = RegionMap::new(&[(0, 0), (2, 0), (4, 0), (2, 2), (2, 2)], Metric::Euclidean);
+        assert_eq!(vec![true, true, true, false, false], (0..5).map(|site| map.is_infinite(site)).collect::<Vec<_>>());
+        assert_eq!(Some(0), map.area(3));
+    }
+}
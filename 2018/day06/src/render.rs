@@ -0,0 +1,128 @@
+//! Pictures of a region map for debugging.
+
+use std::fmt::Write;
+
+use crate::regions::RegionMap;
+
+fn letter(site: usize) -> char {
+    (b'a' + (site % 26) as u8) as char
+}
+
+/// The map drawn the way the puzzle does: each location as the letter of its nearest site,
+/// uppercase at the site itself, and `.` where sites tie. The letters repeat after the 26th site.
+pub fn ascii(map: &RegionMap) -> String {
+    let ((min_x, min_y), (max_x, max_y)) = map.bounds();
+    let mut out = String::new();
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            out.push(match map.owner((x, y)) {
+                Some(site) if map.sites()[site] == (x, y) => letter(site).to_ascii_uppercase(),
+                Some(site) => letter(site),
+                None => '.',
+            });
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+fn color(map: &RegionMap, owner: Option<usize>) -> String {
+    match owner {
+        // the golden angle keeps the hues of neighbouring indices apart
+        Some(site) if map.is_infinite(site) => format!("hsl({}, 35%, 85%)", site * 137 % 360),
+        Some(site) => format!("hsl({}, 70%, 55%)", site * 137 % 360),
+        None => "white".to_string(),
+    }
+}
+
+/// The map as an SVG image with `cell` pixels per location. Infinite regions are washed out, each
+/// site is a dot titled with its index and location.
+pub fn svg(map: &RegionMap, cell: usize) -> String {
+    let ((min_x, min_y), (max_x, max_y)) = map.bounds();
+    let (width, height) = ((max_x - min_x + 1).max(0) as usize, (max_y - min_y + 1).max(0) as usize);
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" shape-rendering="crispEdges">"#,
+        width * cell,
+        height * cell
+    )
+    .unwrap();
+
+    for (row, y) in (min_y..=max_y).enumerate() {
+        let mut x = min_x;
+
+        // one rectangle for each run of locations with the same owner
+        while x <= max_x {
+            let owner = map.owner((x, y));
+            let start = x;
+
+            while x <= max_x && map.owner((x, y)) == owner {
+                x += 1;
+            }
+
+            writeln!(
+                out,
+                r#"  <rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                (start - min_x) as usize * cell,
+                row * cell,
+                (x - start) as usize * cell,
+                cell,
+                color(map, owner)
+            )
+            .unwrap();
+        }
+    }
+
+    for (site, &(x, y)) in map.sites().iter().enumerate() {
+        writeln!(
+            out,
+            r#"  <circle cx="{}" cy="{}" r="{}" fill="black"><title>{} ({}, {})</title></circle>"#,
+            (x - min_x) as f64 * cell as f64 + cell as f64 / 2.0,
+            (y - min_y) as f64 * cell as f64 + cell as f64 / 2.0,
+            (cell as f64 / 2.0).max(1.0),
+            site,
+            x,
+            y
+        )
+        .unwrap();
+    }
+
+    out.push_str("</svg>\n");
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ascii, svg};
+    use crate::regions::{Metric, RegionMap};
+
+    #[test]
+    fn render_test() {
+        let map = RegionMap::new(&[(1, 1), (1, 6), (8, 3), (3, 4), (5, 5), (8, 9)], Metric::Manhattan);
+
+        // the puzzle's picture without the locations outside the box of the sites
+        let expected = "Aaaa.ccc\n\
+                        aaddeccc\n\
+                        adddeccC\n\
+                        .dDdeecc\n\
+                        b.deEeec\n\
+                        Bb.eeee.\n\
+                        bb.eeeff\n\
+                        bb.eefff\n\
+                        bb.ffffF\n";
+
+        assert_eq!(expected, ascii(&map));
+
+        let image = svg(&map, 10);
+        assert!(image.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="80" height="90""#));
+        assert!(image.contains(r#"<rect x="0" y="0" width="40" height="10" fill="hsl(0, 35%, 85%)"/>"#));
+        assert!(image.contains(r#"<circle cx="45" cy="45" r="5" fill="black"><title>4 (5, 5)</title></circle>"#));
+        assert!(image.ends_with("</svg>\n"));
+    }
+}
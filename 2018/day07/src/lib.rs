@@ -5,8 +5,7 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use std::str::FromStr;
 
-use std::io;
-use std::io::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 lazy_static! {
     static ref PREREQ_RE: Regex = Regex::new(
@@ -15,11 +14,8 @@ lazy_static! {
     .unwrap();
 }
 
-#[derive(Debug)]
-struct AocError(String);
-
 #[derive(Debug, PartialEq, Eq)]
-struct Prerequisites(BTreeMap<char, HashSet<char>>);
+pub struct Prerequisites(BTreeMap<char, HashSet<char>>);
 
 impl FromStr for Prerequisites {
     type Err = AocError;
@@ -32,7 +28,7 @@ impl FromStr for Prerequisites {
 
             let caps = PREREQ_RE
                 .captures(trimmed)
-                .ok_or_else(|| AocError(format!("Invalid input: '{}'", trimmed)))?;
+                .ok_or_else(|| AocError::parse(format!("Invalid input: '{}'", trimmed)))?;
 
             let curr_ch = caps
                 .name("curr_ch")
@@ -72,7 +68,7 @@ impl Prerequisites {
                 .filter(|(_, prereqs)| prereqs.is_subset(&prereqs_fulfilled))
                 .map(|(ch, _)| ch)
                 .next()
-                .ok_or_else(|| AocError("Ran out of nodes with fulfilled prerequisites!".into()))?;
+                .ok_or_else(|| AocError::runtime("Ran out of nodes with fulfilled prerequisites!"))?;
 
             out.push(new_node_ch);
             prereqs_fulfilled.insert(new_node_ch);
@@ -124,8 +120,8 @@ impl Prerequisites {
                 .collect::<Vec<char>>();
 
             if free_workers > 0 && new_nodes.is_empty() && processing_nodes.is_empty() {
-                return Err(AocError(
-                    "Ran out of nodes with fulfilled prerequisites!".into(),
+                return Err(AocError::runtime(
+                    "Ran out of nodes with fulfilled prerequisites!",
                 ));
             }
 
@@ -146,24 +142,24 @@ impl Prerequisites {
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
-
-    let prerequisites: Prerequisites = input_str.parse().expect("parse error");
+pub struct Solution;
 
-    let step_order = prerequisites.get_steps_a().expect("invalid prerequisites");
+impl Solver for Solution {
+    type Input = Prerequisites;
 
-    println!("The step order is: {}", step_order);
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
 
-    let sim = prerequisites
-        .get_steps_b(5, 60)
-        .expect("invalid prerequisites");
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        input.get_steps_a()
+    }
 
-    println!(
-        "With 5 workers, the nodes will be processed in {} seconds.",
-        sim.0
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        input
+            .get_steps_b(5, 60)
+            .map(|sim| sim.0.to_string())
+    }
 }
 
 #[cfg(test)]
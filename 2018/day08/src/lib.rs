@@ -1,14 +1,9 @@
-use std::num::ParseIntError;
 use std::str::FromStr;
 
-use std::io;
-use std::io::prelude::*;
-
-#[derive(Debug)]
-struct AocError(String);
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug, PartialEq, Eq)]
-struct LicenseTree {
+pub struct LicenseTree {
     raw_nodes_input: Vec<usize>,
 }
 
@@ -20,7 +15,7 @@ impl FromStr for LicenseTree {
             .split_whitespace()
             .map(|val| {
                 val.parse::<usize>()
-                    .map_err(|e: ParseIntError| AocError(e.to_string()))
+                    .map_err(AocError::from)
             })
             .collect::<Result<Vec<usize>, AocError>>()?;
 
@@ -95,21 +90,26 @@ impl LicenseTree {
     }
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+pub struct Solution;
 
-    let license_tree: LicenseTree = input_str.parse().expect("invalid input");
+impl Solver for Solution {
+    type Input = LicenseTree;
 
-    println!(
-        "The sum of all metadata in the input is: {}",
-        license_tree.get_metadata_sum().expect("invalid input")
-    );
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
 
-    println!(
-        "The value of the root node is: {}",
-        license_tree.get_root_node_value().expect("invalid input")
-    );
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        input
+            .get_metadata_sum()
+            .map(|sum| sum.to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        input
+            .get_root_node_value()
+            .map(|value| value.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,8 @@
-use std::env;
-
 use std::fmt;
 use std::fmt::Display;
 
+use aoc_common::{AocError, AocResult, Solver};
+
 #[derive(Debug, Clone, Copy)]
 struct Marble {
     prev_idx: usize,
@@ -120,34 +120,38 @@ fn play_game(num_marbles: usize, num_players: usize) -> usize {
     scores.into_iter().max().unwrap()
 }
 
-fn main() {
-    let mut args = env::args();
-    args.next();
-
-    let num_players = args
-        .next()
-        .expect("missing argument")
-        .parse()
-        .expect("parse error");
-    let max_marble = args
-        .next()
-        .expect("missing argument")
-        .parse()
-        .expect("parse error");
-
-    println!(
-        "When played with {} players, and {} marbles, the high score is {}.",
-        num_players,
-        max_marble,
-        play_game(max_marble, num_players)
-    );
-
-    println!(
-        "When played with {} players, and {} marbles, the high score is {}.",
-        num_players,
-        max_marble * 100,
-        play_game(max_marble * 100, num_players)
-    );
+fn parse_game(input: &str) -> AocResult<(usize, usize)> {
+    let numbers = input
+        .split_whitespace()
+        .filter_map(|word| word.parse().ok())
+        .collect::<Vec<usize>>();
+
+    match numbers.as_slice() {
+        [num_players, max_marble] => Ok((*num_players, *max_marble)),
+        _ => Err(AocError::parse(format!("invalid game description: {:?}", input.trim()))),
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_game(input)
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let (num_players, max_marble) = *input;
+
+        Ok(play_game(max_marble, num_players).to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let (num_players, max_marble) = *input;
+
+        Ok(play_game(max_marble * 100, num_players).to_string())
+    }
 }
 
 #[cfg(test)]
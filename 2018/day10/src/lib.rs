@@ -3,14 +3,10 @@ use std::str::FromStr;
 use std::fmt::Display;
 use std::fmt;
 
-use std::io;
-use std::io::prelude::*;
-
 use lazy_static::lazy_static;
 use regex::Regex;
 
-#[derive(Debug)]
-struct AocError(String);
+use aoc_common::{AocError, AocResult, Solver};
 
 lazy_static! {
     static ref LIGHT_RE: Regex = Regex::new(r"^position=<\s*(?P<pos_x>-?[0-9]+),\s*(?P<pos_y>-?[0-9]+)> velocity=<\s*(?P<vel_x>-?[0-9]+),\s*(?P<vel_y>-?[0-9]+)>$").unwrap();
@@ -28,7 +24,7 @@ impl FromStr for Light {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let caps = LIGHT_RE
             .captures(s.trim())
-            .ok_or_else(|| AocError(format!("Invalid input: {:?}", s)))?;
+            .ok_or_else(|| AocError::parse(format!("Invalid input: {:?}", s)))?;
 
         let pos_x = caps.name("pos_x").unwrap().as_str().parse().unwrap();
         let pos_y = caps.name("pos_y").unwrap().as_str().parse().unwrap();
@@ -44,7 +40,7 @@ impl FromStr for Light {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Lights(Vec<Light>);
+pub struct Lights(Vec<Light>);
 
 impl FromStr for Lights {
     type Err = AocError;
@@ -68,7 +64,7 @@ impl Display for Lights {
 
             for y in 0..=size_y {
                 for x in 0..=size_x {
-                    let ch = if self.0.iter().filter(|light| light.pos == (min.0 + x, min.1 + y)).next().is_some() { '#' } else { '.' };
+                    let ch = if self.0.iter().find(|light| light.pos == (min.0 + x, min.1 + y)).is_some() { '#' } else { '.' };
                     out.push(ch);
                 }
 
@@ -127,7 +123,7 @@ impl Lights {
     }
 
     fn run_until_local_minimum(&mut self) -> Result<usize, AocError> {
-        let (prev_min, prev_max) = self.find_pos_min_max().ok_or_else(|| AocError("no lights defined".into()))?;
+        let (prev_min, prev_max) = self.find_pos_min_max().ok_or_else(|| AocError::runtime("no lights defined"))?;
 
         let mut prev_size_x = prev_max.0 - prev_min.0;
         let mut prev_size_y = prev_max.1 - prev_min.1;
@@ -155,25 +151,31 @@ impl Lights {
 }
 
 
+pub struct Solution;
 
+impl Solver for Solution {
+    type Input = Lights;
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
 
-    let mut lights: Lights = input_str.parse().expect("invalid input");
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut lights = input.clone();
+        lights.run_until_local_minimum()?;
 
-    let num_steps = lights.run_until_local_minimum().unwrap();
+        Ok(format!("\n{}", lights))
+    }
 
-    println!(
-        "Local minimum found after {} steps",
-        num_steps
-    );
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let mut lights = input.clone();
 
-    println!("{}", lights);
+        lights
+            .run_until_local_minimum()
+            .map(|num_steps| num_steps.to_string())
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::Lights;
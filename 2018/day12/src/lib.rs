@@ -6,11 +6,7 @@ use std::str::FromStr;
 
 use std::collections::HashSet;
 
-use std::io;
-use std::io::prelude::*;
-
-#[derive(Debug)]
-struct AocError(String);
+use aoc_common::{AocError, AocResult, Solver};
 
 lazy_static! {
     static ref INITIAL_STATE_RE: Regex = Regex::new(r"^initial state: (?P<state>[\.#]+)$").unwrap();
@@ -19,7 +15,7 @@ lazy_static! {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Plants {
+pub struct Plants {
     plant_locations: Vec<bool>,
     possible_patterns: HashSet<Vec<bool>>,
     left_offset: isize,
@@ -33,12 +29,12 @@ impl FromStr for Plants {
 
         let initial_state_line = lines
             .next()
-            .ok_or_else(|| AocError("missing line with initial state".into()))?;
+            .ok_or_else(|| AocError::parse("missing line with initial state"))?;
 
         let state_str = INITIAL_STATE_RE
             .captures(initial_state_line)
             .ok_or_else(|| {
-                AocError(format!(
+                AocError::parse(format!(
                     "invalid initial state line: {:?}",
                     initial_state_line
                 ))
@@ -52,7 +48,7 @@ impl FromStr for Plants {
             let plant_state = match state_ch {
                 '#' => true,
                 '.' => false,
-                other => return Err(AocError(format!("invalid plant state: {:?}", other))),
+                other => return Err(AocError::parse(format!("invalid plant state: {:?}", other))),
             };
 
             plant_locations.push(plant_state);
@@ -61,7 +57,7 @@ impl FromStr for Plants {
         let mut possible_patterns = HashSet::new();
         for pattern_line in lines.filter(|line| !line.is_empty()) {
             let cap = PATTERN_RE.captures(pattern_line).ok_or_else(|| {
-                AocError(format!(
+                AocError::parse(format!(
                     "invalid plant pattern string: {:?}",
                     pattern_line.trim()
                 ))
@@ -74,7 +70,7 @@ impl FromStr for Plants {
                 let plant_state = match pat_ch {
                     '#' => true,
                     '.' => false,
-                    other => return Err(AocError(format!("invalid plant state: {:?}", other))),
+                    other => return Err(AocError::parse(format!("invalid plant state: {:?}", other))),
                 };
 
                 pat.push(plant_state);
@@ -126,7 +122,7 @@ impl Plants {
             .iter()
             .enumerate()
             .filter(|(_, plant_present)| **plant_present)
-            .map(|(pos, _)| pos as isize - self.left_offset as isize)
+            .map(|(pos, _)| pos as isize - self.left_offset)
             .sum()
     }
 }
@@ -147,30 +143,58 @@ fn pad_vec(input: &[bool], pad_count: usize) -> (isize, Vec<bool>) {
     (pad_count as isize - leading_false as isize, out)
 }
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+/// Number of consecutive generations with the same growth needed to consider the pattern stable.
+const STABLE_GENERATIONS: usize = 100;
+
+/// Simulates the plants until the pot sum grows linearly, then extrapolates the sum
+/// to the requested generation.
+fn extrapolate_plant_count(plants: &Plants, num_generations: usize) -> isize {
+    let mut plants = plants.clone();
 
-    let mut plants: Plants = input_str.parse().expect("parsing error");
+    let mut generation = 0;
+    let mut last_count = plants.count_plants();
+    let mut last_diff = 0;
+    let mut stable_for = 0;
+
+    while generation < num_generations && stable_for < STABLE_GENERATIONS {
+        plants.do_step();
+        generation += 1;
 
-    plants.run_simulation(20);
-    println!(
-        "The sum of the numbers of all pots is: {}",
-        plants.count_plants()
-    );
+        let count = plants.count_plants();
+        let diff = count - last_count;
 
-    // Print out some values for analysis
-    let mut plants: Plants = input_str.parse().expect("parsing error");
-    for i in 0..1_000_000 {
-        if i % 10_000 == 0 {
-            println!("{} {}", i, plants.count_plants());
+        if diff == last_diff {
+            stable_for += 1;
+        } else {
+            stable_for = 0;
         }
 
-        plants.do_step();
+        last_count = count;
+        last_diff = diff;
     }
 
-    // After a while, the result is f(x) = 22 * x + 475
-    println!("{}", 22i64 * 50_000_000_000i64 + 475i64)
+    last_count + (num_generations - generation) as isize * last_diff
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Plants;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let mut plants = input.clone();
+        plants.run_simulation(20);
+
+        Ok(plants.count_plants().to_string())
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(extrapolate_plant_count(input, 50_000_000_000).to_string())
+    }
 }
 
 #[cfg(test)]
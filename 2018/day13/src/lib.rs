@@ -3,11 +3,7 @@ use std::str::FromStr;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
-use std::io;
-use std::io::prelude::*;
-
-#[derive(Debug, PartialEq, Eq)]
-struct AocError(String);
+use aoc_common::{AocError, AocResult, Solver};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 enum TrackType {
@@ -44,7 +40,7 @@ impl Train {
 type Location = (usize, usize);
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Railroad {
+pub struct Railroad {
     tracks: HashMap<Location, TrackType>,
     trains: BTreeMap<Location, Train>,
 }
@@ -88,7 +84,7 @@ impl FromStr for Railroad {
                     }
                     ' ' | '\n' => {}
                     _ => {
-                        return Err(AocError(format!(
+                        return Err(AocError::parse(format!(
                             "Invalid character in input map '{}' at line {}, column {}.",
                             ch, y, x
                         )))
@@ -116,7 +112,7 @@ impl Railroad {
 
         loop {
             if self.trains.len() <= 1 {
-                let last_train_loc = self.trains.keys().next().and_then(|val| Some(*val));
+                let last_train_loc = self.trains.keys().next().copied();
 
                 return Ok((last_train_loc, first_crash_loc));
             }
@@ -131,13 +127,13 @@ impl Railroad {
                 };
 
                 let next_loc = match curr_train.dir {
-                    Direction::North => (curr_train_loc.0.checked_sub(1).ok_or_else(|| AocError("train ran off the tracks".to_string()))?, curr_train_loc.1),
+                    Direction::North => (curr_train_loc.0.checked_sub(1).ok_or_else(|| AocError::runtime("train ran off the tracks"))?, curr_train_loc.1),
                     Direction::East => (curr_train_loc.0, curr_train_loc.1 + 1),
                     Direction::South => (curr_train_loc.0 + 1, curr_train_loc.1),
-                    Direction::West => (curr_train_loc.0, curr_train_loc.1.checked_sub(1).ok_or_else(|| AocError("train ran off the tracks".to_string()))?),
+                    Direction::West => (curr_train_loc.0, curr_train_loc.1.checked_sub(1).ok_or_else(|| AocError::runtime("train ran off the tracks"))?),
                 };
 
-                let next_track = self.tracks.get(&next_loc).ok_or_else(|| AocError("train ran off the tracks".to_string()))?;
+                let next_track = self.tracks.get(&next_loc).ok_or_else(|| AocError::runtime("train ran off the tracks"))?;
 
                 let new_dir = match curr_train.dir {
                     Direction::North => {
@@ -145,7 +141,7 @@ impl Railroad {
                             TrackType::Vertical => Direction::North,
                             TrackType::TurnSlash => Direction::East,
                             TrackType::TurnBackslash => Direction::West,
-                            TrackType::Horizontal => return Err(AocError("encountered crossing track with no intersection".to_string())),
+                            TrackType::Horizontal => return Err(AocError::runtime("encountered crossing track with no intersection")),
                             TrackType::Intersection => {
                                 let dir = match curr_train.intersection_cnt {
                                     0 => Direction::West, // left
@@ -163,7 +159,7 @@ impl Railroad {
                     },
                     Direction::East => {
                         match next_track {
-                            TrackType::Vertical => return Err(AocError("encountered crossing track with no intersection".to_string())),
+                            TrackType::Vertical => return Err(AocError::runtime("encountered crossing track with no intersection")),
                             TrackType::TurnSlash => Direction::North,
                             TrackType::TurnBackslash => Direction::South,
                             TrackType::Horizontal => Direction::East,
@@ -187,7 +183,7 @@ impl Railroad {
                             TrackType::Vertical => Direction::South,
                             TrackType::TurnSlash => Direction::West,
                             TrackType::TurnBackslash => Direction::East,
-                            TrackType::Horizontal => return Err(AocError("encountered crossing track with no intersection".to_string())),
+                            TrackType::Horizontal => return Err(AocError::runtime("encountered crossing track with no intersection")),
                             TrackType::Intersection => {
                                 let dir = match curr_train.intersection_cnt {
                                     0 => Direction::East, // left
@@ -205,7 +201,7 @@ impl Railroad {
                     },
                     Direction::West => {
                         match next_track {
-                            TrackType::Vertical => return Err(AocError("encountered crossing track with no intersection".to_string())),
+                            TrackType::Vertical => return Err(AocError::runtime("encountered crossing track with no intersection")),
                             TrackType::TurnSlash => Direction::South,
                             TrackType::TurnBackslash => Direction::North,
                             TrackType::Horizontal => Direction::West,
@@ -242,16 +238,37 @@ impl Railroad {
 }
 
 
-fn main() {
-    let mut input_str = String::new();
-    io::stdin().read_to_string(&mut input_str).unwrap();
+fn run_railroad(railroad: &Railroad) -> AocResult<(Location, Location)> {
+    let mut railroad = railroad.clone();
+
+    let (last_train_at, first_crash_at) = railroad.run_until_last()?;
+
+    Ok((
+        last_train_at.ok_or_else(|| AocError::unsolvable("no train left"))?,
+        first_crash_at.ok_or_else(|| AocError::unsolvable("no crash occurred"))?,
+    ))
+}
+
+pub struct Solution;
 
-    let mut railroad: Railroad = input_str.parse().expect("parsing error");
+impl Solver for Solution {
+    type Input = Railroad;
 
-    let (last_train_at, first_crash_at) = railroad.run_until_last().unwrap();
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input.parse()
+    }
 
-    println!("The first crash occurred at ({}, {})", first_crash_at.unwrap().1, first_crash_at.unwrap().0);
-    println!("Last train remaining is at ({}, {})", last_train_at.unwrap().1, last_train_at.unwrap().0);
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        let (_, first_crash_at) = run_railroad(input)?;
+
+        Ok(format!("{},{}", first_crash_at.1, first_crash_at.0))
+    }
+
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        let (last_train_at, _) = run_railroad(input)?;
+
+        Ok(format!("{},{}", last_train_at.1, last_train_at.0))
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
-use rayon::prelude::*;
+use aoc_common::{AocError, AocResult, Solver};
 
 fn split_even_digit_number(n: u64) -> Option<(u64, u64)> {
     let n_as_str = n.to_string();
     let len = n_as_str.len();
-    if len % 2 == 0 {
+    if len.is_multiple_of(2) {
         let half = len / 2;
         let first = n_as_str[..half].parse().unwrap();
         let second = n_as_str[half..].parse().unwrap();
@@ -33,9 +33,7 @@ fn expand_number(n: u64) -> Number {
 fn run_puzzle(num_iterations: usize, input: Vec<u64>) -> Vec<u64> {
     let mut input = input;
 
-    for i in 0..num_iterations {
-        println!("Running iteration {}", i);
-
+    for _ in 0..num_iterations {
         let mut output = Vec::new();
         
         for num in &input {
@@ -54,6 +52,7 @@ fn run_puzzle(num_iterations: usize, input: Vec<u64>) -> Vec<u64> {
     input
 }
 
+#[cfg(test)]
 fn run_copilots_solution(num_iterations: usize, input: Vec<u64>) -> Vec<u64> {
     let mut stones = input.iter().map(|&n| n.to_string()).collect::<Vec<String>>();
 
@@ -66,7 +65,7 @@ fn run_copilots_solution(num_iterations: usize, input: Vec<u64>) -> Vec<u64> {
     stones.iter().map(|s| s.parse().unwrap()).collect()
 }
 
-
+#[cfg(test)]
 fn transform_stones(stones: &[String], memo: &mut HashMap<Vec<String>, Vec<String>>) -> Vec<String> {
     if let Some(cached) = memo.get(stones) {
         return cached.clone();
@@ -83,8 +82,9 @@ fn transform_stones(stones: &[String], memo: &mut HashMap<Vec<String>, Vec<Strin
     new_stones
 }
 
-fn transform_stone(stone: &String) -> Vec<String> {
-    let num: u32 = stone.parse().unwrap();
+#[cfg(test)]
+fn transform_stone(stone: &str) -> Vec<String> {
+    let num: u64 = stone.parse().unwrap();
 
     if num == 0 {
         return vec!["1".to_string()];
@@ -92,28 +92,67 @@ fn transform_stone(stone: &String) -> Vec<String> {
 
     let digits = stone.chars().count();
 
-    if digits % 2 == 0 {
+    if digits.is_multiple_of(2) {
         let mid = digits / 2;
         let left = &stone[..mid];
-        let right = &stone[mid..];
+        // Parse the right half back to drop its leading zeros
+        let right: u64 = stone[mid..].parse().unwrap();
         return vec![left.to_string(), right.to_string()];
     }
 
     let new_value = num * 2024;
-    return vec![new_value.to_string()];
+    vec![new_value.to_string()]
+}
+
+/// Counts the stones after the given number of blinks.
+///
+/// Stones with the same number evolve the same way, so only the count of each
+/// distinct number is tracked instead of the whole row.
+fn count_stones(num_iterations: usize, input: &[u64]) -> usize {
+    let mut counts: HashMap<u64, usize> = HashMap::new();
+
+    for &num in input {
+        *counts.entry(num).or_insert(0) += 1;
+    }
+
+    for _ in 0..num_iterations {
+        let mut new_counts = HashMap::new();
+
+        for (num, count) in counts {
+            match expand_number(num) {
+                Number::Single(n) => *new_counts.entry(n).or_insert(0) += count,
+                Number::Double(first, second) => {
+                    *new_counts.entry(first).or_insert(0) += count;
+                    *new_counts.entry(second).or_insert(0) += count;
+                }
+            }
+        }
+
+        counts = new_counts;
+    }
+
+    counts.values().sum()
 }
 
+pub struct Solution;
 
+impl Solver for Solution {
+    type Input = Vec<u64>;
 
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        input
+            .split_whitespace()
+            .map(|num| num.parse().map_err(AocError::from))
+            .collect()
+    }
 
-fn main() {
-    let input = vec![27, 10647, 103, 9, 0, 5524, 4594227, 902936];
-    let result = run_puzzle(25, input);
-    println!("Part 1: I have {} stones.", result.len());
+    fn part_a(input: &Self::Input) -> AocResult<String> {
+        Ok(run_puzzle(25, input.clone()).len().to_string())
+    }
 
-    let input = vec![27, 10647, 103, 9, 0, 5524, 4594227, 902936];
-    let result = run_puzzle(75, input);
-    println!("Part 2: I have {} stones.", result.len());
+    fn part_b(input: &Self::Input) -> AocResult<String> {
+        Ok(count_stones(75, input).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -133,6 +172,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn count_stones_test() {
+        assert_eq!(22, super::count_stones(6, &[125, 17]));
+        assert_eq!(55312, super::count_stones(25, &[125, 17]));
+    }
+
     #[test]
     fn test_copilots_solution() {
         let input = vec![125, 17];
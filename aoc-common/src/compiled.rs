@@ -0,0 +1,253 @@
+//! Ahead-of-time compilation of programs to closures.
+//!
+//! `Machine` decodes every instruction each time it runs it. A `CompiledMachine` runs the same
+//! programs with the same semantics, but turns every instruction into a closure once, with its
+//! registers resolved to register file slots and the work depending only on constants done.
+
+use std::fmt;
+
+use crate::vm::{self, Control, Execute, Fault, InstructionSet, Operand, Register, Status, Step, VmError};
+
+/// A compiled instruction.
+pub type Op<S> = Box<
+    dyn Fn(&mut <S as InstructionSet>::State) -> Result<Step<<S as InstructionSet>::Event>, Fault> + Send,
+>;
+
+/// Instruction sets whose instructions can be compiled to closures.
+pub trait Compile: InstructionSet {
+    /// The closure doing what `execute` does for `instr`.
+    fn compile(instr: &Self::Instr) -> Op<Self>;
+}
+
+/// An operand with its register resolved to a register file slot.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Slot<W> {
+    Reg(usize),
+    Imm(W),
+}
+
+impl<W: Copy> Slot<W> {
+    pub fn value(self, slots: &[W]) -> W {
+        match self {
+            Slot::Reg(slot) => slots[slot],
+            Slot::Imm(val) => val,
+        }
+    }
+}
+
+impl<R: Register, W> From<Operand<R, W>> for Slot<W> {
+    fn from(operand: Operand<R, W>) -> Slot<W> {
+        match operand {
+            Operand::Reg(reg) => Slot::Reg(reg.index()),
+            Operand::Imm(val) => Slot::Imm(val),
+        }
+    }
+}
+
+/// Runs a compiled program of the instruction set `S`, behaving exactly like `Machine`.
+pub struct CompiledMachine<S: Compile> {
+    program: Vec<S::Instr>,
+    ops: Vec<Op<S>>,
+    state: S::State,
+    control: Control,
+}
+
+impl<S: Compile> CompiledMachine<S> {
+    pub fn new(program: Vec<S::Instr>, state: S::State) -> CompiledMachine<S> {
+        let ops = program.iter().map(S::compile).collect();
+
+        CompiledMachine {
+            program,
+            ops,
+            state,
+            control: Control::default(),
+        }
+    }
+
+    /// Makes `run` fail once the machine has executed `limit` instructions in total.
+    pub fn with_step_limit(mut self, limit: u64) -> CompiledMachine<S> {
+        self.control.step_limit = Some(limit);
+        self
+    }
+
+    /// The program, including the changes made by `Step::Rewrite`.
+    pub fn program(&self) -> &[S::Instr] {
+        &self.program
+    }
+
+    pub fn state(&self) -> &S::State {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut S::State {
+        &mut self.state
+    }
+
+    pub fn pc(&self) -> usize {
+        self.control.pc
+    }
+
+    /// Number of instructions executed so far.
+    pub fn steps(&self) -> u64 {
+        self.control.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.control.pc >= self.ops.len()
+    }
+
+    /// The instruction the program counter points to.
+    pub fn current(&self) -> Option<&S::Instr> {
+        self.program.get(self.control.pc)
+    }
+
+    /// Executes a single instruction.
+    pub fn step(&mut self) -> Result<Status<S::Event>, VmError> {
+        vm::step(self)
+    }
+
+    /// Runs until the program halts or an instruction pauses the machine.
+    pub fn run(&mut self) -> Result<Status<S::Event>, VmError> {
+        vm::run(self)
+    }
+
+    /// Like `run`, but executes at most `max_steps` instructions.
+    pub fn run_steps(&mut self, max_steps: u64) -> Result<Status<S::Event>, VmError> {
+        vm::run_steps(self, max_steps)
+    }
+}
+
+impl<S: Compile> Execute for CompiledMachine<S> {
+    type Event = S::Event;
+
+    fn control(&mut self) -> &mut Control {
+        &mut self.control
+    }
+
+    fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    fn execute(&mut self, pc: usize) -> Result<Step<S::Event>, Fault> {
+        (self.ops[pc])(&mut self.state)
+    }
+
+    /// Rewrites the instruction and compiles it again.
+    fn rewrite(&mut self, target: usize) -> Result<(), Fault> {
+        let rewritten = S::rewrite(&self.program[target])?;
+
+        self.ops[target] = S::compile(&rewritten);
+        self.program[target] = rewritten;
+        Ok(())
+    }
+}
+
+impl<S: Compile> fmt::Debug for CompiledMachine<S>
+where
+    S::State: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CompiledMachine")
+            .field("program", &self.program)
+            .field("state", &self.state)
+            .field("pc", &self.control.pc)
+            .field("steps", &self.control.steps)
+            .field("step_limit", &self.control.step_limit)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compile, CompiledMachine, Op, Slot};
+    use crate::vm::{Fault, InstructionSet, Machine, Operand, Register, RegisterFile, Status, Step};
+
+    type Value = Operand<char, i64>;
+
+    #[derive(Debug, Clone)]
+    enum Instr {
+        Add(char, Value),
+        Out(Value),
+        Jnz(Value, isize),
+    }
+
+    struct Toy;
+
+    impl InstructionSet for Toy {
+        type Instr = Instr;
+        type State = RegisterFile<char, i64>;
+        type Event = i64;
+
+        fn execute(instr: &Instr, regs: &mut Self::State) -> Result<Step<i64>, Fault> {
+            match instr {
+                Instr::Add(reg, val) => {
+                    regs[*reg] += val.value(regs);
+                    Ok(Step::Next)
+                }
+                Instr::Out(val) => Ok(Step::Emit(val.value(regs))),
+                Instr::Jnz(val, offset) if val.value(regs) != 0 => Ok(Step::Jump(*offset)),
+                Instr::Jnz(..) => Ok(Step::Next),
+            }
+        }
+    }
+
+    impl Compile for Toy {
+        fn compile(instr: &Instr) -> Op<Toy> {
+            match *instr {
+                Instr::Add(reg, val) => {
+                    let (reg, val) = (reg.index(), Slot::from(val));
+
+                    Box::new(move |regs| {
+                        let val = val.value(regs.slots());
+                        regs.slots_mut()[reg] += val;
+
+                        Ok(Step::Next)
+                    })
+                }
+                Instr::Out(val) => {
+                    let val = Slot::from(val);
+
+                    Box::new(move |regs| Ok(Step::Emit(val.value(regs.slots()))))
+                }
+                Instr::Jnz(Operand::Imm(0), _) => Box::new(|_| Ok(Step::Next)),
+                Instr::Jnz(Operand::Imm(_), offset) => Box::new(move |_| Ok(Step::Jump(offset))),
+                Instr::Jnz(val, offset) => {
+                    let val = Slot::from(val);
+
+                    Box::new(move |regs| {
+                        Ok(if val.value(regs.slots()) != 0 {
+                            Step::Jump(offset)
+                        } else {
+                            Step::Next
+                        })
+                    })
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn compiled_test() {
+        let program = vec![
+            Instr::Add('a', Operand::Imm(3)),
+            Instr::Out(Operand::Reg('a')),
+            Instr::Add('a', Operand::Imm(-1)),
+            Instr::Jnz(Operand::Reg('a'), -2),
+            Instr::Jnz(Operand::Imm(0), -4),
+        ];
+
+        let mut machine = Machine::<Toy>::new(program.clone(), RegisterFile::new());
+        let mut compiled = CompiledMachine::<Toy>::new(program, RegisterFile::new()).with_step_limit(11);
+
+        for _ in 0..4 {
+            assert_eq!(machine.run(), compiled.run());
+        }
+
+        assert_eq!(Ok(Status::Halted), compiled.run());
+        assert_eq!(machine.steps(), compiled.steps());
+        assert_eq!(machine.state(), compiled.state());
+
+        let mut compiled = CompiledMachine::<Toy>::new(vec![Instr::Jnz(Operand::Imm(1), -1)], RegisterFile::new());
+        assert_eq!(Fault::PcOutOfRange(-1), compiled.run().unwrap_err().fault);
+    }
+}
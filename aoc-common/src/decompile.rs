@@ -0,0 +1,653 @@
+//! Control-flow graphs and structured pseudo-code for programs with relative jumps.
+//!
+//! An instruction set only has to say how control leaves each instruction and what the others
+//! do, as `Decompile`. The program is then split into basic blocks for the `Cfg`, and
+//! `decompile` turns backward jumps into loops and forward conditional jumps into if/else
+//! statements. Jumps that fit neither shape are kept as `goto`s to labelled instructions.
+
+use std::collections::BTreeSet;
+use std::fmt::{self, Write};
+
+use crate::vm::InstructionSet;
+
+/// A branch condition, with both ways of writing it so that either can be printed without
+/// having to negate an expression.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Condition {
+    pub holds: String,
+    pub fails: String,
+}
+
+impl Condition {
+    pub fn new<H: Into<String>, F: Into<String>>(holds: H, fails: F) -> Condition {
+        Condition {
+            holds: holds.into(),
+            fails: fails.into(),
+        }
+    }
+
+    pub fn negate(self) -> Condition {
+        Condition {
+            holds: self.fails,
+            fails: self.holds,
+        }
+    }
+}
+
+/// How control leaves an instruction.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Flow {
+    /// Continues with the next instruction.
+    Next,
+    /// Always jumps by the offset.
+    Jump(isize),
+    /// Jumps by the offset if the condition holds, and continues with the next instruction
+    /// otherwise.
+    Branch(isize, Condition),
+    /// Jumps to a target only known at run time, described by the string.
+    Computed(String),
+}
+
+/// Instruction sets whose programs can be decompiled.
+pub trait Decompile: InstructionSet
+where
+    Self::Instr: fmt::Display,
+{
+    fn flow(instr: &Self::Instr) -> Flow;
+
+    /// The pseudo-code of an instruction whose flow is `Flow::Next`, e.g. `a *= 3`.
+    fn statement(instr: &Self::Instr) -> String;
+}
+
+/// Where an edge of the control-flow graph leads.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Target {
+    Block(usize),
+    /// Leaving the program past its last instruction, which halts it.
+    Exit,
+    /// Jumping before the first instruction, which is a fault.
+    Fault,
+    /// A computed jump.
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Edge {
+    pub target: Target,
+    /// The condition under which the edge is taken, if it is one of two.
+    pub condition: Option<String>,
+}
+
+/// A run of instructions only entered at its first and only left after its last one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Block {
+    pub start: usize,
+    pub end: usize,
+    pub edges: Vec<Edge>,
+}
+
+/// The control-flow graph of a program.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cfg {
+    pub blocks: Vec<Block>,
+}
+
+impl Cfg {
+    pub fn new<S: Decompile>(program: &[S::Instr]) -> Cfg
+    where
+        S::Instr: fmt::Display,
+    {
+        let flows = program.iter().map(S::flow).collect::<Vec<_>>();
+
+        let mut leaders = BTreeSet::new();
+        leaders.insert(0);
+
+        for (pc, flow) in flows.iter().enumerate() {
+            match *flow {
+                Flow::Next => continue,
+                Flow::Jump(offset) | Flow::Branch(offset, _) => {
+                    if let Some(target) = target(pc, offset, program.len()) {
+                        leaders.insert(target);
+                    }
+                }
+                Flow::Computed(_) => {}
+            }
+
+            leaders.insert(pc + 1);
+        }
+
+        let starts = leaders.into_iter().filter(|&pc| pc < program.len()).collect::<Vec<_>>();
+
+        let block_of = |pc: isize| -> Target {
+            if pc < 0 {
+                Target::Fault
+            } else if pc as usize >= program.len() {
+                Target::Exit
+            } else {
+                Target::Block(starts.binary_search(&(pc as usize)).unwrap())
+            }
+        };
+
+        let blocks = starts
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| {
+                let end = starts.get(i + 1).cloned().unwrap_or(program.len());
+                let last = end - 1;
+                let next = Edge {
+                    target: block_of(end as isize),
+                    condition: None,
+                };
+
+                let edges = match flows[last] {
+                    Flow::Next => vec![next],
+                    Flow::Jump(offset) => vec![Edge {
+                        target: block_of(last as isize + offset),
+                        condition: None,
+                    }],
+                    Flow::Branch(offset, ref condition) => vec![
+                        Edge {
+                            target: block_of(last as isize + offset),
+                            condition: Some(condition.holds.clone()),
+                        },
+                        Edge {
+                            condition: Some(condition.fails.clone()),
+                            ..next
+                        },
+                    ],
+                    Flow::Computed(_) => vec![Edge {
+                        target: Target::Unknown,
+                        condition: None,
+                    }],
+                };
+
+                Block { start, end, edges }
+            })
+            .collect();
+
+        Cfg { blocks }
+    }
+
+    /// Renders the graph in the Graphviz DOT language, with the instructions of every block.
+    pub fn to_dot<I: fmt::Display>(&self, program: &[I]) -> String {
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph cfg {{").unwrap();
+        writeln!(dot, "    node [shape=box, fontname=\"monospace\"];").unwrap();
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            let label = (block.start..block.end)
+                .map(|pc| format!("{}: {}\\l", pc, escape(&program[pc].to_string())))
+                .collect::<String>();
+
+            writeln!(dot, "    b{} [label=\"{}\"];", i, label).unwrap();
+        }
+
+        let targets = self
+            .blocks
+            .iter()
+            .flat_map(|block| block.edges.iter().map(|edge| edge.target))
+            .collect::<Vec<_>>();
+
+        for (target, name) in &[(Target::Exit, "exit"), (Target::Fault, "fault"), (Target::Unknown, "unknown")] {
+            if targets.contains(target) {
+                writeln!(dot, "    {} [shape=oval];", name).unwrap();
+            }
+        }
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            for edge in &block.edges {
+                let target = match edge.target {
+                    Target::Block(j) => format!("b{}", j),
+                    Target::Exit => "exit".to_string(),
+                    Target::Fault => "fault".to_string(),
+                    Target::Unknown => "unknown".to_string(),
+                };
+
+                match edge.condition {
+                    Some(ref condition) => {
+                        writeln!(dot, "    b{} -> {} [label=\"{}\"];", i, target, escape(condition)).unwrap()
+                    }
+                    None => writeln!(dot, "    b{} -> {};", i, target).unwrap(),
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn target(pc: usize, offset: isize, len: usize) -> Option<usize> {
+    let target = pc as isize + offset;
+
+    if target >= 0 && (target as usize) < len {
+        Some(target as usize)
+    } else {
+        None
+    }
+}
+
+/// A statement of the structured pseudo-code.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Stmt {
+    Op(String),
+    If {
+        condition: Condition,
+        then: Vec<Stmt>,
+        otherwise: Vec<Stmt>,
+    },
+    Loop(Vec<Stmt>),
+    While(Condition, Vec<Stmt>),
+    DoWhile(Vec<Stmt>, Condition),
+    Break,
+    Continue,
+    Halt,
+    Fault,
+    Goto(usize),
+    Label(usize),
+}
+
+impl Stmt {
+    fn render(&self, depth: usize, out: &mut String) {
+        let indent = "    ".repeat(depth);
+
+        match *self {
+            Stmt::Op(ref op) => writeln!(out, "{}{}", indent, op).unwrap(),
+            Stmt::If {
+                ref condition,
+                ref then,
+                ref otherwise,
+            } => {
+                writeln!(out, "{}if {} {{", indent, condition.holds).unwrap();
+                render_block(then, depth + 1, out);
+
+                if !otherwise.is_empty() {
+                    writeln!(out, "{}}} else {{", indent).unwrap();
+                    render_block(otherwise, depth + 1, out);
+                }
+
+                writeln!(out, "{}}}", indent).unwrap();
+            }
+            Stmt::Loop(ref body) => {
+                writeln!(out, "{}loop {{", indent).unwrap();
+                render_block(body, depth + 1, out);
+                writeln!(out, "{}}}", indent).unwrap();
+            }
+            Stmt::While(ref condition, ref body) => {
+                writeln!(out, "{}while {} {{", indent, condition.holds).unwrap();
+                render_block(body, depth + 1, out);
+                writeln!(out, "{}}}", indent).unwrap();
+            }
+            Stmt::DoWhile(ref body, ref condition) => {
+                writeln!(out, "{}do {{", indent).unwrap();
+                render_block(body, depth + 1, out);
+                writeln!(out, "{}}} while {}", indent, condition.holds).unwrap();
+            }
+            Stmt::Break => writeln!(out, "{}break", indent).unwrap(),
+            Stmt::Continue => writeln!(out, "{}continue", indent).unwrap(),
+            Stmt::Halt => writeln!(out, "{}halt", indent).unwrap(),
+            Stmt::Fault => writeln!(out, "{}fault", indent).unwrap(),
+            Stmt::Goto(pc) => writeln!(out, "{}goto L{}", indent, pc).unwrap(),
+            Stmt::Label(pc) => writeln!(out, "L{}:", pc).unwrap(),
+        }
+    }
+}
+
+fn render_block(stmts: &[Stmt], depth: usize, out: &mut String) {
+    for stmt in stmts {
+        stmt.render(depth, out);
+    }
+}
+
+/// Renders the pseudo-code with four spaces of indentation per level.
+pub fn render(stmts: &[Stmt]) -> String {
+    let mut out = String::new();
+    render_block(stmts, 0, &mut out);
+    out
+}
+
+/// A loop being structured: the instruction it starts at and the one following it.
+#[derive(Copy, Clone)]
+struct Frame {
+    head: usize,
+    exit: usize,
+}
+
+struct Structurer {
+    flows: Vec<Flow>,
+    statements: Vec<String>,
+    labels: BTreeSet<usize>,
+    gotos: BTreeSet<usize>,
+}
+
+impl Structurer {
+    /// Structures the instructions from `start` up to `end`, which only jump within that range
+    /// or out of the loops in `frames`, the innermost last.
+    fn block(&mut self, start: usize, end: usize, frames: &mut Vec<Frame>) -> Vec<Stmt> {
+        let mut stmts = Vec::new();
+        let mut pc = start;
+
+        while pc < end {
+            if self.labels.contains(&pc) {
+                stmts.push(Stmt::Label(pc));
+            }
+
+            // The last jump back to this instruction closes the loop starting at it.
+            let back_edge = (pc..end).rev().find(|&from| match self.flows[from] {
+                Flow::Jump(offset) | Flow::Branch(offset, _) => from as isize + offset == pc as isize,
+                _ => false,
+            });
+
+            if let Some(from) = back_edge {
+                frames.push(Frame {
+                    head: pc,
+                    exit: from + 1,
+                });
+                let body = self.block(pc, from, frames);
+                frames.pop();
+
+                stmts.push(match self.flows[from].clone() {
+                    Flow::Branch(_, condition) => Stmt::DoWhile(body, condition),
+                    _ => simplify_loop(body),
+                });
+                pc = from + 1;
+                continue;
+            }
+
+            match self.flows[pc].clone() {
+                Flow::Next => {
+                    stmts.push(Stmt::Op(self.statements[pc].clone()));
+                    pc += 1;
+                }
+                Flow::Jump(offset) => {
+                    stmts.push(self.jump(pc, offset, frames));
+                    pc += 1;
+                }
+                Flow::Branch(offset, condition) => {
+                    let target = pc as isize + offset;
+
+                    if target > pc as isize + 1 && target <= end as isize {
+                        let target = target as usize;
+
+                        // A jump over the code run when the branch is not taken, which itself
+                        // ends by jumping over the code run when it is: an if/else.
+                        let skip = match self.flows[target - 1] {
+                            Flow::Jump(offset) if target - 1 > pc => Some(target as isize - 1 + offset),
+                            _ => None,
+                        };
+
+                        match skip {
+                            Some(join) if join > target as isize && join <= end as isize => {
+                                let join = join as usize;
+                                let then = self.block(pc + 1, target - 1, frames);
+                                let otherwise = self.block(target, join, frames);
+
+                                stmts.push(if then.is_empty() {
+                                    Stmt::If {
+                                        condition,
+                                        then: otherwise,
+                                        otherwise: then,
+                                    }
+                                } else {
+                                    Stmt::If {
+                                        condition: condition.negate(),
+                                        then,
+                                        otherwise,
+                                    }
+                                });
+                                pc = join;
+                            }
+                            _ => {
+                                let then = self.block(pc + 1, target, frames);
+
+                                stmts.push(Stmt::If {
+                                    condition: condition.negate(),
+                                    then,
+                                    otherwise: Vec::new(),
+                                });
+                                pc = target;
+                            }
+                        }
+                    } else {
+                        let jump = self.jump(pc, offset, frames);
+
+                        stmts.push(Stmt::If {
+                            condition,
+                            then: vec![jump],
+                            otherwise: Vec::new(),
+                        });
+                        pc += 1;
+                    }
+                }
+                Flow::Computed(target) => {
+                    stmts.push(Stmt::Op(format!("goto {}", target)));
+                    pc += 1;
+                }
+            }
+        }
+
+        stmts
+    }
+
+    fn jump(&mut self, pc: usize, offset: isize, frames: &[Frame]) -> Stmt {
+        let target = pc as isize + offset;
+
+        if target < 0 {
+            return Stmt::Fault;
+        }
+
+        let target = target as usize;
+
+        if let Some(frame) = frames.last() {
+            if target == frame.exit {
+                return Stmt::Break;
+            }
+            if target == frame.head {
+                return Stmt::Continue;
+            }
+        }
+
+        if target >= self.flows.len() {
+            Stmt::Halt
+        } else {
+            self.gotos.insert(target);
+            Stmt::Goto(target)
+        }
+    }
+}
+
+/// Turns `loop { if c { break } ... }` into `while !c { ... }`.
+fn simplify_loop(mut body: Vec<Stmt>) -> Stmt {
+    let condition = match body.first() {
+        Some(Stmt::If {
+            condition,
+            then,
+            otherwise,
+        }) if then == &[Stmt::Break] && otherwise.is_empty() => Some(condition.clone()),
+        _ => None,
+    };
+
+    match condition {
+        Some(condition) => {
+            body.remove(0);
+            Stmt::While(condition.negate(), body)
+        }
+        None => Stmt::Loop(body),
+    }
+}
+
+/// Structures the program into pseudo-code.
+pub fn decompile<S: Decompile>(program: &[S::Instr]) -> Vec<Stmt>
+where
+    S::Instr: fmt::Display,
+{
+    let flows = program.iter().map(S::flow).collect::<Vec<_>>();
+    let statements = program
+        .iter()
+        .zip(&flows)
+        .map(|(instr, flow)| match *flow {
+            Flow::Next => S::statement(instr),
+            _ => String::new(),
+        })
+        .collect();
+
+    let mut structurer = Structurer {
+        flows,
+        statements,
+        labels: BTreeSet::new(),
+        gotos: BTreeSet::new(),
+    };
+
+    let stmts = structurer.block(0, program.len(), &mut Vec::new());
+
+    if structurer.gotos.is_empty() {
+        return stmts;
+    }
+
+    // Structuring again with the goto targets known places their labels.
+    structurer.labels = structurer.gotos.clone();
+    structurer.block(0, program.len(), &mut Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::{decompile, render, Cfg, Condition, Decompile, Flow};
+    use crate::vm::{Fault, InstructionSet, RegisterFile, Step};
+
+    #[derive(Debug, Clone)]
+    enum Instr {
+        Inc(char),
+        Jmp(isize),
+        Jz(char, isize),
+    }
+
+    impl fmt::Display for Instr {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match *self {
+                Instr::Inc(reg) => write!(f, "inc {}", reg),
+                Instr::Jmp(offset) => write!(f, "jmp {}", offset),
+                Instr::Jz(reg, offset) => write!(f, "jz {} {}", reg, offset),
+            }
+        }
+    }
+
+    struct Toy;
+
+    impl InstructionSet for Toy {
+        type Instr = Instr;
+        type State = RegisterFile<char, i64>;
+        type Event = ();
+
+        fn execute(instr: &Instr, regs: &mut Self::State) -> Result<Step<()>, Fault> {
+            match *instr {
+                Instr::Inc(reg) => {
+                    regs[reg] += 1;
+                    Ok(Step::Next)
+                }
+                Instr::Jmp(offset) => Ok(Step::Jump(offset)),
+                Instr::Jz(reg, offset) if regs[reg] == 0 => Ok(Step::Jump(offset)),
+                Instr::Jz(..) => Ok(Step::Next),
+            }
+        }
+    }
+
+    impl Decompile for Toy {
+        fn flow(instr: &Instr) -> Flow {
+            match *instr {
+                Instr::Inc(_) => Flow::Next,
+                Instr::Jmp(offset) => Flow::Jump(offset),
+                Instr::Jz(reg, offset) => Flow::Branch(offset, Condition::new(format!("{} == 0", reg), format!("{} != 0", reg))),
+            }
+        }
+
+        fn statement(instr: &Instr) -> String {
+            match *instr {
+                Instr::Inc(reg) => format!("{} += 1", reg),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn structure_test() {
+        let program = vec![
+            Instr::Jz('a', 3),
+            Instr::Inc('b'),
+            Instr::Jmp(2),
+            Instr::Inc('c'),
+            Instr::Jz('b', 4),
+            Instr::Inc('a'),
+            Instr::Jz('c', 2),
+            Instr::Jmp(-3),
+            Instr::Inc('d'),
+            Instr::Inc('d'),
+            Instr::Jz('d', -1),
+        ];
+
+        let expected = "\
+if a != 0 {
+    b += 1
+} else {
+    c += 1
+}
+while b != 0 {
+    a += 1
+    if c == 0 {
+        break
+    }
+}
+d += 1
+do {
+    d += 1
+} while d == 0
+";
+
+        assert_eq!(expected, render(&decompile::<Toy>(&program)));
+    }
+
+    #[test]
+    fn goto_test() {
+        let program = vec![Instr::Jz('a', 2), Instr::Inc('b'), Instr::Inc('c'), Instr::Jz('c', -2), Instr::Jmp(2)];
+
+        let expected = "\
+if a != 0 {
+L1:
+    b += 1
+}
+c += 1
+if c == 0 {
+    goto L1
+}
+halt
+";
+
+        assert_eq!(expected, render(&decompile::<Toy>(&program)));
+    }
+
+    #[test]
+    fn cfg_test() {
+        let program = vec![Instr::Inc('a'), Instr::Jz('a', -1), Instr::Jmp(-5)];
+        let cfg = Cfg::new::<Toy>(&program);
+
+        let expected = "\
+digraph cfg {
+    node [shape=box, fontname=\"monospace\"];
+    b0 [label=\"0: inc a\\l1: jz a -1\\l\"];
+    b1 [label=\"2: jmp -5\\l\"];
+    fault [shape=oval];
+    b0 -> b0 [label=\"a == 0\"];
+    b0 -> b1 [label=\"a != 0\"];
+    b1 -> fault;
+}
+";
+
+        assert_eq!(expected, cfg.to_dot(&program));
+    }
+}
@@ -0,0 +1,165 @@
+use std::error::Error;
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::ParseBoolError;
+
+/// Error shared by all the puzzle solutions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The puzzle input could not be parsed.
+    ///
+    /// Line and column are 1-based, and are `None` when the failing parser does not know them.
+    Parse {
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    /// The input was valid, but something went wrong while solving the puzzle.
+    Runtime(String),
+    /// The input was valid, but it has no solution.
+    Unsolvable(String),
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    /// Creates a parse error without a known location.
+    pub fn parse<S: Into<String>>(message: S) -> AocError {
+        AocError::Parse {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a parse error at the given line and column.
+    pub fn parse_at<S: Into<String>>(line: usize, column: usize, message: S) -> AocError {
+        AocError::Parse {
+            line: Some(line),
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+
+    pub fn runtime<S: Into<String>>(message: S) -> AocError {
+        AocError::Runtime(message.into())
+    }
+
+    pub fn unsolvable<S: Into<String>>(message: S) -> AocError {
+        AocError::Unsolvable(message.into())
+    }
+
+    /// Attaches a line number to a parse error that does not have one yet.
+    ///
+    /// This lets single line parsers report a column, and the caller that iterates over
+    /// the lines fill in the line number.
+    pub fn at_line(self, line_num: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                line: None,
+                column,
+                message,
+            } => AocError::Parse {
+                line: Some(line_num),
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line: Some(line),
+                column: Some(column),
+                message,
+            } => write!(f, "parse error at line {}, column {}: {}", line, column, message),
+            AocError::Parse {
+                line: Some(line),
+                column: None,
+                message,
+            } => write!(f, "parse error at line {}: {}", line, message),
+            AocError::Parse {
+                line: None,
+                column: Some(column),
+                message,
+            } => write!(f, "parse error at column {}: {}", column, message),
+            AocError::Parse { message, .. } => write!(f, "parse error: {}", message),
+            AocError::Runtime(message) => write!(f, "runtime error: {}", message),
+            AocError::Unsolvable(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> AocError {
+        AocError::parse(e.to_string())
+    }
+}
+
+impl From<ParseFloatError> for AocError {
+    fn from(e: ParseFloatError) -> AocError {
+        AocError::parse(e.to_string())
+    }
+}
+
+impl From<ParseBoolError> for AocError {
+    fn from(e: ParseBoolError) -> AocError {
+        AocError::parse(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AocError;
+
+    #[test]
+    fn display_test() {
+        assert_eq!(
+            "parse error at line 3, column 7: bad digit",
+            AocError::parse_at(3, 7, "bad digit").to_string()
+        );
+        assert_eq!("parse error: bad digit", AocError::parse("bad digit").to_string());
+        assert_eq!(
+            "parse error at column 7: bad digit",
+            AocError::Parse {
+                line: None,
+                column: Some(7),
+                message: "bad digit".into(),
+            }
+            .to_string()
+        );
+        assert_eq!("runtime error: overflow", AocError::runtime("overflow").to_string());
+        assert_eq!("no solution: no path", AocError::unsolvable("no path").to_string());
+    }
+
+    #[test]
+    fn at_line_test() {
+        let err = AocError::Parse {
+            line: None,
+            column: Some(4),
+            message: "oops".into(),
+        };
+
+        assert_eq!(AocError::parse_at(2, 4, "oops"), err.at_line(2));
+
+        // Known lines are kept, other errors are untouched
+        assert_eq!(
+            AocError::parse_at(5, 1, "oops"),
+            AocError::parse_at(5, 1, "oops").at_line(2)
+        );
+        assert_eq!(AocError::runtime("oops"), AocError::runtime("oops").at_line(2));
+    }
+
+    #[test]
+    fn from_test() {
+        let err: AocError = "x1".parse::<u32>().unwrap_err().into();
+
+        assert_eq!(AocError::parse("invalid digit found in string"), err);
+    }
+}
@@ -0,0 +1,835 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Sub};
+
+use crate::error::{AocError, AocResult};
+
+/// A location on a 2D grid. `y` grows downwards, like the lines of the puzzle input.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+    }
+
+    /// The four points sharing an edge with this one.
+    pub fn neighbours(self) -> [Point; 4] {
+        [
+            self + Direction::Up.offset(),
+            self + Direction::Right.offset(),
+            self + Direction::Down.offset(),
+            self + Direction::Left.offset(),
+        ]
+    }
+
+    /// The eight points sharing an edge or a corner with this one.
+    pub fn neighbours_diagonal(self) -> [Point; 8] {
+        [
+            Point::new(self.x - 1, self.y - 1),
+            Point::new(self.x, self.y - 1),
+            Point::new(self.x + 1, self.y - 1),
+            Point::new(self.x + 1, self.y),
+            Point::new(self.x + 1, self.y + 1),
+            Point::new(self.x, self.y + 1),
+            Point::new(self.x - 1, self.y + 1),
+            Point::new(self.x - 1, self.y),
+        ]
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Point {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// The unit step in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// A rectangular grid with its top left corner at (0, 0).
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self[Point::new(pos.y, self.height as isize - 1 - pos.x)].clone()
+        })
+    }
+
+    /// The grid turned a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self[Point::new(self.width as isize - 1 - pos.y, pos.x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self[Point::new(self.width as isize - 1 - pos.x, pos.y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self[Point::new(pos.x, self.height as isize - 1 - pos.y)].clone()
+        })
+    }
+
+    /// The grid mirrored along its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| self[Point::new(pos.y, pos.x)].clone())
+    }
+
+    /// The eight ways to rotate and flip the grid, starting with the grid itself. Symmetric grids
+    /// appear more than once.
+    pub fn symmetries(&self) -> Vec<Grid<T>> {
+        let mut out = Vec::with_capacity(8);
+        let mut rotated = self.clone();
+
+        for _ in 0..4 {
+            out.push(rotated.flip_horizontal());
+            let next = rotated.rotate_right();
+            out.push(rotated);
+            rotated = next;
+        }
+
+        out.swap(0, 1);
+
+        out
+    }
+
+    /// Moves the cells of a row `by` places to the right, the last ones wrapping around to the
+    /// start.
+    pub fn shift_row(&mut self, y: usize, by: usize) {
+        let width = self.width;
+
+        if width > 0 {
+            self.cells[y * width..(y + 1) * width].rotate_right(by % width);
+        }
+    }
+
+    /// Moves the cells of a column `by` places down, the last ones wrapping around to the top.
+    pub fn shift_column(&mut self, x: usize, by: usize) {
+        let mut column = (0..self.height).map(|y| self.cells[y * self.width + x].clone()).collect::<Vec<T>>();
+
+        if !column.is_empty() {
+            let len = column.len();
+            column.rotate_right(by % len);
+        }
+
+        for (y, cell) in column.into_iter().enumerate() {
+            self.cells[y * self.width + x] = cell;
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid with each cell computed from its location.
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Grid<T>
+    where
+        F: FnMut(Point) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+            .map(&mut cell)
+            .collect();
+
+        Grid { width, height, cells }
+    }
+
+    /// Parses a grid with one cell per character, e.g. a maze made of `#` and `.`.
+    ///
+    /// All lines must have the same length. Errors returned by `parse_cell` get the cell location attached.
+    pub fn parse<F>(input: &str, mut parse_cell: F) -> AocResult<Grid<T>>
+    where
+        F: FnMut(char) -> AocResult<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut line_width = 0;
+
+            for (x, ch) in line.chars().enumerate() {
+                let cell = parse_cell(ch).map_err(|e| match e {
+                    AocError::Parse {
+                        line: None,
+                        column: None,
+                        message,
+                    } => AocError::parse_at(y + 1, x + 1, message),
+                    other => other,
+                })?;
+
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(AocError::parse_at(
+                        y + 1,
+                        line_width.min(width) + 1,
+                        format!("expected {} cells, found {}", width, line_width),
+                    ));
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[self.idx(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        if self.contains(pos) {
+            let idx = self.idx(pos);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over all the cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (Point::new((idx % width) as isize, (idx / width) as isize), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, cell)| (Point::new((idx % width) as isize, (idx / width) as isize), cell))
+    }
+
+    /// Iterates over the values of all the cells, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The part of the grid with the top left corner at `origin`.
+    ///
+    /// Panics unless the whole part lies inside the grid.
+    pub fn view(&self, origin: Point, width: usize, height: usize) -> View<'_, T> {
+        self.check_area(origin, width, height);
+
+        View {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// The part of the grid with the top left corner at `origin`, to be changed in place.
+    ///
+    /// Panics unless the whole part lies inside the grid.
+    pub fn view_mut(&mut self, origin: Point, width: usize, height: usize) -> ViewMut<'_, T> {
+        self.check_area(origin, width, height);
+
+        ViewMut {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    fn check_area(&self, origin: Point, width: usize, height: usize) {
+        let far_corner = origin + Point::new(width as isize - 1, height as isize - 1);
+
+        if width > 0 && height > 0 && !(self.contains(origin) && self.contains(far_corner)) {
+            panic!("{:?} to {:?} is outside of the grid", origin, far_corner);
+        }
+    }
+
+    /// The cells connected to `start` through edge neighbours, all of them `inside`, in the order
+    /// they are reached. Empty if `start` itself is not inside.
+    pub fn flood_fill<F>(&self, start: Point, mut inside: F) -> Vec<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut seen = vec![false; self.cells.len()];
+        let mut filled = Vec::new();
+
+        if self.get(start).is_some_and(&mut inside) {
+            seen[self.idx(start)] = true;
+            filled.push(start);
+        }
+
+        let mut next = 0;
+
+        while next < filled.len() {
+            let pos = filled[next];
+            next += 1;
+
+            for neighbour in pos.neighbours() {
+                if self.contains(neighbour) && !seen[self.idx(neighbour)] && inside(&self[neighbour]) {
+                    seen[self.idx(neighbour)] = true;
+                    filled.push(neighbour);
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// The edge neighbours of the point that lie inside the grid.
+    pub fn neighbours(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbours().into_iter().filter(move |p| self.contains(*p))
+    }
+
+    /// The edge and corner neighbours of the point that lie inside the grid.
+    pub fn neighbours_diagonal(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        pos.neighbours_diagonal()
+            .into_iter()
+            .filter(move |p| self.contains(*p))
+    }
+
+    /// Renders the grid with one character per cell, one line per row.
+    pub fn render<F>(&self, mut render_cell: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.cells.chunks(self.width.max(1)) {
+            out.extend(row.iter().map(&mut render_cell));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn idx(&self, pos: Point) -> usize {
+        pos.y as usize * self.width + pos.x as usize
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+/// A rectangular part of a grid. Its points are relative to its own top left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'g, T> {
+    grid: &'g Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'g, T> View<'g, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&'g T> {
+        if self.contains(pos) {
+            self.grid.get(self.origin + pos)
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over all the cells of the part, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'g T)> {
+        let (grid, origin, width) = (self.grid, self.origin, self.width);
+
+        (0..self.height as isize)
+            .flat_map(move |y| (0..width as isize).map(move |x| Point::new(x, y)))
+            .map(move |pos| (pos, &grid[origin + pos]))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |pos| self.grid[self.origin + pos].clone())
+    }
+}
+
+impl<'g, T> Index<Point> for View<'g, T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the view", pos))
+    }
+}
+
+/// A rectangular part of a grid that can be changed in place. Its points are relative to its own
+/// top left corner.
+#[derive(Debug)]
+pub struct ViewMut<'g, T> {
+    grid: &'g mut Grid<T>,
+    origin: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'g, T> ViewMut<'g, T> {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let (grid_width, origin, width) = (self.grid.width, self.origin, self.width);
+
+        self.grid
+            .cells
+            .chunks_mut(grid_width.max(1))
+            .skip(origin.y as usize)
+            .take(self.height)
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                row[origin.x as usize..origin.x as usize + width]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(x, cell)| (Point::new(x as isize, y as isize), cell))
+            })
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for (_, cell) in self.iter_mut() {
+            *cell = value.clone();
+        }
+    }
+
+    /// Copies the other grid over the part, top left corners together.
+    ///
+    /// Panics unless the other grid has the same size as the part.
+    pub fn paste(&mut self, other: &Grid<T>)
+    where
+        T: Clone,
+    {
+        assert_eq!((self.width, self.height), (other.width, other.height), "pasting a grid of another size");
+
+        for (pos, cell) in self.iter_mut() {
+            *cell = other[pos].clone();
+        }
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.render(|ch| *ch))
+    }
+}
+
+/// A grid without bounds, where every cell not set to anything else holds the default value. Only
+/// the other cells are stored.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// The cells of the dense grid that do not hold the default value, at the same locations.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> SparseGrid<T>
+    where
+        T: Clone,
+    {
+        let mut sparse = SparseGrid::new(default);
+
+        for (pos, cell) in grid.iter() {
+            sparse.set(pos, cell.clone());
+        }
+
+        sparse
+    }
+
+    pub fn get(&self, pos: Point) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, pos: Point, value: T) {
+        if value == self.default {
+            self.cells.remove(&pos);
+        } else {
+            self.cells.insert(pos, value);
+        }
+    }
+
+    /// The number of cells not holding the default value.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterates over the cells not holding the default value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    /// The top left and bottom right corners of the cells not holding the default value.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.cells.keys().fold(None, |bounds, pos| match bounds {
+            None => Some((*pos, *pos)),
+            Some((min, max)) => Some((
+                Point::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Point::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )),
+        })
+    }
+
+    /// The edge neighbours of the point, which are always there.
+    pub fn neighbours(&self, pos: Point) -> impl Iterator<Item = Point> {
+        pos.neighbours().into_iter()
+    }
+
+    /// The edge and corner neighbours of the point, which are always there.
+    pub fn neighbours_diagonal(&self, pos: Point) -> impl Iterator<Item = Point> {
+        pos.neighbours_diagonal().into_iter()
+    }
+
+    /// A dense copy of the cells from `min` to `max`, both included, with `min` moved to (0, 0).
+    pub fn to_grid(&self, min: Point, max: Point) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (width, height) = ((max.x - min.x + 1).max(0) as usize, (max.y - min.y + 1).max(0) as usize);
+
+        Grid::from_fn(width, height, |pos| self.get(min + pos).clone())
+    }
+
+    /// Renders the cells from `min` to `max`, both included, one character per cell and one line
+    /// per row.
+    pub fn render<F>(&self, min: Point, max: Point, mut render_cell: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        let mut out = String::new();
+
+        for y in min.y..=max.y {
+            out.extend((min.x..=max.x).map(|x| render_cell(self.get(Point::new(x, y)))));
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Grid, Point, SparseGrid};
+    use crate::error::AocError;
+
+    const MAZE: &str = "#.##\n\
+                        ..#.\n\
+                        #...\n";
+
+    fn parse_maze(input: &str) -> Result<Grid<bool>, AocError> {
+        Grid::parse(input, |ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            other => Err(AocError::parse(format!("unknown cell {:?}", other))),
+        })
+    }
+
+    #[test]
+    fn point_test() {
+        let p = Point::new(2, 3);
+
+        assert_eq!(Point::new(2, 2), p + Direction::Up.offset());
+        assert_eq!(5, p.manhattan_distance(Point::new(0, 0)));
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Up, Direction::Up.turn_right().turn_right().reverse());
+        assert!(p.neighbours().contains(&Point::new(3, 3)));
+        assert!(p.neighbours_diagonal().contains(&Point::new(3, 4)));
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = parse_maze(MAZE).unwrap();
+
+        assert_eq!((4, 3), (grid.width(), grid.height()));
+        assert!(grid[Point::new(0, 0)]);
+        assert!(!grid[Point::new(1, 0)]);
+        assert_eq!(None, grid.get(Point::new(4, 0)));
+        assert_eq!(MAZE, grid.render(|wall| if *wall { '#' } else { '.' }));
+
+        assert_eq!(
+            Err(AocError::parse_at(2, 3, "unknown cell 'x'")),
+            parse_maze("#.##\n..x.\n")
+        );
+        assert!(parse_maze("#.##\n..#\n").is_err());
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(2, grid.neighbours(Point::new(0, 0)).count());
+        assert_eq!(4, grid.neighbours(Point::new(1, 1)).count());
+        assert_eq!(5, grid.neighbours_diagonal(Point::new(1, 0)).count());
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[Point::new(1, 0)] = '#';
+
+        assert_eq!(
+            vec![Point::new(1, 0)],
+            grid.iter()
+                .filter(|(_, ch)| **ch == '#')
+                .map(|(p, _)| p)
+                .collect::<Vec<Point>>()
+        );
+        assert_eq!(".#\n..\n", grid.to_string());
+    }
+
+    #[test]
+    fn transform_test() {
+        let grid = parse_maze(MAZE).unwrap();
+        let render = |grid: &Grid<bool>| grid.render(|wall| if *wall { '#' } else { '.' });
+
+        assert_eq!("#.#\n...\n.##\n..#\n", render(&grid.rotate_right()));
+        assert_eq!("#..\n##.\n...\n#.#\n", render(&grid.rotate_left()));
+        assert_eq!("##.#\n.#..\n...#\n", render(&grid.flip_horizontal()));
+        assert_eq!("#...\n..#.\n#.##\n", render(&grid.flip_vertical()));
+        assert_eq!("#.#\n...\n##.\n#..\n", render(&grid.transpose()));
+        assert_eq!(grid, grid.rotate_right().rotate_left());
+        assert_eq!(grid.transpose(), grid.rotate_right().flip_horizontal());
+
+        let symmetries = grid.symmetries();
+        assert_eq!(8, symmetries.len());
+        assert_eq!(grid, symmetries[0]);
+        assert!(symmetries.contains(&grid.transpose()));
+        assert!(symmetries.contains(&grid.flip_vertical()));
+
+        let mut shifted = grid.clone();
+        shifted.shift_row(0, 5);
+        shifted.shift_column(3, 1);
+        assert_eq!("##..\n..##\n#...\n", render(&shifted));
+    }
+
+    #[test]
+    fn view_test() {
+        let mut grid = parse_maze(MAZE).unwrap();
+
+        let view = grid.view(Point::new(1, 1), 3, 2);
+        assert_eq!((3, 2), (view.width(), view.height()));
+        assert!(view[Point::new(1, 0)]);
+        assert_eq!(None, view.get(Point::new(3, 0)));
+        assert_eq!(1, view.iter().filter(|(_, wall)| **wall).count());
+        assert_eq!(".#.\n...\n", view.to_grid().render(|wall| if *wall { '#' } else { '.' }));
+
+        grid.view_mut(Point::new(0, 1), 2, 2).fill(true);
+        grid.view_mut(Point::new(2, 0), 2, 1).paste(&Grid::new(2, 1, false));
+        assert_eq!("#...\n###.\n##..\n", grid.render(|wall| if *wall { '#' } else { '.' }));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn view_outside_test() {
+        Grid::new(2, 2, 0).view(Point::new(1, 1), 2, 1);
+    }
+
+    #[test]
+    fn flood_fill_test() {
+        let grid = parse_maze(MAZE).unwrap();
+
+        let mut floor = grid.flood_fill(Point::new(1, 0), |wall| !*wall);
+        floor.sort();
+        assert_eq!(
+            vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 2), (3, 1), (3, 2)]
+                .into_iter()
+                .map(Point::from)
+                .collect::<Vec<Point>>(),
+            floor
+        );
+        assert_eq!(
+            vec![Point::new(2, 0), Point::new(3, 0), Point::new(2, 1)],
+            grid.flood_fill(Point::new(2, 0), |wall| *wall)
+        );
+        assert!(grid.flood_fill(Point::new(0, 0), |wall| !*wall).is_empty());
+    }
+
+    #[test]
+    fn sparse_test() {
+        let grid = parse_maze(MAZE).unwrap();
+        let mut sparse = SparseGrid::from_grid(&grid, false);
+
+        assert_eq!(5, sparse.len());
+        assert!(*sparse.get(Point::new(0, 0)));
+        assert!(!*sparse.get(Point::new(-100, 7)));
+        assert_eq!(Some((Point::new(0, 0), Point::new(3, 2))), sparse.bounds());
+
+        sparse.set(Point::new(-1, -1), true);
+        sparse.set(Point::new(0, 0), false);
+        assert_eq!(5, sparse.len());
+        assert_eq!(4, sparse.neighbours(Point::new(-1, -1)).count());
+        assert_eq!(
+            "#....\n...##\n...#.\n.#...\n",
+            sparse.render(Point::new(-1, -1), Point::new(3, 2), |wall| if *wall { '#' } else { '.' })
+        );
+        assert_eq!(
+            grid.view(Point::new(1, 0), 3, 3).to_grid(),
+            sparse.to_grid(Point::new(1, 0), Point::new(3, 2))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::io;
+use std::io::prelude::*;
+use std::str::FromStr;
+
+use crate::error::{AocError, AocResult};
+
+/// Reads the whole puzzle input from stdin.
+pub fn read_input() -> io::Result<String> {
+    let mut input_str = String::new();
+    io::stdin().read_to_string(&mut input_str)?;
+
+    Ok(input_str)
+}
+
+/// Parses every non-empty line of the input.
+///
+/// Parse errors without a location get the number of the failing line attached.
+pub fn parse_lines<T>(input: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<AocError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| line.parse().map_err(|e: T::Err| e.into().at_line(idx + 1)))
+        .collect()
+}
+
+/// Parses every item of a list, e.g. `1,2,3`. Newlines separate items as well.
+///
+/// The items are trimmed, empty items are skipped. Parse errors point to the start of the failing item.
+pub fn parse_separated<T>(input: &str, separator: char) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<AocError>,
+{
+    parse_items(
+        input,
+        input
+            .split([separator, '\n'])
+            .map(str::trim),
+    )
+}
+
+/// Parses every whitespace separated item of the input.
+pub fn parse_words<T>(input: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<AocError>,
+{
+    parse_items(input, input.split_whitespace())
+}
+
+/// Parses the whole input, ignoring surrounding whitespace.
+pub fn parse_trimmed<T>(input: &str) -> AocResult<T>
+where
+    T: FromStr,
+    T::Err: Into<AocError>,
+{
+    input.trim().parse().map_err(|e: T::Err| e.into().at_line(1))
+}
+
+/// Parses the given slices of `input`, locating errors without a position at the failing item.
+fn parse_items<'a, T, I>(input: &str, items: I) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<AocError>,
+    I: Iterator<Item = &'a str>,
+{
+    items
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            item.parse().map_err(|e: T::Err| match e.into() {
+                AocError::Parse {
+                    line: None,
+                    column: None,
+                    message,
+                } => {
+                    let (line, column) = position_of(input, item);
+                    AocError::parse_at(line, column, message)
+                }
+                other => other,
+            })
+        })
+        .collect()
+}
+
+/// Returns the 1-based line and column at which `part` starts. `part` must be a slice of `input`.
+fn position_of(input: &str, part: &str) -> (usize, usize) {
+    let offset = part.as_ptr() as usize - input.as_ptr() as usize;
+    let before = &input[..offset];
+
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_lines, parse_separated, parse_trimmed, parse_words};
+    use crate::error::AocError;
+
+    #[test]
+    fn parse_lines_test() {
+        assert_eq!(Ok(vec![1, 2, 3]), parse_lines::<u32>("1\n2\n\n3\n"));
+
+        match parse_lines::<u32>("1\n2\nx\n") {
+            Err(AocError::Parse { line, .. }) => assert_eq!(Some(3), line),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_separated_test() {
+        assert_eq!(Ok(vec![3, 4, 1, 5]), parse_separated::<u8>("3,4, 1,5\n", ','));
+
+        match parse_separated::<u8>("3,4\n1,x5", ',') {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((Some(2), Some(3)), (line, column)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_words_test() {
+        assert_eq!(Ok(vec![27, 10647, 103]), parse_words::<u32>("27  10647\t103\n"));
+
+        match parse_words::<u32>("27  10647\n  1o3") {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((Some(2), Some(3)), (line, column)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_trimmed_test() {
+        assert_eq!(Ok(1362), parse_trimmed::<u32>("  1362\n"));
+        assert!(parse_trimmed::<u32>("13 62").is_err());
+    }
+}
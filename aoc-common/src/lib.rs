@@ -0,0 +1,106 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub mod compiled;
+pub mod decompile;
+mod error;
+mod grid;
+mod input;
+pub mod search;
+pub mod vm;
+
+pub use crate::error::{AocError, AocResult};
+pub use crate::grid::{Direction, Grid, Point, SparseGrid, View, ViewMut};
+pub use crate::input::{parse_lines, parse_separated, parse_trimmed, parse_words, read_input};
+
+/// Which half of a puzzle to solve.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(format!("invalid puzzle part: {:?}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Part::A => write!(w, "a"),
+            Part::B => write!(w, "b"),
+        }
+    }
+}
+
+/// Common interface of every day's solution.
+///
+/// The puzzle input is parsed once and then handed to both parts.
+pub trait Solver {
+    type Input;
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+    fn part_a(input: &Self::Input) -> AocResult<String>;
+    fn part_b(input: &Self::Input) -> AocResult<String>;
+}
+
+/// Parses the input once and runs the requested parts of the puzzle on it, with an answer or an
+/// error for each part.
+pub fn solve<S: Solver>(input: &str, parts: &[Part]) -> AocResult<Vec<AocResult<String>>> {
+    let parsed = S::parse(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            Part::A => S::part_a(&parsed),
+            Part::B => S::part_b(&parsed),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_words, solve, AocResult, Part, Solver};
+
+    struct Sum;
+
+    impl Solver for Sum {
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> AocResult<Self::Input> {
+            parse_words(input)
+        }
+
+        fn part_a(input: &Self::Input) -> AocResult<String> {
+            Ok(input.iter().sum::<i32>().to_string())
+        }
+
+        fn part_b(input: &Self::Input) -> AocResult<String> {
+            Ok(input.iter().product::<i32>().to_string())
+        }
+    }
+
+    #[test]
+    fn part_parse_test() {
+        assert_eq!(Ok(Part::A), "a".parse());
+        assert_eq!(Ok(Part::B), "B".parse());
+        assert!("c".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn solve_test() {
+        assert_eq!(
+            Ok(vec![Ok("24".to_string()), Ok("10".to_string())]),
+            solve::<Sum>("1 2 3 4", &[Part::B, Part::A])
+        );
+        assert!(solve::<Sum>("1 x", &[Part::A]).is_err());
+    }
+}
@@ -0,0 +1,469 @@
+//! Generic state-space search shared by the path finding and puzzle solving days.
+//!
+//! A puzzle describes its states with [`SearchState`] and picks the algorithm that fits:
+//! [`bfs`] when every move costs the same, [`dijkstra`] for weighted moves, [`a_star`] and
+//! [`ida_star`] when a good estimate of the remaining cost is known. All of them return the path
+//! they found together with [`SearchStats`], which tell how much work the search did.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A node of a search graph, which knows its neighbours and whether it is a goal.
+pub trait SearchState: Sized {
+    /// The cost of moves, `Cost::default()` is zero.
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// The states reachable with one move, with the cost of the move.
+    fn neighbors(&self) -> Vec<(Self, Self::Cost)>;
+
+    fn is_goal(&self) -> bool;
+
+    /// A lower bound of the cost left to reach a goal. `a_star` and `ida_star` only find optimal
+    /// paths when it never overestimates, the other algorithms ignore it.
+    fn heuristic(&self) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SearchStats {
+    /// The number of states whose neighbours were generated.
+    pub expanded: usize,
+    /// The most states waiting to be expanded at any one time.
+    pub frontier_peak: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<S: SearchState> {
+    /// The states from the start to the goal, both included.
+    pub path: Vec<S>,
+    pub cost: S::Cost,
+    pub stats: SearchStats,
+}
+
+impl<S: SearchState> SearchResult<S> {
+    /// The number of moves on the path.
+    pub fn steps(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    pub fn goal(&self) -> &S {
+        self.path.last().unwrap()
+    }
+}
+
+/// The states found so far, each with the state it was reached from.
+struct Arena<S: SearchState> {
+    nodes: Vec<(S, Option<usize>, S::Cost)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S> Arena<S>
+where
+    S: SearchState + Clone + Eq + Hash,
+{
+    fn new(start: S) -> Arena<S> {
+        let mut arena = Arena {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        };
+
+        arena.insert(start, None, S::Cost::default());
+        arena
+    }
+
+    fn insert(&mut self, state: S, parent: Option<usize>, cost: S::Cost) -> usize {
+        let id = self.nodes.len();
+
+        self.index.insert(state.clone(), id);
+        self.nodes.push((state, parent, cost));
+        id
+    }
+
+    fn path_to(&self, id: usize, stats: SearchStats) -> SearchResult<S> {
+        let mut path = vec![self.nodes[id].0.clone()];
+        let mut current = id;
+
+        while let Some(parent) = self.nodes[current].1 {
+            path.push(self.nodes[parent].0.clone());
+            current = parent;
+        }
+
+        path.reverse();
+
+        SearchResult {
+            path,
+            cost: self.nodes[id].2,
+            stats,
+        }
+    }
+}
+
+/// Breadth-first search, finding the path with the fewest moves.
+pub fn bfs<S>(start: S) -> Option<SearchResult<S>>
+where
+    S: SearchState + Clone + Eq + Hash,
+{
+    let mut arena = Arena::new(start);
+    let mut frontier = VecDeque::from(vec![0]);
+    let mut stats = SearchStats::default();
+
+    while let Some(id) = frontier.pop_front() {
+        if arena.nodes[id].0.is_goal() {
+            return Some(arena.path_to(id, stats));
+        }
+
+        stats.expanded += 1;
+
+        for (next, cost) in arena.nodes[id].0.neighbors() {
+            if !arena.index.contains_key(&next) {
+                let total = arena.nodes[id].2 + cost;
+                frontier.push_back(arena.insert(next, Some(id), total));
+            }
+        }
+
+        stats.frontier_peak = stats.frontier_peak.max(frontier.len());
+    }
+
+    None
+}
+
+/// Every state at most `max_depth` moves away from the start, with the fewest moves to reach it.
+pub fn reachable<S>(start: S, max_depth: usize) -> HashMap<S, usize>
+where
+    S: SearchState + Clone + Eq + Hash,
+{
+    let mut depths = HashMap::new();
+    let mut frontier = VecDeque::new();
+
+    depths.insert(start.clone(), 0);
+    frontier.push_back((start, 0));
+
+    while let Some((state, depth)) = frontier.pop_front() {
+        if depth == max_depth {
+            continue;
+        }
+
+        for (next, _) in state.neighbors() {
+            if !depths.contains_key(&next) {
+                depths.insert(next.clone(), depth + 1);
+                frontier.push_back((next, depth + 1));
+            }
+        }
+    }
+
+    depths
+}
+
+/// An entry of the priority queue, ordered by the estimated total cost, then by insertion order
+/// so that ties are broken the same way on every run.
+struct Queued<C> {
+    estimate: C,
+    order: Reverse<usize>,
+    id: usize,
+}
+
+impl<C: Ord> PartialEq for Queued<C> {
+    fn eq(&self, other: &Queued<C>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Queued<C> {}
+
+impl<C: Ord> PartialOrd for Queued<C> {
+    fn partial_cmp(&self, other: &Queued<C>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Queued<C> {
+    fn cmp(&self, other: &Queued<C>) -> Ordering {
+        // the heap pops the greatest entry, which has to be the cheapest one
+        other.estimate.cmp(&self.estimate).then(self.order.cmp(&other.order))
+    }
+}
+
+fn best_first<S, H>(start: S, heuristic: H) -> Option<SearchResult<S>>
+where
+    S: SearchState + Clone + Eq + Hash,
+    H: Fn(&S) -> S::Cost,
+{
+    let mut arena = Arena::new(start);
+    let mut frontier = BinaryHeap::new();
+    let mut stats = SearchStats::default();
+    let mut pushed = 0;
+
+    frontier.push(Queued {
+        estimate: heuristic(&arena.nodes[0].0),
+        order: Reverse(pushed),
+        id: 0,
+    });
+
+    while let Some(Queued { id, estimate, .. }) = frontier.pop() {
+        let (ref state, _, cost) = arena.nodes[id];
+
+        // a cheaper way to the state was found after this entry was queued
+        if estimate > cost + heuristic(state) {
+            continue;
+        }
+
+        if state.is_goal() {
+            return Some(arena.path_to(id, stats));
+        }
+
+        stats.expanded += 1;
+
+        for (next, step) in state.neighbors() {
+            let total = cost + step;
+
+            let next_id = match arena.index.get(&next) {
+                Some(&known) if arena.nodes[known].2 <= total => continue,
+                Some(&known) => {
+                    arena.nodes[known].1 = Some(id);
+                    arena.nodes[known].2 = total;
+                    known
+                }
+                None => arena.insert(next, Some(id), total),
+            };
+
+            pushed += 1;
+            frontier.push(Queued {
+                estimate: total + heuristic(&arena.nodes[next_id].0),
+                order: Reverse(pushed),
+                id: next_id,
+            });
+        }
+
+        stats.frontier_peak = stats.frontier_peak.max(frontier.len());
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, finding the cheapest path. Move costs must not be negative.
+pub fn dijkstra<S>(start: S) -> Option<SearchResult<S>>
+where
+    S: SearchState + Clone + Eq + Hash,
+{
+    best_first(start, |_| S::Cost::default())
+}
+
+/// A* search, finding the cheapest path with the help of `SearchState::heuristic`.
+pub fn a_star<S>(start: S) -> Option<SearchResult<S>>
+where
+    S: SearchState + Clone + Eq + Hash,
+{
+    best_first(start, S::heuristic)
+}
+
+/// Iterative deepening A*: depth-first searches with a growing bound on the estimated total cost.
+///
+/// It only keeps the current path in memory, and no set of visited states, so it suits searches
+/// with huge numbers of states and few ways to reach each of them. States already on the
+/// current path are skipped, which keeps it from walking in circles.
+pub fn ida_star<S>(start: S) -> Option<SearchResult<S>>
+where
+    S: SearchState + Clone + Eq,
+{
+    let mut bound = start.heuristic();
+    let mut path = vec![(start, S::Cost::default())];
+    let mut stats = SearchStats::default();
+
+    loop {
+        match deepen(&mut path, bound, &mut stats) {
+            Deepening::Found => {
+                let cost = path.last().unwrap().1;
+                let path = path.into_iter().map(|(state, _)| state).collect();
+
+                return Some(SearchResult { path, cost, stats });
+            }
+            Deepening::Exceeded(next_bound) => bound = next_bound,
+            Deepening::Exhausted => return None,
+        }
+    }
+}
+
+enum Deepening<C> {
+    Found,
+    /// The smallest estimate above the bound that was seen.
+    Exceeded(C),
+    Exhausted,
+}
+
+fn deepen<S>(path: &mut Vec<(S, S::Cost)>, bound: S::Cost, stats: &mut SearchStats) -> Deepening<S::Cost>
+where
+    S: SearchState + Clone + Eq,
+{
+    let neighbors = {
+        let (ref state, cost) = *path.last().unwrap();
+        let estimate = cost + state.heuristic();
+
+        if estimate > bound {
+            return Deepening::Exceeded(estimate);
+        }
+
+        if state.is_goal() {
+            return Deepening::Found;
+        }
+
+        state.neighbors()
+    };
+
+    stats.expanded += 1;
+    stats.frontier_peak = stats.frontier_peak.max(path.len());
+
+    let cost = path.last().unwrap().1;
+    let mut next_bound: Option<S::Cost> = None;
+
+    for (next, step) in neighbors {
+        if path.iter().any(|(on_path, _)| *on_path == next) {
+            continue;
+        }
+
+        path.push((next, cost + step));
+
+        match deepen(path, bound, stats) {
+            Deepening::Found => return Deepening::Found,
+            Deepening::Exceeded(over) => {
+                next_bound = Some(next_bound.map_or(over, |known| known.min(over)));
+            }
+            Deepening::Exhausted => {}
+        }
+
+        path.pop();
+    }
+
+    match next_bound {
+        Some(next) => Deepening::Exceeded(next),
+        None => Deepening::Exhausted,
+    }
+}
+
+/// Visits every state reachable from the start, once for every path leading to it, depth first.
+///
+/// Suits the puzzles that look for the best of all the paths rather than for a goal, where the
+/// graph is a tree anyway. Returns the number of visited states.
+pub fn traverse<S, F>(start: S, mut visit: F) -> SearchStats
+where
+    S: SearchState,
+    F: FnMut(&S),
+{
+    let mut stack = vec![start];
+    let mut stats = SearchStats::default();
+
+    while let Some(state) = stack.pop() {
+        visit(&state);
+
+        stats.expanded += 1;
+        stack.extend(state.neighbors().into_iter().map(|(next, _)| next));
+        stats.frontier_peak = stats.frontier_peak.max(stack.len());
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{a_star, bfs, dijkstra, ida_star, reachable, traverse, SearchState};
+
+    /// Walking a line of positions: steps of one cost 3, jumps of four cost 5.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    struct Walk {
+        pos: i32,
+        goal: i32,
+    }
+
+    impl SearchState for Walk {
+        type Cost = u32;
+
+        fn neighbors(&self) -> Vec<(Walk, u32)> {
+            let walk = |pos| Walk { pos, goal: self.goal };
+
+            vec![(walk(self.pos + 1), 3), (walk(self.pos - 1), 3), (walk(self.pos + 4), 5)]
+        }
+
+        fn is_goal(&self) -> bool {
+            self.pos == self.goal
+        }
+
+        fn heuristic(&self) -> u32 {
+            // a jump covers the most ground per cost
+            ((self.goal - self.pos).unsigned_abs() * 5).div_ceil(4)
+        }
+    }
+
+    fn positions(path: &[Walk]) -> Vec<i32> {
+        path.iter().map(|walk| walk.pos).collect()
+    }
+
+    #[test]
+    fn search_test() {
+        let start = Walk { pos: 0, goal: 7 };
+
+        // fewest moves: a step back and two jumps
+        let result = bfs(start).unwrap();
+        assert_eq!(vec![0, -1, 3, 7], positions(&result.path));
+        assert_eq!(13, result.cost);
+        assert_eq!(3, result.steps());
+
+        // cheapest: the same moves, in any order
+        let cheapest = dijkstra(start).unwrap();
+        assert_eq!(13, cheapest.cost);
+        assert_eq!(7, cheapest.goal().pos);
+
+        let guided = a_star(start).unwrap();
+        assert_eq!(13, guided.cost);
+        assert!(guided.stats.expanded < cheapest.stats.expanded);
+        assert!(guided.stats.frontier_peak > 0);
+
+        let deepened = ida_star(start).unwrap();
+        assert_eq!(13, deepened.cost);
+        assert_eq!(0, deepened.path[0].pos);
+        assert_eq!(7, deepened.goal().pos);
+    }
+
+    /// A binary tree of the given height, whose leaves are the numbers `1 << height` and above.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    struct Tree(u32, u32);
+
+    impl SearchState for Tree {
+        type Cost = u32;
+
+        fn neighbors(&self) -> Vec<(Tree, u32)> {
+            if self.0 >= 1 << self.1 {
+                vec![]
+            } else {
+                vec![(Tree(2 * self.0, self.1), 1), (Tree(2 * self.0 + 1, self.1), 1)]
+            }
+        }
+
+        fn is_goal(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn exhaustive_test() {
+        assert!(bfs(Tree(1, 3)).is_none());
+        assert!(ida_star(Tree(1, 3)).is_none());
+
+        let mut leaves = Vec::new();
+        let stats = traverse(Tree(1, 3), |node| {
+            if node.neighbors().is_empty() {
+                leaves.push(node.0);
+            }
+        });
+
+        leaves.sort();
+        assert_eq!((8..16).collect::<Vec<u32>>(), leaves);
+        assert_eq!(15, stats.expanded);
+
+        let depths = reachable(Tree(1, 3), 2);
+        assert_eq!(7, depths.len());
+        assert_eq!(Some(&2), depths.get(&Tree(7, 3)));
+        assert_eq!(None, depths.get(&Tree(8, 3)));
+    }
+}
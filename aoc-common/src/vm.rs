@@ -0,0 +1,647 @@
+//! A small virtual machine shared by the register machine puzzles.
+//!
+//! Every puzzle plugs in its own [`InstructionSet`], which decides what the instructions do to the
+//! machine state. The [`Machine`] takes care of the program counter, step counting and limits.
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::{AocError, AocResult};
+
+/// A register name of one of the machines, e.g. `a` or `b`.
+pub trait Register: Copy + Eq + fmt::Debug + fmt::Display {
+    /// Number of registers of this kind.
+    const COUNT: usize;
+
+    /// Index of the register in `0..COUNT`.
+    fn index(self) -> usize;
+
+    /// Inverse of `index`.
+    fn from_index(index: usize) -> Self;
+
+    /// Looks up a register by the name it is displayed with.
+    fn parse(name: &str) -> Option<Self> {
+        (0..Self::COUNT)
+            .map(Self::from_index)
+            .find(|reg| reg.to_string() == name)
+    }
+}
+
+/// Lowercase letters `a` to `z` are registers in the 2017 puzzles.
+impl Register for char {
+    const COUNT: usize = 26;
+
+    fn index(self) -> usize {
+        (self as u8 - b'a') as usize
+    }
+
+    fn from_index(index: usize) -> char {
+        (b'a' + index as u8) as char
+    }
+
+    fn parse(name: &str) -> Option<char> {
+        let mut chars = name.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) if ch.is_ascii_lowercase() => Some(ch),
+            _ => None,
+        }
+    }
+}
+
+/// One value of type `W` per register `R`, all starting at `W::default()`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct RegisterFile<R, W> {
+    values: Vec<W>,
+    registers: PhantomData<R>,
+}
+
+impl<R: Register, W: Copy + Default> RegisterFile<R, W> {
+    pub fn new() -> RegisterFile<R, W> {
+        RegisterFile {
+            values: vec![W::default(); R::COUNT],
+            registers: PhantomData,
+        }
+    }
+
+    pub fn get(&self, reg: R) -> W {
+        self.values[reg.index()]
+    }
+
+    pub fn set(&mut self, reg: R, val: W) {
+        self.values[reg.index()] = val;
+    }
+
+    /// The values in register index order, for code that resolved its registers ahead of time.
+    pub fn slots(&self) -> &[W] {
+        &self.values
+    }
+
+    pub fn slots_mut(&mut self) -> &mut [W] {
+        &mut self.values
+    }
+
+    /// All the registers with their values, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (R, W)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(idx, val)| (R::from_index(idx), *val))
+    }
+}
+
+impl<R: Register, W: Copy + Default> Default for RegisterFile<R, W> {
+    fn default() -> RegisterFile<R, W> {
+        RegisterFile::new()
+    }
+}
+
+impl<R: Register, W> Index<R> for RegisterFile<R, W> {
+    type Output = W;
+
+    fn index(&self, reg: R) -> &W {
+        &self.values[reg.index()]
+    }
+}
+
+impl<R: Register, W> IndexMut<R> for RegisterFile<R, W> {
+    fn index_mut(&mut self, reg: R) -> &mut W {
+        &mut self.values[reg.index()]
+    }
+}
+
+/// An instruction argument, either a register or an immediate value.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Operand<R, W> {
+    Reg(R),
+    Imm(W),
+}
+
+impl<R: Register, W: Copy + Default> Operand<R, W> {
+    pub fn value(&self, regs: &RegisterFile<R, W>) -> W {
+        match *self {
+            Operand::Reg(reg) => regs[reg],
+            Operand::Imm(val) => val,
+        }
+    }
+
+    /// The register an instruction writes to. Immediate values can not be written to.
+    pub fn register(&self) -> Result<R, Fault> {
+        match *self {
+            Operand::Reg(reg) => Ok(reg),
+            Operand::Imm(_) => Err(Fault::Invalid("immediate value used as a register".to_string())),
+        }
+    }
+}
+
+impl<R: Register, W: FromStr> FromStr for Operand<R, W> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(val) = s.parse() {
+            Ok(Operand::Imm(val))
+        } else {
+            R::parse(s)
+                .map(Operand::Reg)
+                .ok_or_else(|| AocError::parse(format!("invalid operand: {:?}", s)))
+        }
+    }
+}
+
+impl<R: fmt::Display, W: fmt::Display> fmt::Display for Operand<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Reg(reg) => write!(f, "{}", reg),
+            Operand::Imm(val) => write!(f, "{}", val),
+        }
+    }
+}
+
+/// Splits an instruction line into its mnemonic and operands, e.g. `jio a, +2` into `jio` and `[a, +2]`.
+pub fn split_instruction(line: &str) -> AocResult<(&str, Vec<&str>)> {
+    let mut words = line
+        .split(|ch: char| ch == ',' || ch.is_whitespace())
+        .filter(|word| !word.is_empty());
+
+    let mnemonic = words
+        .next()
+        .ok_or_else(|| AocError::parse("missing instruction"))?;
+
+    Ok((mnemonic, words.collect()))
+}
+
+/// Checks that an instruction got exactly `N` operands.
+pub fn operands<'a, const N: usize>(mnemonic: &str, args: &[&'a str]) -> AocResult<[&'a str; N]> {
+    <[&str; N]>::try_from(args).map_err(|_| {
+        AocError::parse(format!(
+            "{} expects {} operand(s), got {}",
+            mnemonic,
+            N,
+            args.len()
+        ))
+    })
+}
+
+/// What the machine does after executing an instruction.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Step<E> {
+    /// Continue with the next instruction.
+    Next,
+    /// Jump relative to the current instruction.
+    Jump(isize),
+    /// Advance to the next instruction, then pause and report the event.
+    Emit(E),
+    /// Pause without advancing. The instruction is executed again once the machine resumes.
+    Block(E),
+    /// Replace the instruction at the given offset from the current one with the result of
+    /// `InstructionSet::rewrite`, then continue with the next instruction. Offsets outside of the
+    /// program are ignored.
+    Rewrite(isize),
+    /// Stop the machine.
+    Halt,
+}
+
+/// State of the machine after it stopped executing instructions.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status<E> {
+    /// The machine can continue.
+    Running,
+    /// An instruction paused the machine.
+    Paused(E),
+    /// The program finished.
+    Halted,
+}
+
+/// Reasons an instruction can fail.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Fault {
+    /// Jumped before the first instruction.
+    PcOutOfRange(isize),
+    /// Arithmetic overflow in a register.
+    Overflow,
+    DivisionByZero,
+    /// The machine executed more instructions than it was allowed to.
+    StepLimit(u64),
+    /// The instruction can not be executed, e.g. because it writes to an immediate value.
+    Invalid(String),
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fault::PcOutOfRange(pc) => write!(f, "jump to invalid address {}", pc),
+            Fault::Overflow => write!(f, "arithmetic overflow in register"),
+            Fault::DivisionByZero => write!(f, "division by zero"),
+            Fault::StepLimit(limit) => write!(f, "step limit of {} exceeded", limit),
+            Fault::Invalid(message) => write!(f, "invalid instruction: {}", message),
+        }
+    }
+}
+
+/// A fault, together with the address of the instruction that caused it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VmError {
+    pub pc: usize,
+    pub fault: Fault,
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at pc {}", self.fault, self.pc)
+    }
+}
+
+impl Error for VmError {}
+
+impl From<VmError> for AocError {
+    fn from(e: VmError) -> AocError {
+        AocError::runtime(e.to_string())
+    }
+}
+
+/// Semantics of a puzzle's instructions.
+///
+/// The same instructions can be given different meanings by different instruction sets, e.g. the
+/// sound and the duet interpretation of the 2017 day 18 programs.
+pub trait InstructionSet {
+    type Instr: Clone + fmt::Debug;
+    /// Registers and anything else the instructions work with.
+    type State;
+    /// Reported to the caller when an instruction pauses the machine.
+    type Event;
+
+    fn execute(instr: &Self::Instr, state: &mut Self::State) -> Result<Step<Self::Event>, Fault>;
+
+    /// The instruction replacing `instr` when an instruction returns `Step::Rewrite`. Only
+    /// self-modifying instruction sets need to implement it.
+    fn rewrite(instr: &Self::Instr) -> Result<Self::Instr, Fault> {
+        Err(Fault::Invalid(format!("{:?} can not be rewritten", instr)))
+    }
+}
+
+/// The program counter and the step count of a machine.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Control {
+    pub(crate) pc: usize,
+    pub(crate) steps: u64,
+    pub(crate) step_limit: Option<u64>,
+}
+
+/// A machine as the control loop sees it: slots it can execute by program counter. `Machine` and
+/// `CompiledMachine` only differ in what a slot holds, so they share the loop below.
+pub(crate) trait Execute {
+    type Event;
+
+    fn control(&mut self) -> &mut Control;
+
+    /// Number of slots, the program counter past them halts the machine.
+    fn len(&self) -> usize;
+
+    fn execute(&mut self, pc: usize) -> Result<Step<Self::Event>, Fault>;
+
+    /// Replaces the instruction in the slot as `InstructionSet::rewrite` says.
+    fn rewrite(&mut self, target: usize) -> Result<(), Fault>;
+}
+
+/// Executes the slot at the program counter and moves the program counter on.
+pub(crate) fn step<M: Execute>(machine: &mut M) -> Result<Status<M::Event>, VmError> {
+    let len = machine.len();
+    let Control { pc, steps, step_limit } = *machine.control();
+    let error = |fault| VmError { pc, fault };
+
+    if pc >= len {
+        return Ok(Status::Halted);
+    }
+
+    if let Some(limit) = step_limit {
+        if steps >= limit {
+            return Err(error(Fault::StepLimit(limit)));
+        }
+    }
+
+    let step = machine.execute(pc).map_err(error)?;
+    machine.control().steps += 1;
+
+    let (offset, status) = match step {
+        Step::Next => (1, Status::Running),
+        Step::Jump(offset) => (offset, Status::Running),
+        Step::Emit(event) => (1, Status::Paused(event)),
+        Step::Block(event) => return Ok(Status::Paused(event)),
+        Step::Rewrite(offset) => {
+            let target = pc as isize + offset;
+
+            if target >= 0 && target < len as isize {
+                machine.rewrite(target as usize).map_err(error)?;
+            }
+
+            (1, Status::Running)
+        }
+        Step::Halt => {
+            machine.control().pc = len;
+            return Ok(Status::Halted);
+        }
+    };
+
+    let target = pc as isize + offset;
+
+    if target < 0 {
+        return Err(error(Fault::PcOutOfRange(target)));
+    }
+
+    machine.control().pc = target as usize;
+
+    Ok(match status {
+        Status::Running if target as usize >= len => Status::Halted,
+        status => status,
+    })
+}
+
+/// Runs until the program halts or an instruction pauses the machine.
+pub(crate) fn run<M: Execute>(machine: &mut M) -> Result<Status<M::Event>, VmError> {
+    loop {
+        match step(machine)? {
+            Status::Running => {}
+            status => return Ok(status),
+        }
+    }
+}
+
+/// Like `run`, but executes at most `max_steps` instructions.
+pub(crate) fn run_steps<M: Execute>(machine: &mut M, max_steps: u64) -> Result<Status<M::Event>, VmError> {
+    for _ in 0..max_steps {
+        match step(machine)? {
+            Status::Running => {}
+            status => return Ok(status),
+        }
+    }
+
+    Ok(if machine.control().pc >= machine.len() {
+        Status::Halted
+    } else {
+        Status::Running
+    })
+}
+
+/// Runs a program of the instruction set `S`.
+///
+/// The machine halts when the program counter moves past the last instruction.
+pub struct Machine<S: InstructionSet> {
+    program: Vec<S::Instr>,
+    /// The program as loaded, kept once an instruction rewrote it.
+    original: Option<Vec<S::Instr>>,
+    state: S::State,
+    control: Control,
+}
+
+impl<S: InstructionSet> Machine<S> {
+    pub fn new(program: Vec<S::Instr>, state: S::State) -> Machine<S> {
+        Machine {
+            program,
+            original: None,
+            state,
+            control: Control::default(),
+        }
+    }
+
+    /// Makes `run` fail once the machine has executed `limit` instructions in total.
+    pub fn with_step_limit(mut self, limit: u64) -> Machine<S> {
+        self.control.step_limit = Some(limit);
+        self
+    }
+
+    /// The program, including the changes made by `Step::Rewrite`.
+    pub fn program(&self) -> &[S::Instr] {
+        &self.program
+    }
+
+    pub fn state(&self) -> &S::State {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut S::State {
+        &mut self.state
+    }
+
+    pub fn pc(&self) -> usize {
+        self.control.pc
+    }
+
+    pub fn set_pc(&mut self, pc: usize) {
+        self.control.pc = pc;
+    }
+
+    /// Number of instructions executed so far.
+    pub fn steps(&self) -> u64 {
+        self.control.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.control.pc >= self.program.len()
+    }
+
+    /// The instruction the program counter points to.
+    pub fn current(&self) -> Option<&S::Instr> {
+        self.program.get(self.control.pc)
+    }
+
+    /// Executes a single instruction.
+    pub fn step(&mut self) -> Result<Status<S::Event>, VmError> {
+        step(self)
+    }
+
+    /// Runs until the program halts or an instruction pauses the machine.
+    pub fn run(&mut self) -> Result<Status<S::Event>, VmError> {
+        run(self)
+    }
+
+    /// Like `run`, but executes at most `max_steps` instructions. Returns `Status::Running` if the
+    /// program neither halted nor paused by then.
+    pub fn run_steps(&mut self, max_steps: u64) -> Result<Status<S::Event>, VmError> {
+        run_steps(self, max_steps)
+    }
+}
+
+impl<S: InstructionSet> Execute for Machine<S> {
+    type Event = S::Event;
+
+    fn control(&mut self) -> &mut Control {
+        &mut self.control
+    }
+
+    fn len(&self) -> usize {
+        self.program.len()
+    }
+
+    fn execute(&mut self, pc: usize) -> Result<Step<S::Event>, Fault> {
+        S::execute(&self.program[pc], &mut self.state)
+    }
+
+    fn rewrite(&mut self, target: usize) -> Result<(), Fault> {
+        let rewritten = S::rewrite(&self.program[target])?;
+
+        if self.original.is_none() {
+            self.original = Some(self.program.clone());
+        }
+
+        self.program[target] = rewritten;
+        Ok(())
+    }
+}
+
+impl<S: InstructionSet> Clone for Machine<S>
+where
+    S::State: Clone,
+{
+    fn clone(&self) -> Machine<S> {
+        Machine {
+            program: self.program.clone(),
+            original: self.original.clone(),
+            state: self.state.clone(),
+            control: self.control.clone(),
+        }
+    }
+}
+
+impl<S: InstructionSet> fmt::Debug for Machine<S>
+where
+    S::State: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Machine")
+            .field("program", &self.program)
+            .field("state", &self.state)
+            .field("pc", &self.control.pc)
+            .field("steps", &self.control.steps)
+            .field("step_limit", &self.control.step_limit)
+            .finish()
+    }
+}
+
+impl<S: InstructionSet> Machine<S>
+where
+    S::State: Default,
+{
+    /// Rewinds and restores the program, and clears the machine state.
+    pub fn reset(&mut self) {
+        if let Some(original) = self.original.take() {
+            self.program = original;
+        }
+
+        self.state = S::State::default();
+        self.control.pc = 0;
+        self.control.steps = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        operands, split_instruction, Fault, InstructionSet, Machine, Operand, Register, RegisterFile,
+        Status, Step, VmError,
+    };
+
+    type Value = Operand<char, i64>;
+
+    #[derive(Debug, Clone)]
+    enum Instr {
+        Add(char, Value),
+        Out(Value),
+        Jnz(Value, isize),
+    }
+
+    /// Adds and jumps, and reports the values passed to `out`.
+    struct Toy;
+
+    impl InstructionSet for Toy {
+        type Instr = Instr;
+        type State = RegisterFile<char, i64>;
+        type Event = i64;
+
+        fn execute(instr: &Instr, regs: &mut Self::State) -> Result<Step<i64>, Fault> {
+            match instr {
+                Instr::Add(reg, val) => {
+                    regs[*reg] = regs[*reg].checked_add(val.value(regs)).ok_or(Fault::Overflow)?;
+                    Ok(Step::Next)
+                }
+                Instr::Out(val) => Ok(Step::Emit(val.value(regs))),
+                Instr::Jnz(val, offset) if val.value(regs) != 0 => Ok(Step::Jump(*offset)),
+                Instr::Jnz(..) => Ok(Step::Next),
+            }
+        }
+    }
+
+    fn countdown() -> Machine<Toy> {
+        Machine::new(
+            vec![
+                Instr::Add('a', Operand::Imm(3)),
+                Instr::Out(Operand::Reg('a')),
+                Instr::Add('a', Operand::Imm(-1)),
+                Instr::Jnz(Operand::Reg('a'), -2),
+            ],
+            RegisterFile::new(),
+        )
+    }
+
+    #[test]
+    fn register_test() {
+        assert_eq!(Some('c'), char::parse("c"));
+        assert_eq!(None, char::parse("C"));
+        assert_eq!(2, 'c'.index());
+
+        let mut regs = RegisterFile::<char, i64>::new();
+        regs.set('b', 5);
+        regs['c'] += 2;
+
+        assert_eq!(5, regs.get('b'));
+        assert_eq!(vec![('b', 5), ('c', 2)], regs.iter().filter(|(_, val)| *val != 0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(Ok(("jio", vec!["a", "+2"])), split_instruction("jio a, +2"));
+        assert_eq!(Ok(["a", "+2"]), operands("jio", &["a", "+2"]));
+        assert!(operands::<1>("inc", &["a", "b"]).is_err());
+
+        assert_eq!(Ok(Operand::Imm(-4)), "-4".parse::<Value>());
+        assert_eq!(Ok(Operand::Reg('x')), "x".parse::<Value>());
+        assert!("X".parse::<Value>().is_err());
+    }
+
+    #[test]
+    fn run_test() {
+        let mut machine = countdown();
+
+        assert_eq!(Ok(Status::Paused(3)), machine.run());
+        assert_eq!(Ok(Status::Paused(2)), machine.run());
+        assert_eq!(Ok(Status::Paused(1)), machine.run());
+        assert_eq!(Ok(Status::Halted), machine.run());
+        assert_eq!(10, machine.steps());
+        assert!(machine.is_halted());
+
+        machine.reset();
+        assert_eq!(Ok(Status::Running), machine.step());
+        assert_eq!(3, machine.state()['a']);
+    }
+
+    #[test]
+    fn limit_test() {
+        let mut machine = countdown().with_step_limit(4);
+
+        assert_eq!(Ok(Status::Paused(3)), machine.run());
+        assert_eq!(Ok(Status::Running), machine.run_steps(2));
+        assert_eq!(
+            Err(VmError {
+                pc: 1,
+                fault: Fault::StepLimit(4)
+            }),
+            machine.run()
+        );
+
+        let mut machine = Machine::<Toy>::new(vec![Instr::Jnz(Operand::Imm(1), -1)], RegisterFile::new());
+
+        assert_eq!(Fault::PcOutOfRange(-1), machine.run().unwrap_err().fault);
+    }
+}
@@ -0,0 +1,281 @@
+mod tools;
+
+use std::env;
+use std::process;
+
+use aoc_common::{read_input, solve, AocResult, Part};
+
+use crate::tools::{ToolArgs, ToolError};
+
+const USAGE: &str = "usage: aoc run <year> <day> [--part a|b] < input.txt
+       aoc <year> <day> <mode> [<args>...]
+
+The second form runs a tool of the day, e.g. a debugger, listed by `aoc <year> <day> help`.";
+
+macro_rules! solutions {
+    ($($year:literal $day:literal => $krate:ident,)*) => {
+        fn run_solution(
+            year: u32,
+            day: u32,
+            parts: &[Part],
+            input: &str,
+        ) -> Option<AocResult<Vec<AocResult<String>>>> {
+            match (year, day) {
+                $(($year, $day) => Some(solve::<$krate::Solution>(input, parts)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+solutions! {
+    2015 1 => aoc2015_day1,
+    2015 2 => aoc2015_day2,
+    2015 3 => aoc2015_day3,
+    2015 4 => aoc2015_day4,
+    2015 5 => aoc2015_day5,
+    2015 6 => aoc2015_day6,
+    2015 7 => aoc2015_day7,
+    2015 8 => aoc2015_day8,
+    2015 9 => aoc2015_day9,
+    2015 10 => aoc2015_day10,
+    2015 11 => aoc2015_day11,
+    2015 12 => aoc2015_day12,
+    2015 13 => aoc2015_day13,
+    2015 14 => aoc2015_day14,
+    2015 15 => aoc2015_day15,
+    2015 16 => aoc2015_day16,
+    2015 17 => aoc2015_day17,
+    2015 18 => aoc2015_day18,
+    2015 19 => aoc2015_day19,
+    2015 20 => aoc2015_day20,
+    2015 21 => aoc2015_day21,
+    2015 22 => aoc2015_day22,
+    2015 23 => aoc2015_day23,
+    2015 24 => aoc2015_day24,
+    2015 25 => aoc2015_day25,
+    2016 1 => aoc2016_day01,
+    2016 2 => aoc2016_day02,
+    2016 3 => aoc2016_day03,
+    2016 4 => aoc2016_day04,
+    2016 5 => aoc2016_day05,
+    2016 6 => aoc2016_day06,
+    2016 7 => aoc2016_day07,
+    2016 8 => aoc2016_day08,
+    2016 9 => aoc2016_day09,
+    2016 10 => aoc2016_day10,
+    2016 11 => aoc2016_day11,
+    2016 12 => aoc2016_day12,
+    2016 13 => aoc2016_day13,
+    2016 14 => aoc2016_day14,
+    2017 1 => aoc2017_day01,
+    2017 2 => aoc2017_day02,
+    2017 3 => aoc2017_day03,
+    2017 4 => aoc2017_day04,
+    2017 5 => aoc2017_day05,
+    2017 6 => aoc2017_day06,
+    2017 7 => aoc2017_day07,
+    2017 8 => aoc2017_day08,
+    2017 9 => aoc2017_day09,
+    2017 10 => aoc2017_day10,
+    2017 11 => aoc2017_day11,
+    2017 12 => aoc2017_day12,
+    2017 13 => aoc2017_day13,
+    2017 14 => aoc2017_day14,
+    2017 15 => aoc2017_day15,
+    2017 16 => aoc2017_day16,
+    2017 17 => aoc2017_day17,
+    2017 18 => aoc2017_day18,
+    2017 19 => aoc2017_day19,
+    2017 20 => aoc2017_day20,
+    2017 21 => aoc2017_day21,
+    2017 22 => aoc2017_day22,
+    2017 23 => aoc2017_day23,
+    2017 24 => aoc2017_day24,
+    2017 25 => aoc2017_day25,
+    2018 1 => aoc2018_day01,
+    2018 2 => aoc2018_day02,
+    2018 3 => aoc2018_day03,
+    2018 4 => aoc2018_day04,
+    2018 5 => aoc2018_day05,
+    2018 6 => aoc2018_day06,
+    2018 7 => aoc2018_day07,
+    2018 8 => aoc2018_day08,
+    2018 9 => aoc2018_day09,
+    2018 10 => aoc2018_day10,
+    2018 12 => aoc2018_day12,
+    2018 13 => aoc2018_day13,
+    2024 11 => aoc2024_day11,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RunArgs {
+    year: u32,
+    day: u32,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run(RunArgs),
+    Tool(u32, u32, ToolArgs),
+}
+
+fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+
+    match args.next() {
+        Some(command) if command == "run" => {}
+        Some(year) => {
+            let year = year.parse().map_err(|_| format!("unknown command: {:?}", year))?;
+            let day = args
+                .next()
+                .ok_or("missing day")?
+                .parse()
+                .map_err(|e| format!("invalid day: {}", e))?;
+
+            return Ok(Command::Tool(year, day, ToolArgs::parse(args)?));
+        }
+        None => return Err("missing command".into()),
+    }
+
+    let year = args
+        .next()
+        .ok_or("missing year")?
+        .parse()
+        .map_err(|e| format!("invalid year: {}", e))?;
+
+    let day = args
+        .next()
+        .ok_or("missing day")?
+        .parse()
+        .map_err(|e| format!("invalid day: {}", e))?;
+
+    let mut parts = vec![Part::A, Part::B];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                parts = vec![args.next().ok_or("missing part after --part")?.parse()?];
+            }
+            other => return Err(format!("unexpected argument: {:?}", other)),
+        }
+    }
+
+    Ok(Command::Run(RunArgs { year, day, parts }))
+}
+
+fn run_tool(year: u32, day: u32, args: &ToolArgs) {
+    let usage = tools::usage(year, day).unwrap_or_else(|| {
+        eprintln!("No tools for {} day {}.", year, day);
+        process::exit(1);
+    });
+
+    if args.mode == "help" {
+        println!("{}", usage);
+        return;
+    }
+
+    match tools::run_tool(year, day, args) {
+        Some(Ok(())) => {}
+        Some(Err(ToolError::Usage(e))) => {
+            eprintln!("{}\n{}", e, usage);
+            process::exit(2);
+        }
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+        None => unreachable!("a day with a usage has tools"),
+    }
+}
+
+fn main() {
+    let run_args = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(run_args)) => run_args,
+        Ok(Command::Tool(year, day, args)) => return run_tool(year, day, &args),
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input_str = read_input().unwrap_or_else(|e| {
+        eprintln!("Cannot read the puzzle input: {}", e);
+        process::exit(1);
+    });
+
+    let answers = match run_solution(run_args.year, run_args.day, &run_args.parts, &input_str) {
+        Some(Ok(answers)) => answers,
+        Some(Err(e)) => {
+            eprintln!("Cannot parse the puzzle input: {}", e);
+            process::exit(1);
+        }
+        None => {
+            eprintln!("No solution for {} day {}.", run_args.year, run_args.day);
+            process::exit(1);
+        }
+    };
+
+    for (part, answer) in run_args.parts.into_iter().zip(answers) {
+        match answer {
+            Ok(answer) => println!("Part {}: {}", part, answer),
+            Err(e) => {
+                eprintln!("Part {} failed: {}", part, e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_args, run_solution, Command, Part, RunArgs, ToolArgs};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(
+            Ok(Command::Run(RunArgs {
+                year: 2017,
+                day: 18,
+                parts: vec![Part::B],
+            })),
+            parse_args(args("run 2017 18 --part b"))
+        );
+
+        assert_eq!(
+            Ok(Command::Run(RunArgs {
+                year: 2015,
+                day: 1,
+                parts: vec![Part::A, Part::B],
+            })),
+            parse_args(args("run 2015 1"))
+        );
+
+        assert!(parse_args(args("run 2015")).is_err());
+        assert!(parse_args(args("walk 2015 1")).is_err());
+        assert!(parse_args(args("run 2015 1 --part c")).is_err());
+
+        assert_eq!(
+            Ok(Command::Tool(2015, 23, ToolArgs::parse(args("debug program.txt")).unwrap())),
+            parse_args(args("2015 23 debug program.txt"))
+        );
+        assert!(parse_args(args("2015 23")).is_err());
+    }
+
+    #[test]
+    fn dispatch_test() {
+        assert_eq!(
+            Some(Ok(vec![Ok("3".to_string())])),
+            run_solution(2015, 1, &[Part::A], "(()(()(")
+        );
+        assert_eq!(None, run_solution(2015, 26, &[Part::A], ""));
+    }
+}
@@ -0,0 +1,435 @@
+//! The tools some days have beside their solution, e.g. a debugger or a renderer, run as
+//! `aoc <year> <day> <mode> [<args>...]`.
+
+use std::fmt;
+use std::fs;
+use std::io;
+
+use aoc_common::decompile::{self, Cfg, Decompile};
+use aoc_common::AocError;
+
+/// Why a tool did not run to the end.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ToolError {
+    /// The arguments do not fit the mode, the usage of the day's tools tells why.
+    Usage(String),
+    Failed(String),
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ToolError::Usage(message) | ToolError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<AocError> for ToolError {
+    fn from(e: AocError) -> ToolError {
+        ToolError::Failed(e.to_string())
+    }
+}
+
+impl From<io::Error> for ToolError {
+    fn from(e: io::Error) -> ToolError {
+        ToolError::Failed(e.to_string())
+    }
+}
+
+type ToolResult = Result<(), ToolError>;
+
+/// The arguments after the mode: positional ones, and options given as `--<name> <value>`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ToolArgs {
+    pub mode: String,
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+}
+
+impl ToolArgs {
+    pub fn parse<I>(args: I) -> Result<ToolArgs, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        let mode = args.next().ok_or("missing mode")?;
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args.next().ok_or_else(|| format!("missing value after {}", arg))?;
+                    options.push((name.to_string(), value));
+                }
+                None => positional.push(arg),
+            }
+        }
+
+        Ok(ToolArgs {
+            mode,
+            positional,
+            options,
+        })
+    }
+
+    /// The positional arguments, failing on a mode the tool does not have, unless there are `min`
+    /// to `max` of them, or on an option the tool does not know.
+    fn expect(&self, modes: &[&str], min: usize, max: usize, known: &[&str]) -> Result<&[String], ToolError> {
+        if !modes.contains(&self.mode.as_str()) {
+            return Err(ToolError::Usage(format!("unknown mode: {:?}", self.mode)));
+        }
+
+        if self.positional.len() < min {
+            return Err(ToolError::Usage("missing arguments".to_string()));
+        }
+
+        if let Some(extra) = self.positional.get(max) {
+            return Err(ToolError::Usage(format!("unexpected argument: {:?}", extra)));
+        }
+
+        if let Some((name, _)) = self.options.iter().find(|(name, _)| !known.contains(&name.as_str())) {
+            return Err(ToolError::Usage(format!("unknown option: --{}", name)));
+        }
+
+        Ok(&self.positional)
+    }
+
+    /// The value of the option, the last one if given more than once.
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.iter().rev().find(|(known, _)| known == name).map(|(_, value)| value.as_str())
+    }
+}
+
+fn read_file(path: &str) -> Result<String, ToolError> {
+    fs::read_to_string(path).map_err(|e| ToolError::Failed(format!("could not read {}: {}", path, e)))
+}
+
+/// The `decompile` and `dot` modes of the days with a machine.
+fn print_flow<S: Decompile>(mode: &str, program: &[S::Instr])
+where
+    S::Instr: fmt::Display,
+{
+    match mode {
+        "decompile" => print!("{}", decompile::render(&decompile::decompile::<S>(program))),
+        _ => print!("{}", Cfg::new::<S>(program).to_dot(program)),
+    }
+}
+
+struct Tool {
+    year: u32,
+    day: u32,
+    usage: &'static str,
+    run: fn(&ToolArgs) -> ToolResult,
+}
+
+const TOOLS: &[Tool] = &[
+    Tool {
+        year: 2015,
+        day: 23,
+        usage: "usage: aoc 2015 23 debug|trace|decompile|dot <program> [--reg-a <value>]
+
+  debug      step through the program, reading debugger commands from stdin
+  trace      run the program, printing one JSON line per executed instruction
+  decompile  print the program as structured pseudo-code
+  dot        print the control-flow graph of the program in the Graphviz DOT language",
+        run: turing_lock,
+    },
+    Tool {
+        year: 2017,
+        day: 23,
+        usage: "usage: aoc 2017 23 optimize|verify|decompile|dot <program> [<reg>=<value>...]
+
+  optimize   print the program with its loops replaced by macro-instructions
+  verify     run the original and the optimized program from the given registers and
+             check they end in the same state
+  decompile  print the program as structured pseudo-code
+  dot        print the control-flow graph of the program in the Graphviz DOT language",
+        run: coprocessor,
+    },
+    Tool {
+        year: 2017,
+        day: 8,
+        usage: "usage: aoc 2017 8 repl [<program>]
+
+  repl  read instructions and commands from stdin, after executing the program if given",
+        run: registers,
+    },
+    Tool {
+        year: 2015,
+        day: 7,
+        usage: "usage: aoc 2015 7 verilog|dot <circuit> [<wire>]
+
+  verilog  print the circuit as a Verilog module
+  dot      print the circuit as a Graphviz graph, only the fan-in of the wire if given",
+        run: circuit,
+    },
+    Tool {
+        year: 2015,
+        day: 22,
+        usage: "usage: aoc 2015 22 replay|json <boss> [--rules <rulebook>] [--difficulty <name>]
+
+  replay  print the battle log of the cheapest win against the boss
+  json    print the same battle log as JSON
+
+The puzzle's rulebook and its normal difficulty are used unless given.",
+        run: wizard,
+    },
+    Tool {
+        year: 2015,
+        day: 21,
+        usage: "usage: aoc 2015 21 pareto <boss> [--shop <shop>]
+
+  pareto  list the winning loadouts no other one beats on both cost and rounds to spare,
+          with the puzzle's shop unless given",
+        run: shop,
+    },
+    Tool {
+        year: 2018,
+        day: 6,
+        usage: "usage: aoc 2018 6 ascii|svg <locations> [--metric manhattan|chebyshev|euclidean]
+
+  ascii  print the nearest site of each location, in the puzzle's letters
+  svg    print the same map as an SVG image, infinite regions washed out
+
+The Manhattan distance is used unless given.",
+        run: regions,
+    },
+];
+
+fn find(year: u32, day: u32) -> Option<&'static Tool> {
+    TOOLS.iter().find(|tool| tool.year == year && tool.day == day)
+}
+
+/// How to run the tools of the day, if it has any.
+pub fn usage(year: u32, day: u32) -> Option<&'static str> {
+    find(year, day).map(|tool| tool.usage)
+}
+
+/// Runs a tool of the day, if it has any.
+pub fn run_tool(year: u32, day: u32, args: &ToolArgs) -> Option<ToolResult> {
+    find(year, day).map(|tool| (tool.run)(args))
+}
+
+fn turing_lock(args: &ToolArgs) -> ToolResult {
+    use aoc2015_day23::debug::{self, Debugger};
+    use aoc2015_day23::{Program, Register, TuringLock};
+
+    let path = &args.expect(&["debug", "trace", "decompile", "dot"], 1, 1, &["reg-a"])?[0];
+    let reg_a = match args.option("reg-a") {
+        Some(val) => val
+            .parse()
+            .map_err(|_| ToolError::Usage(format!("invalid register value: {:?}", val)))?,
+        None => 0,
+    };
+
+    let mut program = read_file(path)?.parse::<Program>()?;
+    program.set_reg(Register::A, reg_a);
+
+    match args.mode.as_str() {
+        "debug" => {
+            let stdin = io::stdin();
+
+            Debugger::new(program).run_session(stdin.lock(), io::stdout())?;
+        }
+        "trace" => {
+            debug::write_trace(&mut program, io::stdout().lock())?;
+        }
+        "decompile" | "dot" => print_flow::<TuringLock>(&args.mode, program.instructions()),
+        _ => unreachable!("the modes are checked"),
+    }
+
+    Ok(())
+}
+
+fn coprocessor(args: &ToolArgs) -> ToolResult {
+    use aoc2017_day23::{optimizer, Coprocessor, Program, Registers};
+    use aoc_common::vm::Register;
+
+    const STEP_LIMIT: u64 = 100_000_000;
+
+    let (path, assignments) = args
+        .expect(&["optimize", "verify", "decompile", "dot"], 1, usize::MAX, &[])?
+        .split_first()
+        .unwrap();
+
+    let mut regs = Registers::new();
+
+    for assignment in assignments {
+        let mut parts = assignment.splitn(2, '=');
+
+        let reg = parts.next().and_then(<char as Register>::parse);
+        let val = parts.next().and_then(|val| val.parse().ok());
+
+        match (reg, val) {
+            (Some(reg), Some(val)) => regs[reg] = val,
+            _ => return Err(ToolError::Usage(format!("invalid register assignment: {:?}", assignment))),
+        }
+    }
+
+    let program = read_file(path)?.parse::<Program>()?;
+
+    match args.mode.as_str() {
+        "optimize" => print!("{}", optimizer::optimize(&program)),
+        "verify" => {
+            let verification = optimizer::verify(&program, &regs, STEP_LIMIT)?;
+
+            println!(
+                "ok: {} steps original, {} steps optimized",
+                verification.original_steps, verification.optimized_steps
+            );
+        }
+        "decompile" | "dot" => print_flow::<Coprocessor>(&args.mode, program.instructions()),
+        _ => unreachable!("the modes are checked"),
+    }
+
+    Ok(())
+}
+
+fn registers(args: &ToolArgs) -> ToolResult {
+    use aoc2017_day08::repl::{Command, Repl};
+
+    let program = args.expect(&["repl"], 0, 1, &[])?.first();
+    let mut repl = Repl::new();
+
+    if let Some(path) = program {
+        if let Some(text) = repl.run_command(Command::Load(path.to_string()))? {
+            println!("{}", text);
+        }
+    }
+
+    let stdin = io::stdin();
+
+    repl.run_session(stdin.lock(), io::stdout())?;
+
+    Ok(())
+}
+
+fn circuit(args: &ToolArgs) -> ToolResult {
+    use aoc2015_day7::export;
+
+    let (path, wire) = match args.mode.as_str() {
+        "verilog" => (&args.expect(&["verilog"], 1, 1, &[])?[0], None),
+        _ => {
+            let positional = args.expect(&["dot"], 1, 2, &[])?;
+
+            (&positional[0], positional.get(1))
+        }
+    };
+
+    let circuit = aoc2015_day7::parse_circuit(&read_file(path)?)?;
+
+    let output = match args.mode.as_str() {
+        "verilog" => export::to_verilog(&circuit, "circuit"),
+        _ => export::to_dot(&circuit, wire.map(String::as_str)),
+    };
+
+    print!("{}", output.map_err(|e| ToolError::Failed(e.to_string()))?);
+
+    Ok(())
+}
+
+fn wizard(args: &ToolArgs) -> ToolResult {
+    use aoc2015_day22::replay::replay;
+    use aoc2015_day22::rulebook::Rulebook;
+    use aoc2015_day22::{find_best_strategy, parse_boss, Player, PLAYER_HP, PLAYER_MANA};
+
+    let path = &args.expect(&["replay", "json"], 1, 1, &["rules", "difficulty"])?[0];
+
+    let book = match args.option("rules") {
+        Some(rules) => read_file(rules)?.parse::<Rulebook>()?,
+        None => Rulebook::standard(),
+    };
+    let rules = book.difficulty(args.option("difficulty").unwrap_or("normal"))?;
+
+    let boss = parse_boss(&read_file(path)?)?;
+    let player = Player::new(PLAYER_HP, PLAYER_MANA);
+
+    let strategy = find_best_strategy(&book, rules, &player, &boss)
+        .ok_or_else(|| ToolError::Failed("The boss cannot be beaten.".to_string()))?;
+    let log = replay(&book, rules, &player, &boss, &strategy.spells)?;
+
+    match args.mode.as_str() {
+        "replay" => {
+            print!("{}", log);
+            println!("\n{} mana spent.", strategy.mana);
+        }
+        _ => println!("{}", log.to_json()),
+    }
+
+    Ok(())
+}
+
+fn shop(args: &ToolArgs) -> ToolResult {
+    use aoc2015_day21::shop::Shop;
+    use aoc2015_day21::{outcomes, parse_boss, pareto_front, pareto_report};
+
+    let path = &args.expect(&["pareto"], 1, 1, &["shop"])?[0];
+
+    let shop = match args.option("shop") {
+        Some(shop) => read_file(shop)?.parse::<Shop>()?,
+        None => Shop::standard(),
+    };
+    let boss = parse_boss(&read_file(path)?)?;
+
+    print!("{}", pareto_report(&pareto_front(&outcomes(&shop, &boss))));
+
+    Ok(())
+}
+
+fn regions(args: &ToolArgs) -> ToolResult {
+    use aoc2018_day06::regions::{Metric, RegionMap};
+    use aoc2018_day06::render::{ascii, svg};
+    use aoc2018_day06::Sites;
+
+    /// Pixels per location in the SVG image.
+    const CELL: usize = 4;
+
+    let path = &args.expect(&["ascii", "svg"], 1, 1, &["metric"])?[0];
+
+    let metric = match args.option("metric") {
+        Some(metric) => metric.parse::<Metric>().map_err(|e| ToolError::Usage(e.to_string()))?,
+        None => Metric::Manhattan,
+    };
+    let sites = read_file(path)?.parse::<Sites>()?;
+    let map = RegionMap::new(sites.locations(), metric);
+
+    match args.mode.as_str() {
+        "ascii" => print!("{}", ascii(&map)),
+        _ => print!("{}", svg(&map, CELL)),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_tool, ToolArgs, ToolError};
+
+    fn args(line: &str) -> ToolArgs {
+        ToolArgs::parse(line.split_whitespace().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn tool_args_test() {
+        let parsed = args("debug program.txt --reg-a 1");
+
+        assert_eq!("debug", parsed.mode);
+        assert_eq!(Ok(&["program.txt".to_string()][..]), parsed.expect(&["debug"], 1, 1, &["reg-a"]));
+        assert_eq!(Some("1"), parsed.option("reg-a"));
+
+        assert!(matches!(parsed.expect(&["trace"], 1, 1, &["reg-a"]), Err(ToolError::Usage(_))));
+        assert!(matches!(parsed.expect(&["debug"], 2, 2, &["reg-a"]), Err(ToolError::Usage(_))));
+        assert!(matches!(parsed.expect(&["debug"], 0, 0, &["reg-a"]), Err(ToolError::Usage(_))));
+        assert!(matches!(parsed.expect(&["debug"], 1, 1, &[]), Err(ToolError::Usage(_))));
+        assert!(ToolArgs::parse(vec!["debug".to_string(), "--reg-a".to_string()]).is_err());
+    }
+
+    #[test]
+    fn run_tool_test() {
+        assert_eq!(None, run_tool(2015, 1, &args("debug input.txt")));
+        assert!(matches!(run_tool(2015, 23, &args("fly input.txt")), Some(Err(ToolError::Usage(_)))));
+        assert!(matches!(run_tool(2015, 23, &args("dot /nonexistent")), Some(Err(ToolError::Failed(_)))));
+    }
+}